@@ -7,13 +7,14 @@ use clap_mangen::Man;
 
 // Include the real CLI definition in a module to avoid conflicts
 #[path = "src/cli/app.rs"]
+#[allow(dead_code)]
 mod cli;
 
 fn main() {
     // Rerun when CLI definition or this script changes
-    println!("cargo::rerun-if-changed=src/cli/app.rs");
-    println!("cargo::rerun-if-changed=build.rs");
-    println!("cargo::rerun-if-env-changed=SIKIL_GENERATE_MAN");
+    println!("cargo:rerun-if-changed=src/cli/app.rs");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=SIKIL_GENERATE_MAN");
 
     // Only generate man page in release builds or when explicitly requested
     let profile = env::var("PROFILE").unwrap_or_default();
@@ -35,7 +36,7 @@ fn generate_man_page() {
 
     let mut buffer = Vec::new();
     if let Err(e) = man.render(&mut buffer) {
-        println!("cargo::warning=Failed to render man page: {}", e);
+        println!("cargo:warning=Failed to render man page: {}", e);
         return;
     }
 
@@ -50,9 +51,9 @@ fn generate_man_page() {
     }
 
     if let Err(e) = fs::write(&man_page_path, buffer) {
-        println!("cargo::warning=Failed to write man page: {}", e);
+        println!("cargo:warning=Failed to write man page: {}", e);
         return;
     }
 
-    println!("cargo::warning=Generated man page: sikil.1");
+    println!("cargo:warning=Generated man page: sikil.1");
 }
@@ -51,6 +51,10 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub no_cache: bool,
 
+    /// Print the planned filesystem changes without making them
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
     /// Subcommand to execute
     #[command(subcommand)]
     pub command: Commands,
@@ -71,6 +75,15 @@ EXAMPLES:
     sikil list --managed
         List only managed skills
 
+    sikil list --orphans
+        List managed skills that no agent links to
+
+    sikil list --tag git --author alice --scope workspace --name 'review-*'
+        List workspace skills by alice tagged 'git' whose name starts with 'review-'
+
+    sikil list --sort size
+        List skills, largest first
+
     sikil list --json
         Output in JSON format
 "##)]
@@ -94,6 +107,31 @@ EXAMPLES:
         /// Show only duplicate skills
         #[arg(short, long)]
         duplicates: bool,
+
+        /// Show only managed skills that no agent links to
+        #[arg(long)]
+        orphans: bool,
+
+        /// Show only skills with this tag (repeat to require several)
+        #[arg(long, value_name = "TAG")]
+        tag: Vec<String>,
+
+        /// Show only skills whose author contains this text
+        #[arg(long, value_name = "AUTHOR")]
+        author: Option<String>,
+
+        /// Show only skills installed in this scope
+        #[arg(long, value_name = "SCOPE", value_parser = ["global", "workspace"])]
+        scope: Option<String>,
+
+        /// Show only skills whose name matches this glob (`*`, `?`)
+        #[arg(long, value_name = "GLOB")]
+        name: Option<String>,
+
+        /// Sort order
+        #[arg(long, value_name = "KEY", default_value = "name",
+              value_parser = ["name", "size", "installed-at", "agents"])]
+        sort: String,
     },
 
     /// Show detailed information about a skill
@@ -110,6 +148,28 @@ EXAMPLES:
         name: String,
     },
 
+    /// Search skills by name, tags, description and SKILL.md content
+    #[command(after_help = r##"
+EXAMPLES:
+    sikil search pdf extraction
+        Find skills about extracting PDFs, best matches first
+
+    sikil search review --limit 5
+        Show the five best matches
+
+    sikil search --json "git commit"
+        Output results with snippets in JSON format
+"##)]
+    Search {
+        /// Words to search for (every word must match)
+        #[arg(required = true, num_args = 1..)]
+        query: Vec<String>,
+
+        /// Maximum number of results
+        #[arg(long, value_name = "N", default_value_t = 20)]
+        limit: usize,
+    },
+
     /// Install a skill from a local path or Git repository
     #[command(after_help = r##"
 EXAMPLES:
@@ -136,6 +196,10 @@ EXAMPLES:
         /// Agents to install to (comma-separated or 'all')
         #[arg(short, long, value_name = "AGENTS")]
         r#to: Option<String>,
+
+        /// Install even if the skill contains possible secrets
+        #[arg(long)]
+        allow_secrets: bool,
     },
 
     /// Validate a skill's SKILL.md file
@@ -146,11 +210,85 @@ EXAMPLES:
 
     sikil validate my-skill
         Validate an installed skill by name
+
+    sikil validate ./skills
+        Validate every skill found below a directory
+
+    sikil validate --all --format sarif > sikil.sarif
+        Validate all installed skills and write a SARIF report
+
+    sikil validate --scripts ./third-party-skill
+        Also inventory bundled scripts and flag risky constructs
+
+    sikil validate --fix ./path/to/skill
+        Show a diff of mechanical fixes to SKILL.md and apply them after confirmation
 "##)]
     Validate {
-        /// Path to the skill or name of installed skill
-        #[arg(value_name = "PATH_OR_NAME")]
-        path: String,
+        /// Path to a skill, a directory of skills, or name of installed skill
+        #[arg(value_name = "PATH_OR_NAME", required_unless_present = "all")]
+        path: Option<String>,
+
+        /// Validate every installed skill
+        #[arg(long, conflicts_with = "path")]
+        all: bool,
+
+        /// Report format
+        #[arg(long, value_name = "FORMAT", default_value = "text",
+              value_parser = ["text", "json", "sarif", "junit"])]
+        format: String,
+
+        /// Inventory scripts and flag risky constructs (curl | sh, sudo, ...)
+        #[arg(long)]
+        scripts: bool,
+
+        /// Fix mechanical problems in SKILL.md (name format, missing description, CRLF, BOM, ...)
+        #[arg(long)]
+        fix: bool,
+
+        /// Apply fixes without confirmation
+        #[arg(long, requires = "fix")]
+        yes: bool,
+    },
+
+    /// Read and edit SKILL.md frontmatter
+    #[command(after_help = r##"
+EXAMPLES:
+    sikil meta get my-skill version
+        Print the version of 'my-skill'
+
+    sikil meta get my-skill
+        Print all frontmatter fields
+
+    sikil meta set my-skill version 1.2.0
+        Set a field, keeping comments, key order and the body
+
+    sikil meta set my-skill tags '[git, review]' --yaml
+        Set a field to a YAML value instead of a string
+
+    sikil meta unset my-skill homepage
+        Remove a field
+"##)]
+    Meta {
+        #[command(subcommand)]
+        action: MetaAction,
+    },
+
+    /// Report which tools skills pre-approve with allowed-tools
+    #[command(after_help = r##"
+EXAMPLES:
+    sikil permissions
+        List the allowed-tools of every installed skill
+
+    sikil permissions --flagged
+        Only show skills that grant shell or network access
+
+    sikil permissions --json
+        Output the report in JSON format
+"##)]
+    Permissions {
+        /// Only show skills that grant shell or network access
+        #[arg(long)]
+        flagged: bool,
     },
 
     /// Adopt an unmanaged skill into management
@@ -169,6 +307,10 @@ EXAMPLES:
         /// Agent to adopt from (required if multiple locations)
         #[arg(short, long, value_name = "AGENT")]
         from: Option<String>,
+
+        /// Adopt even if the skill contains possible secrets
+        #[arg(long)]
+        allow_secrets: bool,
     },
 
     /// Unmanage a skill (convert to unmanaged)
@@ -275,6 +417,148 @@ EXAMPLES:
         set: Vec<String>,
     },
 
+    /// Check the sikil environment for problems
+    #[command(after_help = r##"
+EXAMPLES:
+    sikil doctor
+        Run all health checks
+
+    sikil doctor --json
+        Output check results in JSON format
+"##)]
+    Doctor,
+
+    /// Repair broken or drifted managed installations
+    #[command(after_help = r##"
+EXAMPLES:
+    sikil repair
+        Relink broken managed symlinks and remove empty skill directories
+
+    sikil repair --dry-run
+        Show what would be repaired without changing anything
+
+    sikil repair --json
+        Output one JSON object per repair action
+
+Only empty directories named after a skill in the repository or the trash are
+removed. A managed skill whose link was deleted entirely is not repaired; run
+`sikil sync` to recreate it.
+"##)]
+    Repair,
+
+    /// Remove orphaned skills, leftover clones and stale cache entries
+    #[command(after_help = r##"
+EXAMPLES:
+    sikil gc
+        Show what can be cleaned up and remove it after confirmation
+
+    sikil gc --yes
+        Skip confirmation prompt
+
+    sikil gc --dry-run
+        Show the removal plan without changing anything
+
+    sikil list --orphans
+        List orphaned managed skills without removing them
+"##)]
+    Gc {
+        /// Skip confirmation prompt
+        #[arg(long)]
+        yes: bool,
+    },
+
+    /// Inspect and maintain the scan cache
+    #[command(after_help = r##"
+EXAMPLES:
+    sikil cache stats
+        Show entry count, size, oldest entry and the last scan's hit rate
+
+    sikil cache prune
+        Remove entries for skill directories that no longer exist
+
+    sikil cache verify
+        Re-hash cached skills and report entries the mtime check missed
+
+    sikil cache clear
+        Remove every entry; the next scan rebuilds the cache
+"##)]
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+
+    /// Roll back or finish operations that were interrupted part-way
+    #[command(after_help = r##"
+EXAMPLES:
+    sikil recover
+        List interrupted operations and the steps they completed
+
+    sikil recover --rollback
+        Undo the completed steps of every interrupted operation
+
+    sikil recover --forward
+        Run the remaining steps of every interrupted operation
+"##)]
+    Recover {
+        /// Undo the steps that completed
+        #[arg(long, conflicts_with = "forward")]
+        rollback: bool,
+
+        /// Run the steps that remain
+        #[arg(long)]
+        forward: bool,
+    },
+
+    /// Reverse the most recent operation
+    #[command(after_help = r##"
+EXAMPLES:
+    sikil undo
+        Reverse the last install, adopt, unmanage, remove or sync
+
+    sikil undo --dry-run
+        Show the steps that would reverse it
+"##)]
+    Undo,
+
+    /// List, restore or empty skills removed with `sikil remove`
+    #[command(after_help = r##"
+EXAMPLES:
+    sikil trash list
+        Show removed skills, where they were installed and when they expire
+
+    sikil trash restore git-workflow
+        Put the most recently removed 'git-workflow' back
+
+    sikil trash empty --yes
+        Permanently delete everything in the trash
+"##)]
+    Trash {
+        #[command(subcommand)]
+        action: TrashAction,
+    },
+
+    /// Show the audit log of skill changes
+    #[command(after_help = r##"
+EXAMPLES:
+    sikil log
+        Show every logged change, oldest first
+
+    sikil log --skill git-workflow
+        Show changes to one skill
+
+    sikil log --since 2024-05-01 --json
+        Show changes since a date as JSON
+"##)]
+    Log {
+        /// Only show changes to this skill
+        #[arg(long, value_name = "NAME")]
+        skill: Option<String>,
+
+        /// Only show changes at or after this date (YYYY-MM-DD or RFC 3339)
+        #[arg(long, value_name = "DATE")]
+        since: Option<String>,
+    },
+
     /// Generate shell completions
     #[command(after_help = r##"
 EXAMPLES:
@@ -297,3 +581,131 @@ EXAMPLES:
         output: Option<String>,
     },
 }
+
+impl Commands {
+    /// Returns true if the command changes the repository, agent
+    /// directories, SKILL.md files, config or cache
+    ///
+    /// Mutating commands hold the process lock for their whole run;
+    /// read-only commands never wait for it.
+    pub fn is_mutating(&self) -> bool {
+        match self {
+            Commands::Install { .. }
+            | Commands::Adopt { .. }
+            | Commands::Unmanage { .. }
+            | Commands::Remove { .. }
+            | Commands::Sync { .. }
+            | Commands::Gc { .. }
+            | Commands::Repair
+            | Commands::Recover { .. }
+            | Commands::Undo => true,
+            Commands::Validate { fix, .. } => *fix,
+            Commands::Meta { action } => !matches!(action, MetaAction::Get { .. }),
+            Commands::Config { edit, set } => *edit || !set.is_empty(),
+            Commands::Cache { action } => matches!(action, CacheAction::Clear | CacheAction::Prune),
+            Commands::Trash { action } => !matches!(action, TrashAction::List),
+            Commands::List { .. }
+            | Commands::Show { .. }
+            | Commands::Search { .. }
+            | Commands::Permissions { .. }
+            | Commands::Doctor
+            | Commands::Log { .. }
+            | Commands::Completions { .. } => false,
+        }
+    }
+
+    /// Returns whether the command can print its plan under `--dry-run`
+    ///
+    /// Read-only commands trivially support it. Plans describe skill
+    /// directories and symlinks, so the commands that only rewrite one file
+    /// in place (`validate --fix` and `meta` edit SKILL.md, `cache` its
+    /// database, `config` the config file) reject the flag.
+    pub fn supports_dry_run(&self) -> bool {
+        match self {
+            Commands::Install { .. }
+            | Commands::Adopt { .. }
+            | Commands::Unmanage { .. }
+            | Commands::Remove { .. }
+            | Commands::Sync { .. }
+            | Commands::Gc { .. }
+            | Commands::Repair
+            | Commands::Recover { .. }
+            | Commands::Undo
+            | Commands::Trash { .. } => true,
+            _ => !self.is_mutating(),
+        }
+    }
+}
+
+/// Actions of the `cache` subcommand
+#[derive(Subcommand, Debug)]
+pub enum CacheAction {
+    /// Show cache size, entry counts and the hit rate of the last scan
+    Stats,
+    /// Remove every cache entry
+    Clear,
+    /// Remove entries for skill directories that no longer exist
+    Prune,
+    /// Re-hash cached skills and report stale entries
+    Verify,
+}
+
+/// Actions of the `trash` subcommand
+#[derive(Subcommand, Debug)]
+pub enum TrashAction {
+    /// List removed skills
+    List,
+    /// Put a removed skill back where it was installed
+    Restore {
+        /// Skill name, or an entry id from `sikil trash list`
+        name: String,
+    },
+    /// Permanently delete everything in the trash
+    Empty {
+        /// Skip confirmation prompt
+        #[arg(long)]
+        yes: bool,
+    },
+}
+
+/// Actions of the `meta` subcommand
+#[derive(Subcommand, Debug)]
+pub enum MetaAction {
+    /// Print a frontmatter field, or all fields
+    Get {
+        /// Name of an installed skill, or path to a skill directory or SKILL.md
+        #[arg(value_name = "SKILL")]
+        skill: String,
+
+        /// Top-level frontmatter key
+        key: Option<String>,
+    },
+
+    /// Set a frontmatter field
+    Set {
+        /// Name of an installed skill, or path to a skill directory or SKILL.md
+        #[arg(value_name = "SKILL")]
+        skill: String,
+
+        /// Top-level frontmatter key
+        key: String,
+
+        /// New value (a string unless --yaml is given)
+        #[arg(allow_hyphen_values = true)]
+        value: String,
+
+        /// Parse the value as YAML (lists, numbers, booleans, ...)
+        #[arg(long)]
+        yaml: bool,
+    },
+
+    /// Remove a frontmatter field
+    Unset {
+        /// Name of an installed skill, or path to a skill directory or SKILL.md
+        #[arg(value_name = "SKILL")]
+        skill: String,
+
+        /// Top-level frontmatter key
+        key: String,
+    },
+}
@@ -2,6 +2,7 @@
 
 pub mod app;
 pub mod output;
+pub mod report;
 
-pub use app::{Cli, Commands};
+pub use app::{CacheAction, Cli, Commands, MetaAction, TrashAction};
 pub use output::{MessageWriter, Output, Progress};
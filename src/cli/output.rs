@@ -3,6 +3,7 @@
 //! This module provides consistent output formatting across all commands,
 //! including colored output, JSON output, and progress indicators.
 
+use crate::core::plan::Plan;
 use anstream::println;
 use anstyle::{AnsiColor, Color, Style};
 use serde::Serialize;
@@ -72,6 +73,27 @@ impl Output {
         Ok(())
     }
 
+    /// Print the plan of a `--dry-run`
+    ///
+    /// In JSON mode the plan is printed as a JSON object on stdout.
+    pub fn print_plan(&self, plan: &Plan) -> Result<(), serde_json::Error> {
+        if self.json_mode {
+            return self.print_json(plan);
+        }
+        if plan.is_empty() {
+            println!("Dry run: nothing to do");
+        } else {
+            let title = format!("{} {}", plan.command, plan.skill);
+            println!(
+                "Dry run: `{}` would perform {} step(s):",
+                title.trim_end(),
+                plan.steps.len()
+            );
+            print!("{}", plan);
+        }
+        Ok(())
+    }
+
     /// Print an info message (no color or icon, for informational output)
     pub fn print_info(&self, msg: &str) {
         if self.json_mode {
@@ -0,0 +1,282 @@
+//! Machine-readable validation reports for CI systems
+//!
+//! This module renders validation findings as SARIF 2.1.0 (for code-scanning
+//! annotations) and JUnit XML (for test report dashboards).
+
+use crate::core::rules::{Finding, Severity, RULES};
+use serde_json::{json, Value};
+
+/// SARIF schema URI emitted in reports
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Findings for one validated skill
+#[derive(Debug, Clone)]
+pub struct ReportEntry<'a> {
+    /// Display name of the skill (its name, or its path if unparseable)
+    pub name: &'a str,
+    /// Path to the skill's SKILL.md, as it should appear in the report
+    pub file: String,
+    /// Findings reported for the skill
+    pub findings: &'a [Finding],
+}
+
+/// Maps a severity to a SARIF result level
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info | Severity::Off => "note",
+    }
+}
+
+/// Renders findings as a SARIF 2.1.0 log
+pub fn to_sarif(entries: &[ReportEntry]) -> Value {
+    let rules: Vec<Value> = RULES
+        .iter()
+        .map(|rule| {
+            json!({
+                "id": rule.id,
+                "name": rule.name,
+                "shortDescription": { "text": rule.description },
+                "defaultConfiguration": { "level": sarif_level(rule.default_severity) },
+            })
+        })
+        .collect();
+
+    let results: Vec<Value> = entries
+        .iter()
+        .flat_map(|entry| {
+            entry.findings.iter().map(move |finding| {
+                let uri = match &finding.file {
+                    Some(file) => match entry.file.rsplit_once('/') {
+                        Some((dir, _)) => format!("{}/{}", dir, file),
+                        None => file.clone(),
+                    },
+                    None => entry.file.clone(),
+                };
+                let mut location = json!({
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": uri },
+                    }
+                });
+                if let Some(line) = finding.line {
+                    location["physicalLocation"]["region"] = json!({ "startLine": line });
+                }
+                json!({
+                    "ruleId": finding.rule,
+                    "level": sarif_level(finding.severity),
+                    "message": { "text": finding.message },
+                    "locations": [location],
+                })
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "sikil",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Renders findings as JUnit XML, one test case per skill
+///
+/// A skill with error-level findings is a failed test case; warnings and
+/// informational findings are attached as `system-out`.
+pub fn to_junit(entries: &[ReportEntry]) -> String {
+    let failures = entries
+        .iter()
+        .filter(|e| e.findings.iter().any(|f| f.severity == Severity::Error))
+        .count();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"sikil validate\" tests=\"{}\" failures=\"{}\">\n",
+        entries.len(),
+        failures
+    ));
+    xml.push_str(&format!(
+        "  <testsuite name=\"sikil validate\" tests=\"{}\" failures=\"{}\" errors=\"0\">\n",
+        entries.len(),
+        failures
+    ));
+
+    for entry in entries {
+        xml.push_str(&format!(
+            "    <testcase classname=\"sikil.validate\" name=\"{}\" file=\"{}\"",
+            xml_escape(entry.name),
+            xml_escape(&entry.file)
+        ));
+
+        let (errors, others): (Vec<&Finding>, Vec<&Finding>) = entry
+            .findings
+            .iter()
+            .partition(|f| f.severity == Severity::Error);
+        if errors.is_empty() && others.is_empty() {
+            xml.push_str("/>\n");
+            continue;
+        }
+        xml.push_str(">\n");
+
+        if !errors.is_empty() {
+            xml.push_str(&format!(
+                "      <failure type=\"validation\" message=\"{} error(s)\">{}</failure>\n",
+                errors.len(),
+                xml_escape(&finding_lines(&errors))
+            ));
+        }
+        if !others.is_empty() {
+            xml.push_str(&format!(
+                "      <system-out>{}</system-out>\n",
+                xml_escape(&finding_lines(&others))
+            ));
+        }
+        xml.push_str("    </testcase>\n");
+    }
+
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+/// Formats findings one per line as `SK010 error (line 11): message`
+fn finding_lines(findings: &[&Finding]) -> String {
+    findings
+        .iter()
+        .map(|f| {
+            let location = match (&f.file, f.line) {
+                (Some(file), Some(line)) => format!(" ({}:{})", file, line),
+                (Some(file), None) => format!(" ({})", file),
+                (None, Some(line)) => format!(" (line {})", line),
+                (None, None) => String::new(),
+            };
+            format!("{} {}{}: {}", f.rule, f.severity, location, f.message)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Escapes text for use in XML attributes and content
+fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(rule: &str, severity: Severity, line: Option<usize>) -> Finding {
+        Finding {
+            rule: rule.to_string(),
+            severity,
+            message: format!("{} <message>", rule),
+            file: None,
+            line,
+        }
+    }
+
+    #[test]
+    fn test_sarif_structure() {
+        let findings = vec![
+            finding("SK010", Severity::Error, Some(11)),
+            finding("SK006", Severity::Warning, None),
+        ];
+        let entries = vec![ReportEntry {
+            name: "my-skill",
+            file: "skills/my-skill/SKILL.md".to_string(),
+            findings: &findings,
+        }];
+
+        let sarif = to_sarif(&entries);
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "sikil");
+        assert_eq!(
+            run["tool"]["driver"]["rules"].as_array().unwrap().len(),
+            RULES.len()
+        );
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "SK010");
+        assert_eq!(results[0]["level"], "error");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(
+            location["artifactLocation"]["uri"],
+            "skills/my-skill/SKILL.md"
+        );
+        assert_eq!(location["region"]["startLine"], 11);
+        assert!(results[1]["locations"][0]["physicalLocation"]["region"].is_null());
+    }
+
+    #[test]
+    fn test_sarif_location_for_bundled_file() {
+        let mut secret = finding("SK012", Severity::Error, Some(3));
+        secret.file = Some("references/api.md".to_string());
+        let findings = vec![secret];
+        let entries = vec![ReportEntry {
+            name: "my-skill",
+            file: "skills/my-skill/SKILL.md".to_string(),
+            findings: &findings,
+        }];
+
+        let sarif = to_sarif(&entries);
+        assert_eq!(
+            sarif["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"]
+                ["uri"],
+            "skills/my-skill/references/api.md"
+        );
+        assert!(to_junit(&entries).contains("SK012 error (references/api.md:3)"));
+    }
+
+    #[test]
+    fn test_junit_pass_and_fail() {
+        let failing = vec![
+            finding("SK004", Severity::Error, Some(2)),
+            finding("SK007", Severity::Warning, None),
+        ];
+        let entries = vec![
+            ReportEntry {
+                name: "good",
+                file: "good/SKILL.md".to_string(),
+                findings: &[],
+            },
+            ReportEntry {
+                name: "bad",
+                file: "bad/SKILL.md".to_string(),
+                findings: &failing,
+            },
+        ];
+
+        let xml = to_junit(&entries);
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("name=\"good\" file=\"good/SKILL.md\"/>"));
+        assert!(xml.contains("<failure type=\"validation\" message=\"1 error(s)\">"));
+        assert!(xml.contains("SK004 error (line 2): SK004 &lt;message&gt;"));
+        assert!(xml.contains("<system-out>SK007 warning: SK007 &lt;message&gt;</system-out>"));
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(xml_escape("a<b>&\"c'"), "a&lt;b&gt;&amp;&quot;c&apos;");
+    }
+}
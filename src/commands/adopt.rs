@@ -5,25 +5,28 @@
 //! and replaces the original with a symlink.
 
 use crate::cli::output::Output;
+use crate::commands::install::check_secrets;
 use crate::core::config::Config;
 use crate::core::errors::SikilError;
+use crate::core::plan::{Plan, Step};
 use crate::core::scanner::Scanner;
 use crate::core::skill::Agent;
-use crate::utils::atomic::atomic_move_dir;
 use crate::utils::paths::{ensure_dir_exists, get_repo_path};
-use crate::utils::symlink::create_symlink;
 use anyhow::Result;
-use fs_err as fs;
 
 /// Arguments for the adopt command
 #[derive(Debug, Clone)]
 pub struct AdoptArgs {
     /// Whether to output in JSON format
     pub json_mode: bool,
+    /// Print the plan instead of adopting
+    pub dry_run: bool,
     /// Name of the skill to adopt
     pub name: String,
     /// Optional agent to adopt from (if multiple locations exist)
     pub from: Option<String>,
+    /// Adopt even if the skill contains possible secrets
+    pub allow_secrets: bool,
 }
 
 /// Executes the adopt command
@@ -31,8 +34,9 @@ pub struct AdoptArgs {
 /// This function:
 /// 1. Finds unmanaged skill by name
 /// 2. If multiple locations, requires `--from`
-/// 3. Moves skill to `~/.sikil/repo/<name>/`
-/// 4. Replaces original with symlink
+/// 3. Scans the skill for secrets (blocks unless `--allow-secrets`)
+/// 4. Moves skill to `~/.sikil/repo/<name>/`
+/// 5. Replaces original with symlink
 ///
 /// # Arguments
 ///
@@ -45,6 +49,7 @@ pub struct AdoptArgs {
 /// - The skill is not found
 /// - The skill is already managed
 /// - Multiple locations exist without `--from`
+/// - The skill contains possible secrets and `allow_secrets` is false
 /// - The skill name already exists in repo
 /// - The move operation fails
 /// - Symlink creation fails
@@ -58,8 +63,10 @@ pub struct AdoptArgs {
 /// let config = Config::default();
 /// let args = AdoptArgs {
 ///     json_mode: false,
+///     dry_run: false,
 ///     name: "my-skill".to_string(),
 ///     from: None,
+///     allow_secrets: false,
 /// };
 /// execute_adopt(args, &config).unwrap();
 /// ```
@@ -139,6 +146,8 @@ pub fn execute_adopt(args: AdoptArgs, config: &Config) -> Result<()> {
         .into());
     }
 
+    check_secrets(source_path, &args.name, args.allow_secrets, &output)?;
+
     // M3-E03-T02-S02: Check skill name not in repo
     let repo_path = get_repo_path();
     ensure_dir_exists(&repo_path).map_err(|_e| SikilError::PermissionDenied {
@@ -164,36 +173,42 @@ pub fn execute_adopt(args: AdoptArgs, config: &Config) -> Result<()> {
         output.print_info("");
     }
 
-    // M3-E03-T01-S05: Move skill to ~/.sikil/repo/<name>/
-    if !args.json_mode {
-        output.print_info("Moving skill to repository...");
-    }
-
-    // M3-E03-T02-S03: Atomic move with rollback on failure
-    atomic_move_dir(source_path, &dest_path)?;
-
-    if !args.json_mode {
-        output.print_success("Skill moved to repository");
+    // M3-E03-T01-S05, M3-E03-T01-S06: Move skill to ~/.sikil/repo/<name>/ and
+    // replace the original with a symlink
+    let mut plan = Plan::new("adopt", &args.name);
+    plan.push(Step::MoveDir {
+        from: source_path.clone(),
+        to: dest_path.clone(),
+    });
+    plan.push(Step::CreateSymlink {
+        target: dest_path.clone(),
+        link: source_path.clone(),
+    });
+    if args.dry_run {
+        output.print_plan(&plan)?;
+        return Ok(());
     }
 
-    // M3-E03-T01-S06: Replace original with symlink
     if !args.json_mode {
-        output.print_info("Creating symlink...");
+        output.print_info("Moving skill to repository...");
     }
 
-    match create_symlink(&dest_path, source_path) {
-        Ok(()) => {
-            if !args.json_mode {
-                output.print_success(&format!("Symlink created at {}", source_path.display()));
-            }
+    // M3-E03-T02-S03: Atomic move; the skill is moved back if linking fails
+    plan.execute(|step| {
+        if args.json_mode {
+            return;
         }
-        Err(e) => {
-            // Rollback: move the skill back
-            let _ = fs::remove_dir_all(&dest_path);
-            let _ = atomic_move_dir(&dest_path, source_path);
-            return Err(e.into());
+        match step {
+            Step::MoveDir { .. } => {
+                output.print_success("Skill moved to repository");
+                output.print_info("Creating symlink...");
+            }
+            Step::CreateSymlink { link, .. } => {
+                output.print_success(&format!("Symlink created at {}", link.display()));
+            }
+            _ => {}
         }
-    }
+    })?;
 
     if !args.json_mode {
         output.print_info("");
@@ -248,8 +263,10 @@ This is a test skill."#,
     fn test_adopt_args() {
         let args = AdoptArgs {
             json_mode: true,
+            dry_run: false,
             name: "my-skill".to_string(),
             from: Some("claude-code".to_string()),
+            allow_secrets: false,
         };
 
         assert!(args.json_mode);
@@ -261,8 +278,10 @@ This is a test skill."#,
     fn test_adopt_args_no_from() {
         let args = AdoptArgs {
             json_mode: false,
+            dry_run: false,
             name: "my-skill".to_string(),
             from: None,
+            allow_secrets: false,
         };
 
         assert!(!args.json_mode);
@@ -289,8 +308,10 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = AdoptArgs {
             json_mode: false,
+            dry_run: false,
             name: "adopt-me".to_string(),
             from: None,
+            allow_secrets: false,
         };
 
         let result = execute_adopt(args, &config);
@@ -355,8 +376,10 @@ This is a test skill."#,
         // Adopt from specific agent
         let args = AdoptArgs {
             json_mode: false,
+            dry_run: false,
             name: "multi-location".to_string(),
             from: Some("windsurf".to_string()),
+            allow_secrets: false,
         };
 
         let result = execute_adopt(args, &config);
@@ -417,8 +440,10 @@ This is a test skill."#,
         // Try to adopt without --from
         let args = AdoptArgs {
             json_mode: false,
+            dry_run: false,
             name: "multi-skill".to_string(),
             from: None,
+            allow_secrets: false,
         };
 
         let result = execute_adopt(args, &config);
@@ -462,8 +487,10 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = AdoptArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             from: None,
+            allow_secrets: false,
         };
 
         let result = execute_adopt(args, &config);
@@ -488,8 +515,10 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = AdoptArgs {
             json_mode: false,
+            dry_run: false,
             name: "non-existent".to_string(),
             from: None,
+            allow_secrets: false,
         };
 
         let result = execute_adopt(args, &config);
@@ -522,8 +551,10 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = AdoptArgs {
             json_mode: false,
+            dry_run: false,
             name: "rollback-test".to_string(),
             from: None,
+            allow_secrets: false,
         };
 
         // The atomic_move_dir should fail because a file exists at the destination
@@ -573,8 +604,10 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = AdoptArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.clone(),
             from: None,
+            allow_secrets: false,
         };
 
         let result = execute_adopt(args, &config);
@@ -621,8 +654,10 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = AdoptArgs {
             json_mode: false,
+            dry_run: false,
             name: "structured-skill".to_string(),
             from: None,
+            allow_secrets: false,
         };
 
         let result = execute_adopt(args, &config);
@@ -0,0 +1,441 @@
+//! Cache command implementation
+//!
+//! This module implements `sikil cache stats|clear|prune|verify` for
+//! inspecting and maintaining the scan cache of the configured backend.
+
+use crate::cli::output::Output;
+use crate::commands::gc::format_bytes;
+use crate::core::cache::{open_cache, Cache, CacheBackend, CacheRunStats};
+use crate::core::config::Config;
+use crate::core::errors::SikilError;
+use crate::core::scanner::Scanner;
+use anyhow::Result;
+use fs_err as fs;
+use std::path::{Path, PathBuf};
+
+/// Arguments for the cache command
+#[derive(Debug, Clone)]
+pub struct CacheArgs {
+    /// Whether to output in JSON format
+    pub json_mode: bool,
+    /// What to do with the cache
+    pub operation: CacheOperation,
+}
+
+/// A cache maintenance operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheOperation {
+    /// Report entry counts, size and the hit rate of the last scan
+    Stats,
+    /// Remove every entry
+    Clear,
+    /// Remove entries for skill directories that no longer exist
+    Prune,
+    /// Re-hash entries and report stale ones the mtime check missed
+    Verify,
+}
+
+/// Oldest entry in the cache
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct OldestEntry {
+    /// Cached skill directory
+    pub path: PathBuf,
+    /// When the entry was cached (unix seconds)
+    pub cached_at: u64,
+}
+
+/// Output of `sikil cache stats`
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CacheStats {
+    /// Configured backend
+    pub backend: CacheBackend,
+    /// File backing the cache
+    pub path: PathBuf,
+    /// Number of cached entries
+    pub entries: usize,
+    /// Entries for directories that held a valid skill
+    pub valid_skills: usize,
+    /// Size of the cache on disk, in bytes
+    pub size_bytes: u64,
+    /// Oldest cached entry, if any
+    pub oldest_entry: Option<OldestEntry>,
+    /// Cache hits and misses of the last scan, if one was recorded
+    pub last_run: Option<CacheRunStats>,
+    /// Fraction of the last scan's lookups answered from the cache
+    pub hit_rate: Option<f64>,
+}
+
+/// Output of `sikil cache clear` and `sikil cache prune`
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CacheCleanup {
+    /// Number of entries removed
+    pub removed: usize,
+}
+
+/// State of a cache entry found by `sikil cache verify`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EntryState {
+    /// The skill directory or its SKILL.md is gone; `prune` removes it
+    Missing,
+    /// SKILL.md or the directory changed; the next scan re-parses it
+    Outdated,
+    /// Passes the mtime and size check but no longer matches its content
+    /// hash, so scans would keep serving old metadata
+    Stale,
+}
+
+/// A cache entry that does not match the filesystem
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct VerifyFinding {
+    /// Cached skill directory
+    pub path: PathBuf,
+    /// How the entry differs from the filesystem
+    pub state: EntryState,
+}
+
+/// Output of `sikil cache verify`
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CacheVerifyReport {
+    /// Number of entries checked
+    pub checked: usize,
+    /// Entries that do not match the filesystem
+    pub findings: Vec<VerifyFinding>,
+}
+
+impl CacheVerifyReport {
+    /// Returns the number of entries the mtime check would not catch
+    pub fn stale_count(&self) -> usize {
+        self.findings
+            .iter()
+            .filter(|f| f.state == EntryState::Stale)
+            .count()
+    }
+}
+
+/// Executes the cache command
+///
+/// # Arguments
+///
+/// * `args` - Cache arguments
+/// * `config` - Configuration selecting the cache backend
+///
+/// # Errors
+///
+/// Returns an error if the cache cannot be opened or modified, and a
+/// `ValidationError` if `verify` finds entries the mtime check missed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sikil::commands::cache::{execute_cache, CacheArgs, CacheOperation};
+/// use sikil::core::config::Config;
+///
+/// let config = Config::default();
+/// let args = CacheArgs {
+///     json_mode: false,
+///     operation: CacheOperation::Stats,
+/// };
+/// execute_cache(args, &config).unwrap();
+/// ```
+pub fn execute_cache(args: CacheArgs, config: &Config) -> Result<()> {
+    let output = Output::new(args.json_mode);
+    let cache = open_cache(&config.cache)?;
+
+    match args.operation {
+        CacheOperation::Stats => {
+            let stats = collect_stats(cache.as_ref(), config.cache.backend)?;
+            if args.json_mode {
+                output.print_json(&stats)?;
+            } else {
+                print_stats(&output, &stats);
+            }
+        }
+        CacheOperation::Clear => {
+            let removed = cache.entries()?.len();
+            cache.clear()?;
+            CacheRunStats::remove(cache.path());
+            if args.json_mode {
+                output.print_json(&CacheCleanup { removed })?;
+            } else {
+                output.print_success(&format!("Cleared {} cache entry(ies)", removed));
+            }
+        }
+        CacheOperation::Prune => {
+            let removed = cache.clean_stale()?;
+            if args.json_mode {
+                output.print_json(&CacheCleanup { removed })?;
+            } else if removed == 0 {
+                output.print_success("No stale cache entries");
+            } else {
+                output.print_success(&format!("Removed {} stale cache entry(ies)", removed));
+            }
+        }
+        CacheOperation::Verify => {
+            let report = verify_cache(cache.as_ref())?;
+            if args.json_mode {
+                output.print_json(&report)?;
+            } else {
+                print_verify_report(&output, &report);
+            }
+
+            let stale = report.stale_count();
+            if stale > 0 {
+                return Err(SikilError::ValidationError {
+                    reason: format!(
+                        "{} cache entry(ies) are stale but pass the mtime check",
+                        stale
+                    ),
+                }
+                .into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Gathers statistics about a cache
+///
+/// # Errors
+///
+/// Returns an error if the cache entries cannot be read.
+pub fn collect_stats(cache: &dyn Cache, backend: CacheBackend) -> Result<CacheStats> {
+    let entries = cache.entries()?;
+    let last_run = CacheRunStats::load(cache.path());
+
+    Ok(CacheStats {
+        backend,
+        path: cache.path().to_path_buf(),
+        entries: entries.len(),
+        valid_skills: entries.iter().filter(|e| e.is_valid_skill).count(),
+        size_bytes: disk_size(cache.path()),
+        oldest_entry: entries
+            .iter()
+            .min_by_key(|e| e.cached_at)
+            .map(|e| OldestEntry {
+                path: e.path.clone(),
+                cached_at: e.cached_at,
+            }),
+        hit_rate: last_run.as_ref().and_then(CacheRunStats::hit_rate),
+        last_run,
+    })
+}
+
+/// Checks every cache entry against the filesystem, re-hashing entries that
+/// pass the mtime and size check
+///
+/// # Errors
+///
+/// Returns an error if the cache entries cannot be read.
+pub fn verify_cache(cache: &dyn Cache) -> Result<CacheVerifyReport> {
+    let entries = cache.entries()?;
+    let mut findings = Vec::new();
+
+    for entry in &entries {
+        let state = if !entry.path.join("SKILL.md").is_file() {
+            Some(EntryState::Missing)
+        } else if !entry.is_fresh() {
+            Some(EntryState::Outdated)
+        } else {
+            match Scanner::recompute_content_hash(entry) {
+                Ok(hash) if hash == entry.content_hash => None,
+                _ => Some(EntryState::Stale),
+            }
+        };
+
+        if let Some(state) = state {
+            findings.push(VerifyFinding {
+                path: entry.path.clone(),
+                state,
+            });
+        }
+    }
+
+    Ok(CacheVerifyReport {
+        checked: entries.len(),
+        findings,
+    })
+}
+
+/// Size of a cache file plus its SQLite write-ahead log, if any
+fn disk_size(path: &Path) -> u64 {
+    let mut wal = path.as_os_str().to_owned();
+    wal.push("-wal");
+
+    [path.to_path_buf(), PathBuf::from(wal)]
+        .iter()
+        .filter_map(|p| fs::metadata(p).ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Prints cache statistics for humans
+fn print_stats(output: &Output, stats: &CacheStats) {
+    let backend = match stats.backend {
+        CacheBackend::Json => "json",
+        CacheBackend::Sqlite => "sqlite",
+    };
+    output.print_info(&format!("Backend:  {} ({})", backend, stats.path.display()));
+    output.print_info(&format!(
+        "Entries:  {} ({} valid skill(s))",
+        stats.entries, stats.valid_skills
+    ));
+    output.print_info(&format!("Size:     {}", format_bytes(stats.size_bytes)));
+
+    match &stats.oldest_entry {
+        Some(oldest) => output.print_info(&format!(
+            "Oldest:   {} ({})",
+            oldest.path.display(),
+            format_age(oldest.cached_at)
+        )),
+        None => output.print_info("Oldest:   -"),
+    }
+
+    match (&stats.last_run, stats.hit_rate) {
+        (Some(run), Some(rate)) => output.print_info(&format!(
+            "Last run: {:.0}% hit rate ({} hit(s), {} miss(es), {})",
+            rate * 100.0,
+            run.hits,
+            run.misses,
+            format_age(run.recorded_at)
+        )),
+        _ => output.print_info("Last run: no cached scan recorded"),
+    }
+}
+
+/// Prints the verify report for humans
+fn print_verify_report(output: &Output, report: &CacheVerifyReport) {
+    for finding in &report.findings {
+        let label = match finding.state {
+            EntryState::Missing => "missing (run 'sikil cache prune')",
+            EntryState::Outdated => "outdated (re-parsed on next scan)",
+            EntryState::Stale => "stale (missed by mtime check)",
+        };
+        output.print_warning(&format!("{}: {}", finding.path.display(), label));
+    }
+
+    if report.findings.is_empty() {
+        output.print_success(&format!(
+            "All {} cache entry(ies) match the filesystem",
+            report.checked
+        ));
+    } else if report.stale_count() > 0 {
+        output.print_info("Run 'sikil cache clear' to rebuild the cache on the next scan");
+    }
+}
+
+/// Formats a unix timestamp as a rough age ("3 days ago")
+fn format_age(timestamp: u64) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let seconds = now.saturating_sub(timestamp);
+
+    match seconds {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{} minute(s) ago", seconds / 60),
+        3600..=86_399 => format!("{} hour(s) ago", seconds / 3600),
+        _ => format!("{} day(s) ago", seconds / 86_400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cache::{JsonCache, ScanEntry, SkillStamp};
+    use tempfile::TempDir;
+
+    /// Creates a skill directory and caches it the way the scanner would
+    fn cache_skill(cache: &JsonCache, root: &Path, name: &str, cached_at: u64) -> ScanEntry {
+        let dir = root.join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("SKILL.md"), format!("# {}", name)).unwrap();
+        let stamp = SkillStamp::read(&dir).unwrap();
+
+        let mut entry = ScanEntry {
+            path: dir,
+            mtime: stamp.mtime,
+            size: stamp.size,
+            dir_mtime: stamp.dir_mtime,
+            content_hash: String::new(),
+            cached_at,
+            skill_name: Some(name.to_string()),
+            is_valid_skill: true,
+            metadata: None,
+        };
+        entry.content_hash = Scanner::recompute_content_hash(&entry).unwrap();
+        cache.put(&entry).unwrap();
+        entry
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(u64::MAX), "just now");
+        assert_eq!(format_age(0).split(' ').nth(1), Some("day(s)"));
+    }
+
+    #[test]
+    fn test_collect_stats() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_path = temp_dir.path().join("cache.json");
+        let cache = JsonCache::open_at(&cache_path).unwrap();
+        cache_skill(&cache, temp_dir.path(), "newer", 200);
+        let older = cache_skill(&cache, temp_dir.path(), "older", 100);
+        CacheRunStats {
+            hits: 3,
+            misses: 1,
+            recorded_at: 300,
+        }
+        .save(&cache_path)
+        .unwrap();
+
+        let stats = collect_stats(&cache, CacheBackend::Json).unwrap();
+
+        assert_eq!(stats.entries, 2);
+        assert_eq!(stats.valid_skills, 2);
+        assert!(stats.size_bytes > 0);
+        assert_eq!(stats.oldest_entry.unwrap().path, older.path);
+        assert_eq!(stats.hit_rate, Some(0.75));
+    }
+
+    #[test]
+    fn test_verify_cache_reports_each_state() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = JsonCache::open_at(&temp_dir.path().join("cache.json")).unwrap();
+        cache_skill(&cache, temp_dir.path(), "ok", 1);
+        let missing = cache_skill(&cache, temp_dir.path(), "missing", 1);
+        let outdated = cache_skill(&cache, temp_dir.path(), "outdated", 1);
+        let stale = cache_skill(&cache, temp_dir.path(), "stale", 1);
+
+        fs::remove_dir_all(&missing.path).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        fs::write(outdated.path.join("SKILL.md"), "# outdated, edited").unwrap();
+        // A nested file changes the directory size without touching the
+        // skill directory's own mtime or SKILL.md
+        let nested = stale.path.join("docs");
+        fs::create_dir(&nested).unwrap();
+        let mut refreshed = stale.clone();
+        let stamp = SkillStamp::read(&stale.path).unwrap();
+        refreshed.dir_mtime = stamp.dir_mtime;
+        cache.put(&refreshed).unwrap();
+        fs::write(nested.join("extra.md"), "more content").unwrap();
+
+        let report = verify_cache(&cache).unwrap();
+
+        assert_eq!(report.checked, 4);
+        let state_of = |path: &Path| {
+            report
+                .findings
+                .iter()
+                .find(|f| f.path == path)
+                .map(|f| f.state)
+        };
+        assert_eq!(state_of(&missing.path), Some(EntryState::Missing));
+        assert_eq!(state_of(&outdated.path), Some(EntryState::Outdated));
+        assert_eq!(state_of(&stale.path), Some(EntryState::Stale));
+        assert_eq!(report.findings.len(), 3);
+        assert_eq!(report.stale_count(), 1);
+    }
+}
@@ -366,6 +366,6 @@ mod tests {
             agents,
         };
 
-        assert_eq!(display.agents["claude-code"].is_default, true);
+        assert!(display.agents["claude-code"].is_default);
     }
 }
@@ -0,0 +1,676 @@
+//! Doctor command implementation
+//!
+//! This module provides an environment health check that inspects
+//! everything sikil depends on: agent directories, the managed
+//! repository, symlinks, the scan cache, Git and leftover temporary clones.
+
+use crate::cli::output::Output;
+use crate::core::cache::{check_cache_at, CacheBackend, CacheHealth};
+use crate::core::config::Config;
+use crate::core::errors::SikilError;
+use crate::core::scanner::Scanner;
+use crate::core::skill::{Agent, Scope};
+use crate::utils::git::{find_leftover_clones, is_git_available, STALE_CLONE_AGE};
+use crate::utils::paths::get_repo_path;
+use crate::utils::symlink::{list_symlinks, resolve_realpath};
+use anyhow::Result;
+use fs_err as fs;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Arguments for the doctor command
+#[derive(Debug, Clone)]
+pub struct DoctorArgs {
+    /// Whether to output in JSON format
+    pub json_mode: bool,
+}
+
+/// Outcome of a single health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    /// Nothing to report
+    Pass,
+    /// Something is off but sikil keeps working
+    Warn,
+    /// Something is broken and needs attention
+    Fail,
+}
+
+/// Result of a single health check
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DoctorCheck {
+    /// Name of the check
+    pub name: String,
+    /// Overall status of the check
+    pub status: CheckStatus,
+    /// One-line summary
+    pub message: String,
+    /// Individual findings behind a warning or failure
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<String>,
+}
+
+impl DoctorCheck {
+    fn new(name: &str, status: CheckStatus, message: String) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            message,
+            details: Vec::new(),
+        }
+    }
+
+    fn with_details(mut self, details: Vec<String>) -> Self {
+        self.details = details;
+        self
+    }
+}
+
+/// Counts of check results by status
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DoctorSummary {
+    /// Number of passing checks
+    pub passed: usize,
+    /// Number of checks with warnings
+    pub warnings: usize,
+    /// Number of failing checks
+    pub failures: usize,
+}
+
+/// Full doctor report
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DoctorReport {
+    /// All checks in the order they were run
+    pub checks: Vec<DoctorCheck>,
+    /// Counts by status
+    pub summary: DoctorSummary,
+}
+
+impl DoctorReport {
+    /// Builds a report from a list of checks
+    pub fn new(checks: Vec<DoctorCheck>) -> Self {
+        let count = |status| checks.iter().filter(|c| c.status == status).count();
+        let summary = DoctorSummary {
+            passed: count(CheckStatus::Pass),
+            warnings: count(CheckStatus::Warn),
+            failures: count(CheckStatus::Fail),
+        };
+        Self { checks, summary }
+    }
+}
+
+/// Locations inspected by the doctor command
+#[derive(Debug, Clone)]
+pub struct DoctorPaths {
+    /// Managed skills repository (`~/.sikil/repo`)
+    pub repo_path: PathBuf,
+    /// Scan cache file of the configured backend (`~/.sikil/cache.json` or
+    /// `~/.sikil/cache.db`)
+    pub cache_path: PathBuf,
+    /// Directory holding temporary clones
+    pub temp_root: PathBuf,
+    /// Root for resolving relative workspace paths
+    pub workspace_root: PathBuf,
+}
+
+impl DoctorPaths {
+    /// Returns the default locations for the current user and directory
+    pub fn from_env(config: &Config) -> Self {
+        Self {
+            repo_path: get_repo_path(),
+            cache_path: config.cache.backend.default_path(),
+            temp_root: std::env::temp_dir(),
+            workspace_root: std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+        }
+    }
+}
+
+/// Executes the doctor command
+///
+/// This function runs every health check, prints a pass/warn/fail line for
+/// each one and fails if any check failed, so scripts can gate on the exit
+/// code.
+///
+/// # Arguments
+///
+/// * `args` - Doctor arguments
+/// * `config` - Configuration to check
+///
+/// # Errors
+///
+/// Returns `ValidationError` if at least one check failed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sikil::commands::doctor::{execute_doctor, DoctorArgs};
+/// use sikil::core::config::Config;
+///
+/// let config = Config::default();
+/// execute_doctor(DoctorArgs { json_mode: false }, &config).unwrap();
+/// ```
+pub fn execute_doctor(args: DoctorArgs, config: &Config) -> Result<()> {
+    let output = Output::new(args.json_mode);
+
+    let report = DoctorReport::new(run_checks(config, &DoctorPaths::from_env(config)));
+
+    if args.json_mode {
+        output.print_json(&report)?;
+    } else {
+        print_human_readable(&output, &report);
+    }
+
+    if report.summary.failures > 0 {
+        return Err(SikilError::ValidationError {
+            reason: format!("{} health check(s) failed", report.summary.failures),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Runs all health checks against the given configuration and locations
+pub fn run_checks(config: &Config, paths: &DoctorPaths) -> Vec<DoctorCheck> {
+    let scanner = Scanner::without_cache(config.clone())
+        .with_workspace_root(&paths.workspace_root)
+        .with_repo_root(&paths.repo_path);
+    let agent_dirs = scanner.agent_directories();
+
+    vec![
+        check_config(config),
+        check_agent_directories(&agent_dirs),
+        check_repo(&paths.repo_path),
+        check_dangling_symlinks(&agent_dirs),
+        check_orphaned_repo_entries(&paths.repo_path, &agent_dirs),
+        check_cache(config.cache.backend, &paths.cache_path),
+        check_git(),
+        check_temp_clones(&paths.temp_root, STALE_CLONE_AGE),
+    ]
+}
+
+/// Checks for config entries that do not correspond to a supported agent
+fn check_config(config: &Config) -> DoctorCheck {
+    let mut unknown: Vec<&String> = config
+        .agents
+        .keys()
+        .filter(|name| Agent::from_cli_name(name).is_none())
+        .collect();
+    unknown.sort();
+
+    let enabled = config.agents.values().filter(|a| a.enabled).count();
+
+    if !unknown.is_empty() {
+        let details = unknown
+            .iter()
+            .map(|name| format!("agents.{} is not a supported agent and is ignored", name))
+            .collect();
+        return DoctorCheck::new(
+            "Configuration",
+            CheckStatus::Warn,
+            format!("{} unknown agent(s) in config", unknown.len()),
+        )
+        .with_details(details);
+    }
+
+    if enabled == 0 {
+        return DoctorCheck::new(
+            "Configuration",
+            CheckStatus::Warn,
+            "no agents are enabled".to_string(),
+        );
+    }
+
+    DoctorCheck::new(
+        "Configuration",
+        CheckStatus::Pass,
+        format!(
+            "{} agent(s) configured, {} enabled",
+            config.agents.len(),
+            enabled
+        ),
+    )
+}
+
+/// Checks that agent skill directories are usable
+///
+/// A missing global directory is only a warning because install creates it.
+/// Workspace directories are optional and only checked when they exist.
+fn check_agent_directories(agent_dirs: &[(Agent, Scope, PathBuf)]) -> DoctorCheck {
+    let mut status = CheckStatus::Pass;
+    let mut details = Vec::new();
+    let mut checked = 0;
+
+    for (agent, scope, path) in agent_dirs {
+        let label = format!("{} ({})", agent, scope_name(*scope));
+
+        if !path.exists() {
+            if *scope == Scope::Global {
+                status = status.max(CheckStatus::Warn);
+                details.push(format!("{}: {} does not exist", label, path.display()));
+            }
+            continue;
+        }
+
+        checked += 1;
+        if !path.is_dir() {
+            status = CheckStatus::Fail;
+            details.push(format!("{}: {} is not a directory", label, path.display()));
+        } else if !is_writable(path) {
+            status = CheckStatus::Fail;
+            details.push(format!("{}: {} is not writable", label, path.display()));
+        }
+    }
+
+    let message = match status {
+        CheckStatus::Pass => format!("{} agent directory(ies) usable", checked),
+        _ => format!("{} problem(s) found", details.len()),
+    };
+
+    DoctorCheck::new("Agent directories", status, message).with_details(details)
+}
+
+/// Checks that the managed repository is a writable directory
+fn check_repo(repo_path: &Path) -> DoctorCheck {
+    if !repo_path.exists() && !repo_path.is_symlink() {
+        return DoctorCheck::new(
+            "Repository",
+            CheckStatus::Pass,
+            format!("{} not created yet", repo_path.display()),
+        );
+    }
+
+    if !repo_path.is_dir() {
+        return DoctorCheck::new(
+            "Repository",
+            CheckStatus::Fail,
+            format!("{} is not a directory", repo_path.display()),
+        );
+    }
+
+    if !is_writable(repo_path) {
+        return DoctorCheck::new(
+            "Repository",
+            CheckStatus::Fail,
+            format!("{} is not writable", repo_path.display()),
+        );
+    }
+
+    let entries = repo_entries(repo_path);
+    let invalid: Vec<String> = entries
+        .iter()
+        .filter(|path| !path.join("SKILL.md").exists())
+        .map(|path| format!("{} has no SKILL.md", path.display()))
+        .collect();
+
+    if !invalid.is_empty() {
+        return DoctorCheck::new(
+            "Repository",
+            CheckStatus::Warn,
+            format!("{} invalid repository entry(ies)", invalid.len()),
+        )
+        .with_details(invalid);
+    }
+
+    DoctorCheck::new(
+        "Repository",
+        CheckStatus::Pass,
+        format!("{} managed skill(s)", entries.len()),
+    )
+}
+
+/// Checks agent directories for symlinks whose target no longer exists
+fn check_dangling_symlinks(agent_dirs: &[(Agent, Scope, PathBuf)]) -> DoctorCheck {
+    let mut details = Vec::new();
+
+    for (agent, _, dir) in agent_dirs {
+        for link in list_symlinks(dir) {
+            if !link.exists() {
+                let target = fs::read_link(&link)
+                    .map(|t| t.display().to_string())
+                    .unwrap_or_else(|_| "?".to_string());
+                details.push(format!("{}: {} -> {}", agent, link.display(), target));
+            }
+        }
+    }
+
+    if details.is_empty() {
+        DoctorCheck::new(
+            "Symlinks",
+            CheckStatus::Pass,
+            "no dangling symlinks".to_string(),
+        )
+    } else {
+        DoctorCheck::new(
+            "Symlinks",
+            CheckStatus::Warn,
+            format!("{} dangling symlink(s)", details.len()),
+        )
+        .with_details(details)
+    }
+}
+
+/// Checks for repository entries that no agent directory links to
+fn check_orphaned_repo_entries(
+    repo_path: &Path,
+    agent_dirs: &[(Agent, Scope, PathBuf)],
+) -> DoctorCheck {
+    let linked: HashSet<PathBuf> = agent_dirs
+        .iter()
+        .flat_map(|(_, _, dir)| list_symlinks(dir))
+        .filter_map(|link| resolve_realpath(&link).ok())
+        .collect();
+
+    let details: Vec<String> = repo_entries(repo_path)
+        .into_iter()
+        .filter(|entry| {
+            let real = resolve_realpath(entry).unwrap_or_else(|_| entry.clone());
+            !linked.contains(&real)
+        })
+        .map(|entry| format!("{} is not linked from any agent", entry.display()))
+        .collect();
+
+    if details.is_empty() {
+        DoctorCheck::new(
+            "Orphaned skills",
+            CheckStatus::Pass,
+            "every managed skill is installed".to_string(),
+        )
+    } else {
+        DoctorCheck::new(
+            "Orphaned skills",
+            CheckStatus::Warn,
+            format!("{} managed skill(s) not installed anywhere", details.len()),
+        )
+        .with_details(details)
+    }
+}
+
+/// Checks that the scan cache file can be loaded
+fn check_cache(backend: CacheBackend, cache_path: &Path) -> DoctorCheck {
+    let health = check_cache_at(backend, cache_path);
+
+    match health {
+        CacheHealth::Missing => {
+            DoctorCheck::new("Cache", CheckStatus::Pass, "no cache file yet".to_string())
+        }
+        CacheHealth::Healthy { entries } => DoctorCheck::new(
+            "Cache",
+            CheckStatus::Pass,
+            format!("{} cached entry(ies)", entries),
+        ),
+        CacheHealth::TooLarge { size } => DoctorCheck::new(
+            "Cache",
+            CheckStatus::Warn,
+            format!(
+                "{} is {} bytes and will be cleared",
+                cache_path.display(),
+                size
+            ),
+        ),
+        CacheHealth::VersionMismatch { found } => DoctorCheck::new(
+            "Cache",
+            CheckStatus::Warn,
+            format!(
+                "{} uses format version {} and will be rebuilt",
+                cache_path.display(),
+                found
+            ),
+        ),
+        CacheHealth::Corrupt { reason } => DoctorCheck::new(
+            "Cache",
+            CheckStatus::Fail,
+            format!("{} is corrupt: {}", cache_path.display(), reason),
+        ),
+    }
+}
+
+/// Checks that Git is available for installing from repositories
+fn check_git() -> DoctorCheck {
+    if is_git_available() {
+        DoctorCheck::new("Git", CheckStatus::Pass, "git is installed".to_string())
+    } else {
+        DoctorCheck::new(
+            "Git",
+            CheckStatus::Warn,
+            "git is not installed; installing from Git URLs will fail".to_string(),
+        )
+    }
+}
+
+/// Checks for temporary clones left behind by interrupted installs
+///
+/// Clones younger than `min_age` may belong to a running install and are
+/// not reported.
+fn check_temp_clones(temp_root: &Path, min_age: Duration) -> DoctorCheck {
+    let leftovers = find_leftover_clones(temp_root, min_age);
+
+    if leftovers.is_empty() {
+        DoctorCheck::new(
+            "Temporary clones",
+            CheckStatus::Pass,
+            "no leftover clones".to_string(),
+        )
+    } else {
+        DoctorCheck::new(
+            "Temporary clones",
+            CheckStatus::Warn,
+            format!("{} leftover clone(s)", leftovers.len()),
+        )
+        .with_details(leftovers.iter().map(|p| p.display().to_string()).collect())
+    }
+}
+
+/// Returns the non-hidden subdirectories of the repository, sorted by path
+fn repo_entries(repo_path: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<PathBuf> = match fs::read_dir(repo_path) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .filter(|p| {
+                p.file_name()
+                    .map(|n| !n.to_string_lossy().starts_with('.'))
+                    .unwrap_or(false)
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    entries.sort();
+    entries
+}
+
+/// Returns true if a file can be created in the directory
+fn is_writable(dir: &Path) -> bool {
+    tempfile::Builder::new()
+        .prefix(".sikil-doctor-")
+        .tempfile_in(dir)
+        .is_ok()
+}
+
+/// Formats the scope enum as a display string
+fn scope_name(scope: Scope) -> &'static str {
+    match scope {
+        Scope::Global => "global",
+        Scope::Workspace => "workspace",
+    }
+}
+
+/// Prints human-readable output for the doctor command
+fn print_human_readable(output: &Output, report: &DoctorReport) {
+    for check in &report.checks {
+        let line = format!("{}: {}", check.name, check.message);
+        match check.status {
+            CheckStatus::Pass => output.print_success(&format!("✓ {}", line)),
+            CheckStatus::Warn => output.print_warning(&format!("⚠ {}", line)),
+            CheckStatus::Fail => output.print_error(&format!("✗ {}", line)),
+        }
+        for detail in &check.details {
+            output.print_info(&format!("    - {}", detail));
+        }
+    }
+
+    output.print_info("");
+    output.print_info(&format!(
+        "{} passed, {} warning(s), {} failure(s)",
+        report.summary.passed, report.summary.warnings, report.summary.failures
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::AgentConfig;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn test_paths(base: &Path) -> DoctorPaths {
+        DoctorPaths {
+            repo_path: base.join("repo"),
+            cache_path: base.join("cache.json"),
+            temp_root: base.join("tmp"),
+            workspace_root: base.join("workspace"),
+        }
+    }
+
+    fn test_config(agent_dir: &Path) -> Config {
+        let mut config = Config::new();
+        config.insert_agent(
+            "claude-code".to_string(),
+            AgentConfig::new(true, agent_dir.to_path_buf(), PathBuf::from(".skills")),
+        );
+        config
+    }
+
+    fn find<'a>(checks: &'a [DoctorCheck], name: &str) -> &'a DoctorCheck {
+        checks.iter().find(|c| c.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_report_summary_counts() {
+        let report = DoctorReport::new(vec![
+            DoctorCheck::new("a", CheckStatus::Pass, String::new()),
+            DoctorCheck::new("b", CheckStatus::Warn, String::new()),
+            DoctorCheck::new("c", CheckStatus::Fail, String::new()),
+            DoctorCheck::new("d", CheckStatus::Pass, String::new()),
+        ]);
+
+        assert_eq!(report.summary.passed, 2);
+        assert_eq!(report.summary.warnings, 1);
+        assert_eq!(report.summary.failures, 1);
+    }
+
+    #[test]
+    fn test_check_status_serializes_lowercase() {
+        let json = serde_json::to_string(&CheckStatus::Warn).unwrap();
+        assert_eq!(json, "\"warn\"");
+    }
+
+    #[test]
+    fn test_healthy_environment_passes() {
+        let temp_dir = TempDir::new().unwrap();
+        let agent_dir = temp_dir.path().join("agent");
+        fs::create_dir_all(&agent_dir).unwrap();
+
+        let checks = run_checks(&test_config(&agent_dir), &test_paths(temp_dir.path()));
+
+        for name in [
+            "Configuration",
+            "Agent directories",
+            "Repository",
+            "Symlinks",
+            "Orphaned skills",
+            "Cache",
+            "Temporary clones",
+        ] {
+            assert_eq!(find(&checks, name).status, CheckStatus::Pass, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_unknown_agent_in_config_warns() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(temp_dir.path());
+        config.insert_agent(
+            "mystery-agent".to_string(),
+            AgentConfig::new(true, PathBuf::from("/x"), PathBuf::from(".x")),
+        );
+
+        let check = check_config(&config);
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.details[0].contains("mystery-agent"));
+    }
+
+    #[test]
+    fn test_missing_agent_directory_warns() {
+        let temp_dir = TempDir::new().unwrap();
+        let agent_dir = temp_dir.path().join("missing");
+
+        let checks = run_checks(&test_config(&agent_dir), &test_paths(temp_dir.path()));
+        assert_eq!(find(&checks, "Agent directories").status, CheckStatus::Warn);
+    }
+
+    #[test]
+    fn test_agent_path_is_file_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        let agent_path = temp_dir.path().join("agent");
+        fs::write(&agent_path, "not a dir").unwrap();
+
+        let checks = run_checks(&test_config(&agent_path), &test_paths(temp_dir.path()));
+        assert_eq!(find(&checks, "Agent directories").status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn test_repo_not_a_directory_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("repo"), "oops").unwrap();
+
+        let check = check_repo(&temp_dir.path().join("repo"));
+        assert_eq!(check.status, CheckStatus::Fail);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_dangling_symlink_and_orphan_detected() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = test_paths(temp_dir.path());
+        let agent_dir = temp_dir.path().join("agent");
+        fs::create_dir_all(&agent_dir).unwrap();
+        fs::create_dir_all(paths.repo_path.join("orphan-skill")).unwrap();
+        fs::write(paths.repo_path.join("orphan-skill/SKILL.md"), "---\n---").unwrap();
+        std::os::unix::fs::symlink(paths.repo_path.join("gone"), agent_dir.join("gone")).unwrap();
+
+        let checks = run_checks(&test_config(&agent_dir), &paths);
+
+        let symlinks = find(&checks, "Symlinks");
+        assert_eq!(symlinks.status, CheckStatus::Warn);
+        assert_eq!(symlinks.details.len(), 1);
+
+        let orphans = find(&checks, "Orphaned skills");
+        assert_eq!(orphans.status, CheckStatus::Warn);
+        assert!(orphans.details[0].contains("orphan-skill"));
+    }
+
+    #[test]
+    fn test_corrupt_cache_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = test_paths(temp_dir.path());
+        fs::write(&paths.cache_path, "not json").unwrap();
+
+        let check = check_cache(CacheBackend::Json, &paths.cache_path);
+        assert_eq!(check.status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn test_leftover_clone_warns() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = test_paths(temp_dir.path());
+        fs::create_dir_all(paths.temp_root.join("sikil-clone-abc123")).unwrap();
+
+        let check = check_temp_clones(&paths.temp_root, Duration::ZERO);
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert_eq!(check.details.len(), 1);
+    }
+}
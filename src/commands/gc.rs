@@ -0,0 +1,693 @@
+//! Garbage collection command implementation
+//!
+//! This module removes data sikil no longer needs: managed skills in the
+//! repository that no agent links to, temporary clones left behind by
+//! interrupted Git installs, and cache entries for skills that are gone.
+//!
+//! Orphans are found by scanning every configured agent, enabled or not, and
+//! the current workspace. Workspaces of other projects cannot be scanned, so
+//! while an agent has a relative workspace path, orphaned skills are only
+//! removed after the user confirms at the prompt.
+//!
+//! Removals run as a plan under the journal: orphaned skills are moved to the
+//! trash like `sikil remove` does, and temporary clones are deleted.
+
+use crate::cli::output::Output;
+use crate::commands::prompt_confirmation;
+use crate::core::cache::{open_cache, Cache};
+use crate::core::config::Config;
+use crate::core::errors::SikilError;
+use crate::core::plan::{Plan, Step};
+use crate::core::scanner::Scanner;
+use crate::core::trash::TrashEntry;
+use crate::utils::git::{find_leftover_clones, STALE_CLONE_AGE};
+use crate::utils::paths::{dir_size, get_journal_dir, get_repo_path, get_trash_dir};
+use crate::utils::time::now_secs;
+use anyhow::Result;
+use fs_err as fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Arguments for the gc command
+#[derive(Debug, Clone)]
+pub struct GcArgs {
+    /// Whether to output in JSON format
+    pub json_mode: bool,
+    /// Print the removal plan instead of running it
+    pub dry_run: bool,
+    /// Skip confirmation prompt
+    pub yes: bool,
+}
+
+/// Kind of garbage found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GcKind {
+    /// Managed skill in the repository with no agent installations
+    OrphanSkill,
+    /// Temporary clone directory left behind by a Git install
+    TempClone,
+    /// Cache entry for a skill directory that no longer exists
+    CacheEntry,
+}
+
+/// A single item that gc can remove
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct GcItem {
+    /// What kind of item this is
+    pub kind: GcKind,
+    /// Path of the item (the cached path for cache entries)
+    pub path: PathBuf,
+    /// Disk space used by the item, in bytes
+    pub bytes: u64,
+    /// Whether the item was removed (moved to the trash, for orphaned skills)
+    pub removed: bool,
+}
+
+/// Result of a gc run
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct GcReport {
+    /// Everything that was found
+    pub items: Vec<GcItem>,
+    /// Total disk space reclaimed, in bytes, not counting orphaned skills
+    /// moved to the trash
+    pub reclaimed_bytes: u64,
+}
+
+/// Executes the gc command
+///
+/// This function:
+/// 1. Finds orphaned repo skills, leftover temp clones and stale cache entries
+/// 2. Lists them with their size, or prints the removal plan under --dry-run
+/// 3. Asks for confirmation (unless --yes or JSON mode)
+/// 4. Removes them and reports the reclaimed disk space
+///
+/// Without a prompt, orphaned skills are kept if links from outside the scan
+/// could exist (see [`links_may_exist_outside_scan`]).
+///
+/// # Arguments
+///
+/// * `args` - Gc arguments
+/// * `config` - Configuration for resolving agent paths
+///
+/// # Errors
+///
+/// Returns an error if the user cancels or an item cannot be removed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sikil::commands::gc::{execute_gc, GcArgs};
+/// use sikil::core::config::Config;
+///
+/// let config = Config::default();
+/// let args = GcArgs {
+///     json_mode: false,
+///     dry_run: false,
+///     yes: true,
+/// };
+/// execute_gc(args, &config).unwrap();
+/// ```
+pub fn execute_gc(args: GcArgs, config: &Config) -> Result<()> {
+    let output = Output::new(args.json_mode);
+    let repo_path = get_repo_path();
+    let trash_dir = get_trash_dir();
+    let cache = open_cache(&config.cache).ok();
+
+    let mut items = find_garbage(
+        config,
+        &repo_path,
+        &std::env::temp_dir(),
+        STALE_CLONE_AGE,
+        cache.as_deref(),
+    );
+
+    if args.dry_run {
+        // Shows what confirming at the prompt would do
+        let (plan, _) = plan_garbage(&items, &repo_path, true, &trash_dir, now_secs());
+        output.print_plan(&plan)?;
+        let stale = items
+            .iter()
+            .filter(|i| i.kind == GcKind::CacheEntry)
+            .count();
+        if stale > 0 && !args.json_mode {
+            output.print_info(&format!(
+                "{} stale cache entry(ies) would also be removed",
+                stale
+            ));
+        }
+        return Ok(());
+    }
+
+    if items.is_empty() {
+        if args.json_mode {
+            output.print_json(&GcReport {
+                items,
+                reclaimed_bytes: 0,
+            })?;
+        } else {
+            output.print_success("Nothing to clean up");
+        }
+        return Ok(());
+    }
+
+    if !args.json_mode {
+        output.print_info(&format!("Found {} item(s) to clean up:", items.len()));
+        for item in &items {
+            output.print_info(&format!(
+                "  - {} {} ({})",
+                kind_label(item.kind),
+                item.path.display(),
+                format_bytes(item.bytes)
+            ));
+        }
+        output.print_info("");
+    }
+
+    let interactive = !args.yes && !args.json_mode;
+    let orphans = items
+        .iter()
+        .filter(|i| i.kind == GcKind::OrphanSkill)
+        .count();
+    let unscanned_links = orphans > 0 && links_may_exist_outside_scan(config);
+
+    if interactive {
+        if unscanned_links {
+            output.print_warning(
+                "Orphaned skills may still be linked from other projects' workspace directories, which gc cannot scan.",
+            );
+        }
+        let confirmed = prompt_confirmation("Remove these items?")?;
+
+        if !confirmed {
+            output.print_warning("Operation cancelled by user.");
+            return Err(SikilError::PermissionDenied {
+                operation: "garbage collection".to_string(),
+                path: repo_path,
+            }
+            .into());
+        }
+    }
+
+    // Refuse to remove orphans unattended when links to them could be unseen
+    let remove_orphans = interactive || !unscanned_links;
+    if !remove_orphans && !args.json_mode {
+        output.print_warning(&format!(
+            "Kept {} orphaned skill(s): other projects' workspace directories are not scanned and may link to them. Run `sikil gc` without --yes to confirm, or `sikil remove <skill>`.",
+            orphans
+        ));
+    }
+
+    let reclaimed_bytes = remove_garbage(
+        &mut items,
+        &repo_path,
+        remove_orphans,
+        &trash_dir,
+        &get_journal_dir(),
+        cache.as_deref(),
+    )?;
+
+    if args.json_mode {
+        output.print_json(&GcReport {
+            items,
+            reclaimed_bytes,
+        })?;
+    } else {
+        output.print_success(&format!(
+            "Removed {} item(s), reclaimed {}",
+            items.iter().filter(|i| i.removed).count(),
+            format_bytes(reclaimed_bytes)
+        ));
+        let trashed = items
+            .iter()
+            .filter(|i| i.kind == GcKind::OrphanSkill && i.removed)
+            .count();
+        if trashed > 0 {
+            output.print_info(&format!(
+                "Moved {} orphaned skill(s) to the trash; `sikil trash empty` frees their space",
+                trashed
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds everything gc would remove, without changing anything
+///
+/// # Arguments
+///
+/// * `config` - Configuration for resolving agent paths
+/// * `repo_path` - Managed skills repository
+/// * `temp_root` - Directory holding temporary clones
+/// * `clone_min_age` - How old a temporary clone must be to count as left behind
+/// * `cache` - Scan cache to check for stale entries, if available
+pub fn find_garbage(
+    config: &Config,
+    repo_path: &Path,
+    temp_root: &Path,
+    clone_min_age: Duration,
+    cache: Option<&dyn Cache>,
+) -> Vec<GcItem> {
+    let mut items = Vec::new();
+
+    // A disabled agent may still link to repo skills, so scan every agent
+    let mut all_agents = config.clone();
+    for agent in all_agents.agents.values_mut() {
+        agent.enabled = true;
+    }
+    let scan_result = Scanner::without_cache(all_agents)
+        .with_repo_root(repo_path)
+        .scan_all_agents();
+    let mut orphans: Vec<PathBuf> = scan_result
+        .all_skills()
+        .into_iter()
+        .filter(|skill| skill.is_managed && skill.is_orphan())
+        .filter_map(|skill| skill.repo_path)
+        .filter(|path| path.starts_with(repo_path))
+        .collect();
+    orphans.sort();
+
+    for path in orphans {
+        items.push(GcItem {
+            kind: GcKind::OrphanSkill,
+            bytes: dir_size(&path),
+            path,
+            removed: false,
+        });
+    }
+
+    for path in find_leftover_clones(temp_root, clone_min_age) {
+        items.push(GcItem {
+            kind: GcKind::TempClone,
+            bytes: dir_size(&path),
+            path,
+            removed: false,
+        });
+    }
+
+    if let Some(cache) = cache {
+        for path in cache.stale_paths() {
+            items.push(GcItem {
+                kind: GcKind::CacheEntry,
+                path,
+                bytes: 0,
+                removed: false,
+            });
+        }
+    }
+
+    items
+}
+
+/// Returns true if symlinks to repo skills could exist where gc cannot look
+///
+/// A relative workspace path is only scanned below the current directory, so
+/// the workspaces of other projects are never seen.
+pub fn links_may_exist_outside_scan(config: &Config) -> bool {
+    config
+        .agents
+        .values()
+        .any(|agent| agent.workspace_path.is_relative())
+}
+
+/// Builds the plan that removes the given items
+///
+/// Each orphaned skill is moved into its own trash entry, returned alongside
+/// the plan and not yet saved; they are left out unless `remove_orphans` is
+/// set. Temporary clones are deleted. Stale cache entries are not filesystem
+/// steps and are not part of the plan.
+pub fn plan_garbage(
+    items: &[GcItem],
+    repo_path: &Path,
+    remove_orphans: bool,
+    trash_dir: &Path,
+    now: u64,
+) -> (Plan, Vec<TrashEntry>) {
+    let mut plan = Plan::new("gc", "");
+    let mut trashed = Vec::new();
+
+    for item in items {
+        match item.kind {
+            GcKind::OrphanSkill => {
+                // Never touch anything outside the repository
+                if !remove_orphans || !item.path.starts_with(repo_path) {
+                    continue;
+                }
+                let name = item
+                    .path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let mut entry = TrashEntry::new(trash_dir, &name, now);
+                entry.trash_repo(&item.path, &mut plan);
+                trashed.push(entry);
+            }
+            GcKind::TempClone => plan.push(Step::RemoveDir {
+                path: item.path.clone(),
+            }),
+            GcKind::CacheEntry => {}
+        }
+    }
+
+    (plan, trashed)
+}
+
+/// Removes the given items and returns the number of bytes reclaimed
+///
+/// The plan from [`plan_garbage`] runs under a journal in `journal_dir`;
+/// orphaned skills are only moved to `trash_dir` if `remove_orphans` is set.
+/// Stale cache entries are removed together; the space they free is the
+/// amount the cache file shrinks by.
+///
+/// # Errors
+///
+/// Returns an error if a directory cannot be moved or removed, after rolling
+/// back the steps that had completed.
+pub fn remove_garbage(
+    items: &mut [GcItem],
+    repo_path: &Path,
+    remove_orphans: bool,
+    trash_dir: &Path,
+    journal_dir: &Path,
+    cache: Option<&dyn Cache>,
+) -> Result<u64> {
+    let mut reclaimed = 0;
+
+    let (plan, trashed) = plan_garbage(items, repo_path, remove_orphans, trash_dir, now_secs());
+    for entry in &trashed {
+        entry.save()?;
+    }
+    let executed = plan.execute_in(journal_dir, None, |step| {
+        let removed_path = match step {
+            Step::MoveDir { from, .. } => from,
+            Step::RemoveDir { path } => path,
+            _ => return,
+        };
+        if let Some(item) = items.iter_mut().find(|i| &i.path == removed_path) {
+            item.removed = true;
+            if item.kind == GcKind::TempClone {
+                reclaimed += item.bytes;
+            }
+        }
+    });
+    if let Err(e) = executed {
+        for entry in &trashed {
+            entry.discard_unused();
+        }
+        return Err(e.into());
+    }
+
+    let has_cache_entries = items.iter().any(|i| i.kind == GcKind::CacheEntry);
+    if let (Some(cache), true) = (cache, has_cache_entries) {
+        let size_before = fs::metadata(cache.path()).map(|m| m.len()).unwrap_or(0);
+        cache.clean_stale()?;
+        let size_after = fs::metadata(cache.path()).map(|m| m.len()).unwrap_or(0);
+
+        let mut cache_items = items.iter_mut().filter(|i| i.kind == GcKind::CacheEntry);
+        if let Some(first) = cache_items.next() {
+            // Attribute the whole shrink to the first entry so totals add up
+            first.bytes = size_before.saturating_sub(size_after);
+            first.removed = true;
+            reclaimed += first.bytes;
+        }
+        for item in cache_items {
+            item.removed = true;
+        }
+    }
+
+    Ok(reclaimed)
+}
+
+/// Returns a short label for a kind of garbage
+fn kind_label(kind: GcKind) -> &'static str {
+    match kind {
+        GcKind::OrphanSkill => "orphaned skill",
+        GcKind::TempClone => "temporary clone",
+        GcKind::CacheEntry => "stale cache entry",
+    }
+}
+
+/// Formats a byte count for display
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cache::{JsonCache, ScanEntry};
+    use crate::core::config::AgentConfig;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_repo_skill(repo_dir: &Path, name: &str) -> PathBuf {
+        let skill_dir = repo_dir.join(name);
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            format!("---\nname: {}\ndescription: test\n---\n", name),
+        )
+        .unwrap();
+        skill_dir
+    }
+
+    fn test_config(agent_dir: &Path) -> Config {
+        let mut config = Config::new();
+        config.insert_agent(
+            "claude-code".to_string(),
+            AgentConfig::new(true, agent_dir.to_path_buf(), PathBuf::from(".no-such-dir")),
+        );
+        config
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_garbage_only_reports_unlinked_skills() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let agent = temp_dir.path().join("agent");
+        fs::create_dir_all(&agent).unwrap();
+        let linked = create_repo_skill(&repo, "linked");
+        let orphan = create_repo_skill(&repo, "orphan");
+        std::os::unix::fs::symlink(&linked, agent.join("linked")).unwrap();
+
+        let items = find_garbage(
+            &test_config(&agent),
+            &repo,
+            &temp_dir.path().join("tmp"),
+            Duration::ZERO,
+            None,
+        );
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].kind, GcKind::OrphanSkill);
+        assert_eq!(items[0].path, orphan);
+        assert!(items[0].bytes > 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_garbage_scans_disabled_agents() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let agent = temp_dir.path().join("agent");
+        fs::create_dir_all(&agent).unwrap();
+        let linked = create_repo_skill(&repo, "linked");
+        std::os::unix::fs::symlink(&linked, agent.join("linked")).unwrap();
+
+        let mut config = test_config(&agent);
+        config.agents.get_mut("claude-code").unwrap().enabled = false;
+        let items = find_garbage(
+            &config,
+            &repo,
+            &temp_dir.path().join("tmp"),
+            Duration::ZERO,
+            None,
+        );
+
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn test_find_garbage_skips_recent_clones() {
+        let temp_dir = TempDir::new().unwrap();
+        let agent = temp_dir.path().join("agent");
+        let tmp = temp_dir.path().join("tmp");
+        fs::create_dir_all(&agent).unwrap();
+        fs::create_dir_all(tmp.join("sikil-clone-running")).unwrap();
+
+        let config = test_config(&agent);
+        let repo = temp_dir.path().join("repo");
+        assert!(find_garbage(&config, &repo, &tmp, STALE_CLONE_AGE, None).is_empty());
+        assert_eq!(
+            find_garbage(&config, &repo, &tmp, Duration::ZERO, None).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_links_may_exist_outside_scan() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(temp_dir.path());
+        assert!(links_may_exist_outside_scan(&config));
+
+        config.agents.get_mut("claude-code").unwrap().workspace_path =
+            temp_dir.path().join("workspace");
+        assert!(!links_may_exist_outside_scan(&config));
+    }
+
+    #[test]
+    fn test_remove_garbage_can_keep_orphans() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let orphan = create_repo_skill(&repo, "orphan");
+        let mut items = vec![GcItem {
+            kind: GcKind::OrphanSkill,
+            path: orphan.clone(),
+            bytes: dir_size(&orphan),
+            removed: false,
+        }];
+
+        let reclaimed = remove_garbage(
+            &mut items,
+            &repo,
+            false,
+            &temp_dir.path().join("trash"),
+            &temp_dir.path().join("journal"),
+            None,
+        )
+        .unwrap();
+        assert_eq!(reclaimed, 0);
+        assert!(!items[0].removed);
+        assert!(orphan.exists());
+        assert!(TrashEntry::load_all(&temp_dir.path().join("trash")).is_empty());
+    }
+
+    #[test]
+    fn test_plan_garbage_trashes_orphans_and_deletes_clones() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let trash = temp_dir.path().join("trash");
+        let orphan = create_repo_skill(&repo, "orphan");
+        let clone = temp_dir.path().join("tmp/sikil-clone-abc");
+        let items = vec![
+            GcItem {
+                kind: GcKind::OrphanSkill,
+                path: orphan.clone(),
+                bytes: 0,
+                removed: false,
+            },
+            GcItem {
+                kind: GcKind::TempClone,
+                path: clone.clone(),
+                bytes: 0,
+                removed: false,
+            },
+        ];
+
+        let (plan, trashed) = plan_garbage(&items, &repo, true, &trash, 0);
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].record().name, "orphan");
+        assert_eq!(
+            plan.steps,
+            vec![
+                Step::MoveDir {
+                    from: orphan,
+                    to: trashed[0].dir().join("repo"),
+                },
+                Step::RemoveDir {
+                    path: clone.clone()
+                },
+            ]
+        );
+
+        let (plan, trashed) = plan_garbage(&items, &repo, false, &trash, 0);
+        assert!(trashed.is_empty());
+        assert_eq!(plan.steps, vec![Step::RemoveDir { path: clone }]);
+    }
+
+    #[test]
+    fn test_gc_removes_everything_and_counts_bytes() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let agent = temp_dir.path().join("agent");
+        let tmp = temp_dir.path().join("tmp");
+        fs::create_dir_all(&agent).unwrap();
+        let orphan = create_repo_skill(&repo, "orphan");
+        let clone = tmp.join("sikil-clone-abc");
+        fs::create_dir_all(&clone).unwrap();
+        fs::write(clone.join("file"), "0123456789").unwrap();
+
+        let cache = JsonCache::open_at(&temp_dir.path().join("cache.json")).unwrap();
+        cache
+            .put(&ScanEntry {
+                path: temp_dir.path().join("gone"),
+                mtime: 0,
+                size: 0,
+                dir_mtime: 0,
+                content_hash: "hash".to_string(),
+                cached_at: 0,
+                skill_name: None,
+                is_valid_skill: false,
+                metadata: None,
+            })
+            .unwrap();
+
+        let mut items = find_garbage(
+            &test_config(&agent),
+            &repo,
+            &tmp,
+            Duration::ZERO,
+            Some(&cache),
+        );
+        assert_eq!(items.len(), 3);
+        let trash = temp_dir.path().join("trash");
+
+        let reclaimed = remove_garbage(
+            &mut items,
+            &repo,
+            true,
+            &trash,
+            &temp_dir.path().join("journal"),
+            Some(&cache),
+        )
+        .unwrap();
+
+        assert!(items.iter().all(|i| i.removed));
+        assert!(!orphan.exists());
+        assert!(!clone.exists());
+        assert!(cache.stale_paths().is_empty());
+        // The orphan went to the trash, so only the clone and cache count
+        let cache_bytes: u64 = items
+            .iter()
+            .filter(|i| i.kind == GcKind::CacheEntry)
+            .map(|i| i.bytes)
+            .sum();
+        assert!(cache_bytes > 0);
+        assert_eq!(reclaimed, 10 + cache_bytes);
+        let trashed = TrashEntry::load_all(&trash);
+        assert_eq!(trashed.len(), 1);
+        assert!(trashed[0].dir().join("repo/SKILL.md").is_file());
+    }
+}
@@ -9,29 +9,36 @@ use crate::commands::{parse_agent_selection, prompt_agent_selection};
 use crate::core::config::Config;
 use crate::core::errors::SikilError;
 use crate::core::parser::parse_skill_md;
-use crate::utils::atomic::copy_skill_dir;
-use crate::utils::git::{cleanup_clone, clone_repo, extract_subdirectory, parse_git_url};
+use crate::core::plan::{Plan, Step};
+use crate::core::secrets::{ensure_no_secrets, scan_skill_dir};
+use crate::core::skill::Agent;
+use crate::utils::git::{
+    cleanup_clone, clone_repo, create_temp_clone_dir, extract_subdirectory, parse_git_url,
+};
 use crate::utils::paths::{ensure_dir_exists, get_repo_path};
-use crate::utils::symlink::create_symlink;
 use anyhow::Result;
 use fs_err as fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Arguments for the install command
 #[derive(Debug, Clone)]
 pub struct InstallArgs {
     /// Whether to output in JSON format
     pub json_mode: bool,
+    /// Print the plan instead of installing
+    pub dry_run: bool,
     /// Path to the skill directory to install
     pub path: String,
     /// Agents to install to (from --to flag, None means interactive prompt)
     pub to: Option<String>,
+    /// Install even if the skill contains possible secrets
+    pub allow_secrets: bool,
 }
 
 /// Executes the install command for a local path
 ///
 /// This function:
-/// 1. Validates the source skill before install
+/// 1. Validates the source skill before install and scans it for secrets
 /// 2. Copies skill to `~/.sikil/repo/<name>/`
 /// 3. Creates symlinks to specified agents
 /// 4. Creates agent directories if missing
@@ -47,6 +54,7 @@ pub struct InstallArgs {
 /// Returns an error if:
 /// - The source path does not exist or is not a directory
 /// - The source skill is invalid (no SKILL.md, invalid metadata)
+/// - The source skill contains possible secrets and `allow_secrets` is false
 /// - A skill with the same name already exists in the repo
 /// - The destination is a physical directory (suggests adopt)
 /// - The destination is a symlink (already installed)
@@ -62,8 +70,10 @@ pub struct InstallArgs {
 /// let config = Config::default();
 /// let args = InstallArgs {
 ///     json_mode: false,
+///     dry_run: false,
 ///     path: "/path/to/skill".to_string(),
 ///     to: Some("claude-code".to_string()),
+///     allow_secrets: false,
 /// };
 /// execute_install_local(args, &config).unwrap();
 /// ```
@@ -96,20 +106,31 @@ pub fn execute_install_local(args: InstallArgs, config: &Config) -> Result<()> {
         return Err(SikilError::InvalidSkillMd {
             path: skill_md_path,
             reason: "SKILL.md not found in source directory".to_string(),
+            line: None,
+            column: None,
         }
         .into());
     }
 
     let metadata = parse_skill_md(&skill_md_path).map_err(|e| match e {
-        SikilError::InvalidSkillMd { path, reason } => SikilError::InvalidSkillMd {
+        SikilError::InvalidSkillMd {
+            path,
+            reason,
+            line,
+            column,
+        } => SikilError::InvalidSkillMd {
             path,
             reason: format!("invalid SKILL.md: {}", reason),
+            line,
+            column,
         },
         _ => e,
     })?;
 
     let skill_name = &metadata.name;
 
+    check_secrets(&source_path, skill_name, args.allow_secrets, &output)?;
+
     // M3-E01-T03: Determine target agents from --to flag or interactive prompt
     let target_agents = if let Some(to_value) = &args.to {
         // Parse the --to flag value
@@ -131,13 +152,7 @@ pub fn execute_install_local(args: InstallArgs, config: &Config) -> Result<()> {
         .into());
     }
 
-    // Get repo path and ensure it exists
     let repo_path = get_repo_path();
-    ensure_dir_exists(&repo_path).map_err(|_e| SikilError::PermissionDenied {
-        operation: "create repo directory".to_string(),
-        path: repo_path.clone(),
-    })?;
-
     let dest_path = repo_path.join(skill_name);
 
     // Check if skill already exists in repo (part of M3-E01-T02 guards)
@@ -199,70 +214,34 @@ pub fn execute_install_local(args: InstallArgs, config: &Config) -> Result<()> {
         output.print_info("");
     }
 
-    // S02: Copy skill to ~/.sikil/repo/<name>/ with progress
-    let progress = Progress::new(args.json_mode, None);
-    if !args.json_mode {
-        progress.set_message("Copying skill to repository...");
+    // S03-S06: Plan the copy and a symlink per agent
+    let links = plan_links(&target_agents, skill_name, config, &output, args.dry_run);
+    let plan = install_plan(skill_name, &source_path, &dest_path, &links);
+    if args.dry_run {
+        output.print_plan(&plan)?;
+        return Ok(());
     }
 
-    copy_skill_dir(&source_path, &dest_path).map_err(|e| match e {
-        SikilError::SymlinkNotAllowed { reason } => SikilError::ValidationError {
-            reason: format!("source contains symlinks which are not allowed: {}", reason),
-        },
-        _ => e,
+    // Ensure the repo exists, now that the plan will run
+    ensure_dir_exists(&repo_path).map_err(|_e| SikilError::PermissionDenied {
+        operation: "create repo directory".to_string(),
+        path: repo_path.clone(),
     })?;
 
+    // S02: Copy skill to ~/.sikil/repo/<name>/ with progress
+    let progress = Progress::new(args.json_mode, None);
     if !args.json_mode {
-        progress.finish_with_message("Skill copied to repository");
+        progress.set_message("Copying skill to repository...");
     }
 
-    // M3-E01-T02-S04: Track created symlinks for rollback
-    let mut created_symlinks: Vec<PathBuf> = Vec::new();
-
-    // S03-S06: Create symlinks to specified agents, creating directories if needed
-    for agent in &target_agents {
-        if let Some(agent_config) = config.get_agent(&agent.to_string()) {
-            let agent_skill_dir = &agent_config.global_path;
-
-            // Ensure agent directory exists
-            if let Err(e) = ensure_dir_exists(agent_skill_dir) {
-                output.print_warning(&format!(
-                    "Failed to create agent directory for {}: {}",
-                    agent, e
-                ));
-                continue;
-            }
-
-            let symlink_path = agent_skill_dir.join(skill_name);
-
-            if !args.json_mode {
-                progress.set_message(&format!("Creating symlink for {}...", agent));
-            }
-
-            match create_symlink(&dest_path, &symlink_path) {
-                Ok(()) => {
-                    created_symlinks.push(symlink_path.clone());
-                    if !args.json_mode {
-                        output.print_success(&format!(
-                            "Linked to {} at {}",
-                            agent,
-                            symlink_path.display()
-                        ));
-                    }
-                }
-                Err(e) => {
-                    // M3-E01-T02-S04: Rollback on partial failure
-                    // Remove all symlinks created so far
-                    for link in &created_symlinks {
-                        let _ = fs::remove_file(link);
-                    }
-                    // Remove the copied skill from repo
-                    let _ = fs::remove_dir_all(&dest_path);
-                    return Err(e.into());
-                }
-            }
-        }
-    }
+    // M3-E01-T02-S04: The copy and earlier symlinks are rolled back on failure
+    plan.execute(|step| report_step(step, &links, &progress, &output))
+        .map_err(|e| match e {
+            SikilError::SymlinkNotAllowed { reason } => SikilError::ValidationError {
+                reason: format!("source contains symlinks which are not allowed: {}", reason),
+            },
+            _ => e,
+        })?;
 
     if !args.json_mode {
         progress.clear();
@@ -291,6 +270,8 @@ pub fn execute_install_local(args: InstallArgs, config: &Config) -> Result<()> {
 /// * `agents` - Vector of agents to install to
 /// * `config` - Configuration for resolving agent paths
 /// * `json_mode` - Whether to output in JSON format
+/// * `allow_secrets` - Install even if the skill contains possible secrets
+/// * `dry_run` - Print the plan instead of installing
 ///
 /// # Errors
 ///
@@ -312,13 +293,15 @@ pub fn execute_install_local(args: InstallArgs, config: &Config) -> Result<()> {
 /// use sikil::core::config::Config;
 ///
 /// let config = Config::default();
-/// execute_install_git("owner/repo", vec![], &config, false).unwrap();
+/// execute_install_git("owner/repo", vec![], &config, false, false, false).unwrap();
 /// ```
 pub fn execute_install_git(
     url: &str,
     agents: Vec<String>,
     config: &Config,
     json_mode: bool,
+    allow_secrets: bool,
+    dry_run: bool,
 ) -> Result<()> {
     let output = Output::new(json_mode);
 
@@ -336,9 +319,7 @@ pub fn execute_install_git(
     }
 
     // M3-E02-T04-S02: Clone repo to temp
-    let temp_clone_dir = tempfile::tempdir().map_err(|e| SikilError::GitError {
-        reason: format!("failed to create temporary directory: {}", e),
-    })?;
+    let temp_clone_dir = create_temp_clone_dir()?;
 
     let clone_path = temp_clone_dir.path();
 
@@ -355,6 +336,8 @@ pub fn execute_install_git(
     }
 
     // M3-E02-T04-S03: Extract skill (root or subdirectory)
+    // An extracted subdirectory lives in its own temp dir, deleted on drop
+    let mut extracted_dir = None;
     let skill_path = if let Some(subdirectory) = &parsed_url.subdirectory {
         // Extract subdirectory
         if !json_mode {
@@ -367,7 +350,7 @@ pub fn execute_install_git(
             progress.finish_with_message(&format!("Extracted: {}", subdirectory));
         }
 
-        extracted
+        extracted_dir.insert(extracted).path().to_path_buf()
     } else {
         // Use root of repository
         clone_path.to_path_buf()
@@ -381,20 +364,34 @@ pub fn execute_install_git(
         return Err(SikilError::InvalidSkillMd {
             path: skill_md_path,
             reason: "SKILL.md not found in Git repository".to_string(),
+            line: None,
+            column: None,
         }
         .into());
     }
 
     let metadata = parse_skill_md(&skill_md_path).map_err(|e| match e {
-        SikilError::InvalidSkillMd { path, reason } => SikilError::InvalidSkillMd {
+        SikilError::InvalidSkillMd {
+            path,
+            reason,
+            line,
+            column,
+        } => SikilError::InvalidSkillMd {
             path,
             reason: format!("invalid SKILL.md: {}", reason),
+            line,
+            column,
         },
         _ => e,
     })?;
 
     let skill_name = &metadata.name;
 
+    if let Err(e) = check_secrets(&skill_path, skill_name, allow_secrets, &output) {
+        let _ = fs::remove_dir_all(temp_clone_dir.path());
+        return Err(e);
+    }
+
     // Determine target agents
     let target_agents = if agents.is_empty() {
         // Use all enabled agents if none specified
@@ -412,13 +409,7 @@ pub fn execute_install_git(
         .into());
     }
 
-    // Get repo path and ensure it exists
     let repo_path = get_repo_path();
-    ensure_dir_exists(&repo_path).map_err(|_e| SikilError::PermissionDenied {
-        operation: "create repo directory".to_string(),
-        path: repo_path.clone(),
-    })?;
-
     let dest_path = repo_path.join(skill_name);
 
     // Check if skill already exists in repo
@@ -484,40 +475,81 @@ pub fn execute_install_git(
     // Clean up the clone (remove .git directory)
     cleanup_clone(&skill_path)?;
 
+    let links = plan_links(&target_agents, skill_name, config, &output, dry_run);
+    let plan = install_plan(skill_name, &skill_path, &dest_path, &links);
+    if dry_run {
+        let _ = fs::remove_dir_all(temp_clone_dir.path());
+        output.print_plan(&plan)?;
+        return Ok(());
+    }
+
+    // Ensure the repo exists, now that the plan will run
+    if ensure_dir_exists(&repo_path).is_err() {
+        let _ = fs::remove_dir_all(temp_clone_dir.path());
+        return Err(SikilError::PermissionDenied {
+            operation: "create repo directory".to_string(),
+            path: repo_path,
+        }
+        .into());
+    }
+
     // M3-E02-T04-S05: Copy to repo using copy_skill_dir (rejects symlinks)
     if !json_mode {
         progress.set_message("Copying skill to repository...");
     }
 
-    copy_skill_dir(&skill_path, &dest_path).map_err(|e| match e {
-        SikilError::SymlinkNotAllowed { reason } => {
-            let _ = fs::remove_dir_all(temp_clone_dir.path());
-            SikilError::ValidationError {
+    // M3-E02-T04-S06: Create symlinks to agents, rolling back on partial failure
+    let executed = plan.execute(|step| report_step(step, &links, &progress, &output));
+    if let Err(e) = executed {
+        let _ = fs::remove_dir_all(temp_clone_dir.path());
+        return Err(match e {
+            SikilError::SymlinkNotAllowed { reason } => SikilError::ValidationError {
                 reason: format!(
                     "Git repository contains symlinks which are not allowed: {}",
                     reason
                 ),
-            }
-        }
-        _ => {
-            let _ = fs::remove_dir_all(temp_clone_dir.path());
-            e
+            },
+            _ => e,
         }
-    })?;
+        .into());
+    }
 
     if !json_mode {
-        progress.finish_with_message("Skill copied to repository");
+        progress.clear();
+        output.print_info("");
+        output.print_success(&format!("Successfully installed {}", skill_name));
+        output.print_info(&format!("Managed at: {}", dest_path.display()));
     }
 
-    // M3-E02-T04-S06: Create symlinks to agents
-    let mut created_symlinks: Vec<PathBuf> = Vec::new();
+    // M3-E02-T04-S07: Clean up temp directory
+    // The temp directory will be automatically cleaned up when temp_clone_dir
+    // goes out of scope, but we can explicitly remove it here to be safe
+    let _ = fs::remove_dir_all(temp_clone_dir.path());
+    drop(extracted_dir);
 
-    for agent in &target_agents {
+    Ok(())
+}
+
+/// Resolves the symlink path for each target agent
+///
+/// Agent directories are created as needed, except in a dry run; agents
+/// whose directory cannot be created are skipped with a warning.
+fn plan_links(
+    target_agents: &[Agent],
+    skill_name: &str,
+    config: &Config,
+    output: &Output,
+    dry_run: bool,
+) -> Vec<(Agent, PathBuf)> {
+    let mut links = Vec::new();
+    for agent in target_agents {
         if let Some(agent_config) = config.get_agent(&agent.to_string()) {
             let agent_skill_dir = &agent_config.global_path;
 
             // Ensure agent directory exists
-            if let Err(e) = ensure_dir_exists(agent_skill_dir) {
+            if dry_run {
+                // Leave the filesystem untouched
+            } else if let Err(e) = ensure_dir_exists(agent_skill_dir) {
                 output.print_warning(&format!(
                     "Failed to create agent directory for {}: {}",
                     agent, e
@@ -525,48 +557,67 @@ pub fn execute_install_git(
                 continue;
             }
 
-            let symlink_path = agent_skill_dir.join(skill_name);
+            links.push((*agent, agent_skill_dir.join(skill_name)));
+        }
+    }
+    links
+}
 
-            if !json_mode {
-                progress.set_message(&format!("Creating symlink for {}...", agent));
-            }
+/// Builds the plan of an install: copy into the repo, then link each agent
+fn install_plan(skill_name: &str, source: &Path, dest: &Path, links: &[(Agent, PathBuf)]) -> Plan {
+    let mut plan = Plan::new("install", skill_name);
+    plan.push(Step::CopyDir {
+        from: source.to_path_buf(),
+        to: dest.to_path_buf(),
+    });
+    for (_, link) in links {
+        plan.push(Step::CreateSymlink {
+            target: dest.to_path_buf(),
+            link: link.clone(),
+        });
+    }
+    plan
+}
 
-            match create_symlink(&dest_path, &symlink_path) {
-                Ok(()) => {
-                    created_symlinks.push(symlink_path.clone());
-                    if !json_mode {
-                        output.print_success(&format!(
-                            "Linked to {} at {}",
-                            agent,
-                            symlink_path.display()
-                        ));
-                    }
-                }
-                Err(e) => {
-                    // Rollback on partial failure
-                    for link in &created_symlinks {
-                        let _ = fs::remove_file(link);
-                    }
-                    let _ = fs::remove_dir_all(&dest_path);
-                    let _ = fs::remove_dir_all(temp_clone_dir.path());
-                    return Err(e.into());
-                }
+/// Reports a completed install step
+fn report_step(step: &Step, links: &[(Agent, PathBuf)], progress: &Progress, output: &Output) {
+    if output.json_mode {
+        return;
+    }
+    match step {
+        Step::CopyDir { .. } => progress.finish_with_message("Skill copied to repository"),
+        Step::CreateSymlink { link, .. } => {
+            if let Some((agent, _)) = links.iter().find(|(_, path)| path == link) {
+                output.print_success(&format!("Linked to {} at {}", agent, link.display()));
             }
         }
+        _ => {}
     }
+}
 
-    if !json_mode {
-        progress.clear();
-        output.print_info("");
-        output.print_success(&format!("Successfully installed {}", skill_name));
-        output.print_info(&format!("Managed at: {}", dest_path.display()));
+/// Blocks installation of skills containing possible secrets
+///
+/// With `allow_secrets`, findings are printed as warnings instead.
+pub(crate) fn check_secrets(
+    skill_path: &Path,
+    skill_name: &str,
+    allow_secrets: bool,
+    output: &Output,
+) -> Result<()> {
+    if !allow_secrets {
+        ensure_no_secrets(skill_path, skill_name)?;
+        return Ok(());
+    }
+
+    for finding in scan_skill_dir(skill_path) {
+        output.print_warning(&format!(
+            "⚠ Possible {} in {}:{} ({})",
+            finding.kind,
+            finding.file.display(),
+            finding.line,
+            finding.excerpt
+        ));
     }
-
-    // M3-E02-T04-S07: Clean up temp directory
-    // The temp directory will be automatically cleaned up when temp_clone_dir
-    // goes out of scope, but we can explicitly remove it here to be safe
-    let _ = fs::remove_dir_all(temp_clone_dir.path());
-
     Ok(())
 }
 
@@ -616,8 +667,10 @@ This is a test skill."#,
     fn test_install_args() {
         let args = InstallArgs {
             json_mode: true,
+            dry_run: false,
             path: "/path/to/skill".to_string(),
             to: Some("claude-code,windsurf".to_string()),
+            allow_secrets: false,
         };
 
         assert!(args.json_mode);
@@ -629,8 +682,10 @@ This is a test skill."#,
     fn test_install_args_no_to() {
         let args = InstallArgs {
             json_mode: false,
+            dry_run: false,
             path: "/path/to/skill".to_string(),
             to: None,
+            allow_secrets: false,
         };
 
         assert!(!args.json_mode);
@@ -679,8 +734,10 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&repo_dir, &agent_dir);
         let args = InstallArgs {
             json_mode: false,
+            dry_run: false,
             path: source_dir.to_str().unwrap().to_string(),
             to: Some("claude-code".to_string()),
+            allow_secrets: false,
         };
 
         let result = execute_install_local(args, &config);
@@ -716,8 +773,10 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&repo_dir, &agent_dir);
         let args = InstallArgs {
             json_mode: false,
+            dry_run: false,
             path: source_dir.to_str().unwrap().to_string(),
             to: Some("claude-code".to_string()),
+            allow_secrets: false,
         };
 
         let result = execute_install_local(args, &config);
@@ -758,8 +817,10 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&repo_dir, &agent_dir);
         let args = InstallArgs {
             json_mode: false,
+            dry_run: false,
             path: source_dir.to_str().unwrap().to_string(),
             to: Some("claude-code".to_string()),
+            allow_secrets: false,
         };
 
         let result = execute_install_local(args, &config);
@@ -865,8 +926,10 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&repo_dir, &agent_dir);
         let args = InstallArgs {
             json_mode: false,
+            dry_run: false,
             path: source_dir.to_str().unwrap().to_string(),
             to: Some("claude-code".to_string()),
+            allow_secrets: false,
         };
 
         let result = execute_install_local(args, &config);
@@ -6,9 +6,15 @@
 use crate::cli::output::Output;
 use crate::core::config::Config;
 use crate::core::conflicts;
+use crate::core::errors::SikilError;
 use crate::core::scanner::Scanner;
 use crate::core::skill::{Agent, Scope, Skill};
+use crate::utils::paths::dir_size;
 use anyhow::Result;
+use fs_err as fs;
+use std::cmp::Reverse;
+use std::path::PathBuf;
+use std::time::SystemTime;
 
 /// Arguments for the list command
 #[derive(Debug, Clone)]
@@ -27,10 +33,55 @@ pub struct ListArgs {
     pub conflicts_only: bool,
     /// Filter to show only duplicate skills
     pub duplicates_only: bool,
+    /// Filter to show only managed skills that no agent links to
+    pub orphans_only: bool,
+    /// Filter to skills that have all of these tags (case-insensitive)
+    pub tags: Vec<String>,
+    /// Filter by author (case-insensitive substring)
+    pub author: Option<String>,
+    /// Filter to skills installed in this scope
+    pub scope: Option<Scope>,
+    /// Filter by skill name glob (`*` and `?`)
+    pub name_pattern: Option<String>,
+    /// Sort order
+    pub sort: ListSort,
     /// Enable verbose output (show info-level conflicts)
     pub verbose: bool,
 }
 
+/// Sort order for the list command
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ListSort {
+    /// Alphabetically by name
+    #[default]
+    Name,
+    /// Largest first
+    Size,
+    /// Most recently installed first
+    InstalledAt,
+    /// Installed for the most agents first
+    Agents,
+}
+
+impl std::str::FromStr for ListSort {
+    type Err = SikilError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "name" => Ok(Self::Name),
+            "size" => Ok(Self::Size),
+            "installed-at" => Ok(Self::InstalledAt),
+            "agents" => Ok(Self::Agents),
+            _ => Err(SikilError::ValidationError {
+                reason: format!(
+                    "unknown sort key '{}' (expected name, size, installed-at or agents)",
+                    s
+                ),
+            }),
+        }
+    }
+}
+
 /// Output format for a single skill in the list
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct ListSkillOutput {
@@ -43,6 +94,9 @@ pub struct ListSkillOutput {
     pub description: String,
     /// Whether this skill is managed
     pub managed: bool,
+    /// Tags from frontmatter
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
     /// Agents where this skill is installed
     pub installations: Vec<ListInstallationOutput>,
 }
@@ -97,7 +151,7 @@ fn get_disabled_default_agents(config: &Config) -> Vec<(String, String)> {
 /// # Examples
 ///
 /// ```no_run
-/// use sikil::commands::list::{execute_list, ListArgs};
+/// use sikil::commands::list::{execute_list, ListArgs, ListSort};
 /// use sikil::core::config::Config;
 ///
 /// let args = ListArgs {
@@ -108,6 +162,12 @@ fn get_disabled_default_agents(config: &Config) -> Vec<(String, String)> {
 ///     unmanaged_only: false,
 ///     conflicts_only: false,
 ///     duplicates_only: false,
+///     orphans_only: false,
+///     tags: vec!["git".to_string()],
+///     author: None,
+///     scope: None,
+///     name_pattern: Some("review-*".to_string()),
+///     sort: ListSort::Name,
 ///     verbose: false,
 /// };
 /// let config = Config::default();
@@ -171,17 +231,15 @@ pub fn execute_list(args: ListArgs, config: &Config) -> Result<()> {
         }
     }
 
-    // Sort by name
-    managed_skills.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
-    unmanaged_skills.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
-
     // Convert to output format
     let mut output_skills = Vec::new();
 
     for skill in managed_skills.iter().chain(unmanaged_skills.iter()) {
+        // The repository copy of a managed skill is not an installation
         let installations: Vec<ListInstallationOutput> = skill
             .installations
             .iter()
+            .filter(|inst| Some(&inst.path) != skill.repo_path.as_ref())
             .map(|inst| ListInstallationOutput {
                 agent: inst.agent.to_string(),
                 scope: format_scope(inst.scope),
@@ -199,6 +257,7 @@ pub fn execute_list(args: ListArgs, config: &Config) -> Result<()> {
             directory_name,
             description: skill.metadata.description.clone(),
             managed: skill.is_managed,
+            tags: skill.metadata.tags.clone(),
             installations,
         });
     }
@@ -222,6 +281,8 @@ fn format_scope(scope: Scope) -> String {
 }
 
 /// Applies filters to a list of skills based on the provided arguments
+///
+/// The result is ordered by `args.sort`, with ties broken by name.
 fn apply_filters<'a>(
     skills: &'a [Skill],
     args: &ListArgs,
@@ -270,9 +331,124 @@ fn apply_filters<'a>(
         filtered.retain(|skill| conflict_skill_names.contains(&skill.metadata.name));
     }
 
+    // Apply --orphans filter
+    if args.orphans_only {
+        filtered.retain(|skill| skill.is_managed && skill.is_orphan());
+    }
+
+    // Apply --tag filter (every tag must be present)
+    for tag in &args.tags {
+        filtered.retain(|skill| {
+            skill
+                .metadata
+                .tags
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(tag))
+        });
+    }
+
+    // Apply --author filter
+    if let Some(ref author) = args.author {
+        let author = author.to_lowercase();
+        filtered.retain(|skill| {
+            skill
+                .metadata
+                .author
+                .as_ref()
+                .is_some_and(|a| a.to_lowercase().contains(&author))
+        });
+    }
+
+    // Apply --scope filter
+    if let Some(scope) = args.scope {
+        filtered.retain(|skill| agent_installations(skill).any(|inst| inst.scope == scope));
+    }
+
+    // Apply --name filter
+    if let Some(ref pattern) = args.name_pattern {
+        filtered.retain(|skill| glob_match(pattern, &skill.metadata.name));
+    }
+
+    // Apply --sort
+    filtered.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+    match args.sort {
+        ListSort::Name => {}
+        ListSort::Size => filtered.sort_by_cached_key(|skill| Reverse(skill_size(skill))),
+        ListSort::InstalledAt => filtered.sort_by_cached_key(|skill| Reverse(installed_at(skill))),
+        ListSort::Agents => filtered.sort_by_cached_key(|skill| {
+            let mut agents: Vec<Agent> = agent_installations(skill).map(|i| i.agent).collect();
+            agents.sort_by_key(|a| a.cli_name());
+            agents.dedup();
+            Reverse(agents.len())
+        }),
+    }
+
     filtered
 }
 
+/// Installations of a skill, excluding the repository copy of a managed skill
+fn agent_installations(skill: &Skill) -> impl Iterator<Item = &crate::core::skill::Installation> {
+    skill
+        .installations
+        .iter()
+        .filter(move |inst| Some(&inst.path) != skill.repo_path.as_ref())
+}
+
+/// Size of a skill's files, from the repository copy or the first installation
+fn skill_size(skill: &Skill) -> u64 {
+    skill
+        .repo_path
+        .as_ref()
+        .or_else(|| skill.installations.first().map(|i| &i.path))
+        .map(|path| dir_size(&fs::canonicalize(path).unwrap_or_else(|_| path.clone())))
+        .unwrap_or(0)
+}
+
+/// When a skill was first installed for an agent
+///
+/// Uses the modification time of the installed symlink or directory itself,
+/// which is set when sikil creates it. Skills without an agent installation
+/// fall back to the repository copy.
+fn installed_at(skill: &Skill) -> Option<SystemTime> {
+    let mtime = |path: &PathBuf| fs::symlink_metadata(path).ok()?.modified().ok();
+    agent_installations(skill)
+        .filter_map(|inst| mtime(&inst.path))
+        .min()
+        .or_else(|| skill.repo_path.as_ref().and_then(mtime))
+}
+
+/// Matches a name against a glob pattern where `*` matches any run of
+/// characters and `?` matches a single character
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Position after the last `*` and the name position it is matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p + 1, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_n)) => {
+                    p = star_p;
+                    n = star_n + 1;
+                    backtrack = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 /// Prints human-readable output for the list command
 fn print_human_readable(
     output: &Output,
@@ -431,6 +607,7 @@ mod tests {
             directory_name: None,
             description: "A test skill".to_string(),
             managed: true,
+            tags: vec![],
             installations: vec![ListInstallationOutput {
                 agent: "claude-code".to_string(),
                 scope: "global".to_string(),
@@ -450,6 +627,7 @@ mod tests {
             directory_name: Some("my-skill-v2".to_string()),
             description: "A test skill".to_string(),
             managed: false,
+            tags: vec![],
             installations: vec![],
         };
 
@@ -464,6 +642,7 @@ mod tests {
             directory_name: None,
             description: "A test skill".to_string(),
             managed: false,
+            tags: vec![],
             installations: vec![],
         };
 
@@ -482,6 +661,12 @@ mod tests {
             unmanaged_only: false,
             conflicts_only: false,
             duplicates_only: false,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: false,
         };
 
@@ -502,6 +687,12 @@ mod tests {
             unmanaged_only: false,
             conflicts_only: false,
             duplicates_only: false,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: false,
         };
 
@@ -520,6 +711,12 @@ mod tests {
             unmanaged_only: false,
             conflicts_only: false,
             duplicates_only: false,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: true,
         };
 
@@ -579,6 +776,12 @@ mod tests {
             unmanaged_only: false,
             conflicts_only: false,
             duplicates_only: false,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: false,
         };
 
@@ -589,6 +792,63 @@ mod tests {
         assert_eq!(filtered[1].metadata.name, "skill3");
     }
 
+    #[test]
+    fn test_apply_filters_orphans_only() {
+        use std::path::PathBuf;
+
+        // Managed and linked from an agent
+        let linked = Skill::new(
+            crate::core::skill::SkillMetadata::new("linked".to_string(), "A skill".to_string()),
+            "linked".to_string(),
+        )
+        .with_installation(crate::core::skill::Installation::new(
+            Agent::ClaudeCode,
+            PathBuf::from("/claude/linked"),
+            Scope::Global,
+        ))
+        .with_repo(PathBuf::from("/repo/linked"));
+
+        // Managed, only present in the repo
+        let orphan = Skill::new(
+            crate::core::skill::SkillMetadata::new("orphan".to_string(), "A skill".to_string()),
+            "orphan".to_string(),
+        )
+        .with_installation(crate::core::skill::Installation::new(
+            Agent::ClaudeCode,
+            PathBuf::from("/repo/orphan"),
+            Scope::Global,
+        ))
+        .with_repo(PathBuf::from("/repo/orphan"));
+
+        // Unmanaged skills are never orphans
+        let unmanaged = Skill::new(
+            crate::core::skill::SkillMetadata::new("loose".to_string(), "A skill".to_string()),
+            "loose".to_string(),
+        );
+
+        let skills = vec![linked, orphan, unmanaged];
+        let args = ListArgs {
+            json_mode: false,
+            no_cache: false,
+            agent_filter: None,
+            managed_only: false,
+            unmanaged_only: false,
+            conflicts_only: false,
+            duplicates_only: false,
+            orphans_only: true,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
+            verbose: false,
+        };
+
+        let filtered = apply_filters(&skills, &args, &[]);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].metadata.name, "orphan");
+    }
+
     #[test]
     fn test_apply_filters_managed_only() {
         use std::path::PathBuf;
@@ -628,6 +888,12 @@ mod tests {
             unmanaged_only: false,
             conflicts_only: false,
             duplicates_only: false,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: false,
         };
 
@@ -677,6 +943,12 @@ mod tests {
             unmanaged_only: true,
             conflicts_only: false,
             duplicates_only: false,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: false,
         };
 
@@ -756,6 +1028,12 @@ mod tests {
             unmanaged_only: false,
             conflicts_only: false,
             duplicates_only: true,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: false,
         };
 
@@ -802,6 +1080,12 @@ mod tests {
             unmanaged_only: false,
             conflicts_only: false,
             duplicates_only: false,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: false,
         };
 
@@ -859,6 +1143,12 @@ mod tests {
             unmanaged_only: false,
             conflicts_only: false,
             duplicates_only: false,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: false,
         };
 
@@ -923,6 +1213,12 @@ mod tests {
             unmanaged_only: false,
             conflicts_only: true,
             duplicates_only: false,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: false,
         };
 
@@ -1066,6 +1362,12 @@ mod tests {
             unmanaged_only: false,
             conflicts_only: true,
             duplicates_only: false,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: false,
         };
 
@@ -1143,6 +1445,12 @@ mod tests {
             unmanaged_only: false,
             conflicts_only: true,
             duplicates_only: false,
+            orphans_only: false,
+            tags: vec![],
+            author: None,
+            scope: None,
+            name_pattern: None,
+            sort: ListSort::Name,
             verbose: true,
         };
 
@@ -1153,4 +1461,116 @@ mod tests {
         assert!(skill_names.contains(&&"managed-skill".to_string()));
         assert!(skill_names.contains(&&"unmanaged-skill".to_string()));
     }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("review-*", "review-code"));
+        assert!(glob_match("review-*", "review-"));
+        assert!(!glob_match("review-*", "code-review"));
+        assert!(glob_match("*-review", "code-review"));
+        assert!(glob_match("*re*ew*", "code-review-bot"));
+        assert!(glob_match("skill-?", "skill-1"));
+        assert!(!glob_match("skill-?", "skill-10"));
+        assert!(glob_match("my-skill", "my-skill"));
+        assert!(!glob_match("my-skill", "my-skills"));
+        assert!(glob_match("*", ""));
+    }
+
+    #[test]
+    fn test_list_sort_from_str() {
+        assert_eq!(
+            "installed-at".parse::<ListSort>().unwrap(),
+            ListSort::InstalledAt
+        );
+        assert_eq!("agents".parse::<ListSort>().unwrap(), ListSort::Agents);
+        assert!("date".parse::<ListSort>().is_err());
+    }
+
+    #[test]
+    fn test_apply_filters_tags_author_scope_and_sort_by_agents() {
+        use crate::core::skill::{Installation, SkillMetadata};
+        use std::path::PathBuf;
+
+        let make = |name: &str, author: &str, tags: &[&str], installs: &[(Agent, Scope)]| {
+            let metadata = SkillMetadata::new(name.to_string(), "d".to_string())
+                .with_author(author.to_string())
+                .with_tags(tags.iter().map(|t| t.to_string()).collect());
+            installs.iter().fold(
+                Skill::new(metadata, name.to_string()),
+                |skill, (agent, scope)| {
+                    skill.with_installation(Installation::new(
+                        *agent,
+                        PathBuf::from(format!("/{}/{}", agent, name)),
+                        *scope,
+                    ))
+                },
+            )
+        };
+        let skills = vec![
+            make(
+                "alpha",
+                "Alice",
+                &["git"],
+                &[(Agent::ClaudeCode, Scope::Global)],
+            ),
+            make(
+                "beta",
+                "alice",
+                &["Git", "review"],
+                &[
+                    (Agent::ClaudeCode, Scope::Workspace),
+                    (Agent::Windsurf, Scope::Global),
+                ],
+            ),
+            make(
+                "gamma",
+                "bob",
+                &["review"],
+                &[(Agent::Amp, Scope::Workspace)],
+            ),
+        ];
+        let args = |f: &dyn Fn(&mut ListArgs)| {
+            let mut args = ListArgs {
+                json_mode: false,
+                no_cache: true,
+                agent_filter: None,
+                managed_only: false,
+                unmanaged_only: false,
+                conflicts_only: false,
+                duplicates_only: false,
+                orphans_only: false,
+                tags: vec![],
+                author: None,
+                scope: None,
+                name_pattern: None,
+                sort: ListSort::Name,
+                verbose: false,
+            };
+            f(&mut args);
+            args
+        };
+        let names = |args: &ListArgs| -> Vec<String> {
+            apply_filters(&skills, args, &[])
+                .iter()
+                .map(|s| s.metadata.name.clone())
+                .collect()
+        };
+
+        assert_eq!(
+            names(&args(&|a| a.tags = vec!["git".to_string()])),
+            vec!["alpha", "beta"]
+        );
+        assert_eq!(
+            names(&args(&|a| a.author = Some("ALICE".to_string()))),
+            vec!["alpha", "beta"]
+        );
+        assert_eq!(
+            names(&args(&|a| a.scope = Some(Scope::Workspace))),
+            vec!["beta", "gamma"]
+        );
+        assert_eq!(
+            names(&args(&|a| a.sort = ListSort::Agents)),
+            vec!["beta", "alpha", "gamma"]
+        );
+    }
 }
@@ -0,0 +1,160 @@
+//! Log command implementation
+//!
+//! This module reads back the audit log at `~/.sikil/log.jsonl`, optionally
+//! narrowed to one skill or to entries since a date.
+
+use crate::cli::output::Output;
+use crate::core::audit::{read_entries, AuditEntry, Outcome};
+use crate::core::errors::SikilError;
+use crate::utils::paths::get_log_path;
+use crate::utils::time::parse_date;
+use anyhow::Result;
+use std::path::Path;
+
+/// Arguments for the log command
+#[derive(Debug, Clone)]
+pub struct LogArgs {
+    /// Whether to output in JSON format
+    pub json_mode: bool,
+    /// Only show entries for this skill
+    pub skill: Option<String>,
+    /// Only show entries at or after this date (`YYYY-MM-DD` or RFC 3339)
+    pub since: Option<String>,
+}
+
+/// Executes the log command
+///
+/// # Errors
+///
+/// Returns `SikilError::ValidationError` if `--since` is not a date, or
+/// `SikilError::PermissionDenied` if the log cannot be read.
+pub fn execute_log(args: LogArgs) -> Result<()> {
+    let output = Output::new(args.json_mode);
+    let entries = filter_entries(
+        &get_log_path(),
+        args.skill.as_deref(),
+        args.since.as_deref(),
+    )?;
+
+    if args.json_mode {
+        output.print_json(&entries)?;
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        output.print_info("No logged changes");
+        return Ok(());
+    }
+    for entry in &entries {
+        output.print_info(&format_entry(entry));
+    }
+    Ok(())
+}
+
+/// Reads the log at `path`, keeping entries for `skill` logged at or after
+/// `since`
+pub fn filter_entries(
+    path: &Path,
+    skill: Option<&str>,
+    since: Option<&str>,
+) -> Result<Vec<AuditEntry>, SikilError> {
+    let since = since
+        .map(|date| {
+            parse_date(date).ok_or_else(|| SikilError::ValidationError {
+                reason: format!(
+                    "invalid date '{}': expected YYYY-MM-DD or YYYY-MM-DDTHH:MM:SSZ",
+                    date
+                ),
+            })
+        })
+        .transpose()?;
+
+    Ok(read_entries(path)?
+        .into_iter()
+        .filter(|entry| skill.is_none() || entry.skill.as_deref() == skill)
+        .filter(|entry| match since {
+            Some(since) => entry.timestamp_secs().is_some_and(|secs| secs >= since),
+            None => true,
+        })
+        .collect())
+}
+
+fn format_entry(entry: &AuditEntry) -> String {
+    let mut line = format!("{}  {}  {}", entry.timestamp, entry.user, entry.command);
+    if let Some(skill) = &entry.skill {
+        line.push_str(&format!(" {}", skill));
+    }
+    if let Some(source) = &entry.source {
+        line.push_str(&format!(" from {}", source));
+    }
+    if !entry.agents.is_empty() {
+        line.push_str(&format!(" [{}]", entry.agents.join(", ")));
+    }
+    match (&entry.outcome, &entry.error) {
+        (Outcome::Success, _) => line.push_str("  ok"),
+        (Outcome::Failure, Some(error)) => line.push_str(&format!("  failed: {}", error)),
+        (Outcome::Failure, None) => line.push_str("  failed"),
+    }
+    if let Some(hash) = &entry.content_hash {
+        line.push_str(&format!("  sha256:{}", &hash[..hash.len().min(12)]));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::audit::append_entry;
+    use tempfile::TempDir;
+
+    fn entry(command: &str, skill: &str, timestamp: &str) -> AuditEntry {
+        let mut entry = AuditEntry::new(command);
+        entry.skill = Some(skill.to_string());
+        entry.timestamp = timestamp.to_string();
+        entry
+    }
+
+    #[test]
+    fn test_filter_entries_by_skill_and_date() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("log.jsonl");
+        append_entry(&path, &entry("install", "a", "2024-01-01T10:00:00Z")).unwrap();
+        append_entry(&path, &entry("install", "b", "2024-03-01T10:00:00Z")).unwrap();
+        append_entry(&path, &entry("remove", "a", "2024-06-01T10:00:00Z")).unwrap();
+
+        assert_eq!(filter_entries(&path, None, None).unwrap().len(), 3);
+
+        let for_a = filter_entries(&path, Some("a"), None).unwrap();
+        assert_eq!(for_a.len(), 2);
+
+        let recent = filter_entries(&path, None, Some("2024-03-01")).unwrap();
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].skill.as_deref(), Some("b"));
+
+        let recent_a = filter_entries(&path, Some("a"), Some("2024-03-01")).unwrap();
+        assert_eq!(recent_a.len(), 1);
+        assert_eq!(recent_a[0].command, "remove");
+    }
+
+    #[test]
+    fn test_filter_entries_rejects_invalid_date() {
+        let temp_dir = TempDir::new().unwrap();
+        let err = filter_entries(&temp_dir.path().join("log.jsonl"), None, Some("last week"))
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid date 'last week'"));
+    }
+
+    #[test]
+    fn test_format_entry() {
+        let mut entry = entry("install", "a", "2024-01-01T10:00:00Z");
+        entry.user = "alice".to_string();
+        entry.source = Some("owner/repo".to_string());
+        entry.agents = vec!["claude-code".to_string(), "windsurf".to_string()];
+        entry.content_hash = Some("0123456789abcdef".to_string());
+
+        assert_eq!(
+            format_entry(&entry),
+            "2024-01-01T10:00:00Z  alice  install a from owner/repo [claude-code, windsurf]  ok  sha256:0123456789ab"
+        );
+    }
+}
@@ -0,0 +1,398 @@
+//! Meta command implementation
+//!
+//! This module implements `sikil meta get|set|unset`, which reads and edits
+//! SKILL.md frontmatter in place. Edits keep comments, key order and the body,
+//! are re-validated before they are written, and invalidate the scan cache.
+
+use crate::cli::output::Output;
+use crate::core::cache::open_cache;
+use crate::core::config::Config;
+use crate::core::errors::SikilError;
+use crate::core::frontmatter::FrontmatterEditor;
+use crate::core::parser::{parse_skill_md_content, validate_skill_name};
+use crate::core::scanner::Scanner;
+use crate::utils::atomic::atomic_write;
+use anyhow::Result;
+use fs_err as fs;
+use std::path::{Path, PathBuf};
+
+/// Arguments for the meta command
+#[derive(Debug, Clone)]
+pub struct MetaArgs {
+    /// Whether to output in JSON format
+    pub json_mode: bool,
+    /// Skill name, or path to a skill directory or SKILL.md
+    pub skill: String,
+    /// What to do with the frontmatter
+    pub operation: MetaOperation,
+}
+
+/// A frontmatter operation
+#[derive(Debug, Clone)]
+pub enum MetaOperation {
+    /// Print one key, or the whole frontmatter if `key` is `None`
+    Get { key: Option<String> },
+    /// Set a key; `value` is parsed as YAML if `yaml` is set
+    Set {
+        key: String,
+        value: String,
+        yaml: bool,
+    },
+    /// Remove a key
+    Unset { key: String },
+}
+
+/// Output of the meta command
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MetaOutput {
+    /// SKILL.md that was read or edited
+    pub path: PathBuf,
+    /// Key that was read or edited (absent when printing all fields)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Value of the key, or the whole frontmatter for `get` without a key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_yaml::Value>,
+    /// Whether SKILL.md was modified (`set` and `unset` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changed: Option<bool>,
+}
+
+/// SKILL.md to edit, with the directories whose cache entries it affects
+struct ResolvedSkill {
+    skill_md: PathBuf,
+    cache_paths: Vec<PathBuf>,
+}
+
+/// Executes the meta command
+///
+/// # Errors
+///
+/// Returns an error if the skill cannot be found, SKILL.md cannot be read
+/// or parsed, a `get` key is not set, or an edit would make SKILL.md invalid
+/// (in which case the file is left untouched).
+pub fn execute_meta(args: MetaArgs, config: &Config) -> Result<()> {
+    let output = Output::new(args.json_mode);
+    let resolved = resolve_skill(&args.skill, config)?;
+    let skill_md = &resolved.skill_md;
+
+    let content = fs::read_to_string(skill_md).map_err(|e| SikilError::InvalidSkillMd {
+        path: skill_md.clone(),
+        reason: format!("failed to read file: {}", e),
+        line: None,
+        column: None,
+    })?;
+    let mut editor = FrontmatterEditor::parse(&content).map_err(|e| with_path(e, skill_md))?;
+
+    let meta_output = match args.operation {
+        MetaOperation::Get { key } => {
+            let value = match key {
+                Some(ref key) => editor
+                    .get(key)
+                    .map_err(|e| with_path(e, skill_md))?
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("'{}' is not set in {}", key, skill_md.display())
+                    })?,
+                None => serde_yaml::Value::Mapping(
+                    editor.mapping().map_err(|e| with_path(e, skill_md))?,
+                ),
+            };
+            if !output.json_mode {
+                output.print_info(&format_value(&value));
+            }
+            MetaOutput {
+                path: skill_md.clone(),
+                key,
+                value: Some(value),
+                changed: None,
+            }
+        }
+        MetaOperation::Set { key, value, yaml } => {
+            // The repository directory and agent symlinks are named after
+            // the skill, so editing the name alone would orphan them
+            if key == "name" {
+                return Err(SikilError::ValidationError {
+                    reason: format!(
+                        "'name' cannot be changed with `sikil meta set`; \
+                         to rename '{}', remove it and install it again under the new name",
+                        args.skill
+                    ),
+                }
+                .into());
+            }
+            let value = if yaml {
+                serde_yaml::from_str(&value).map_err(|e| SikilError::ValidationError {
+                    reason: format!("invalid YAML value for '{}': {}", key, e),
+                })?
+            } else {
+                serde_yaml::Value::String(value)
+            };
+            editor
+                .set(&key, &value)
+                .map_err(|e| with_path(e, skill_md))?;
+            let changed = write_if_changed(&content, &editor.render(), &resolved, config)?;
+            if changed {
+                output.print_success(&format!("Set '{}' in {}", key, skill_md.display()));
+            } else {
+                output.print_info(&format!(
+                    "'{}' is already set to that value in {}",
+                    key,
+                    skill_md.display()
+                ));
+            }
+            MetaOutput {
+                path: skill_md.clone(),
+                key: Some(key),
+                value: Some(value),
+                changed: Some(changed),
+            }
+        }
+        MetaOperation::Unset { key } => {
+            let removed = editor.unset(&key).map_err(|e| with_path(e, skill_md))?;
+            let changed =
+                removed && write_if_changed(&content, &editor.render(), &resolved, config)?;
+            if changed {
+                output.print_success(&format!("Removed '{}' from {}", key, skill_md.display()));
+            } else {
+                output.print_info(&format!("'{}' is not set in {}", key, skill_md.display()));
+            }
+            MetaOutput {
+                path: skill_md.clone(),
+                key: Some(key),
+                value: None,
+                changed: Some(changed),
+            }
+        }
+    };
+
+    if output.json_mode {
+        output.print_json(&meta_output)?;
+    }
+
+    Ok(())
+}
+
+/// Validates and writes edited SKILL.md content, then invalidates the cache
+///
+/// Returns `false` without writing if the content did not change.
+fn write_if_changed(
+    old: &str,
+    new: &str,
+    resolved: &ResolvedSkill,
+    config: &Config,
+) -> Result<bool> {
+    if old == new {
+        return Ok(false);
+    }
+
+    // Refuse edits that the scanner could not read back
+    let metadata = parse_skill_md_content(new, &resolved.skill_md)?;
+    validate_skill_name(&metadata.name)?;
+
+    atomic_write(&resolved.skill_md, new.as_bytes())?;
+
+    // The cache is an optimization, so failing to update it is not an error
+    if let Ok(cache) = open_cache(&config.cache) {
+        for path in &resolved.cache_paths {
+            let _ = cache.invalidate(path);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Resolves a skill name or path to the SKILL.md to read or edit
+///
+/// Existing paths (a skill directory or a SKILL.md file) are used directly.
+/// Otherwise the name is looked up among installed skills: managed skills are
+/// edited in the repository, so every agent sees the change; an unmanaged
+/// skill must have a single physical copy.
+fn resolve_skill(skill: &str, config: &Config) -> Result<ResolvedSkill> {
+    let path = Path::new(skill);
+    if path.exists() {
+        let dir = if path.is_file() {
+            path.parent().unwrap_or(path).to_path_buf()
+        } else {
+            path.to_path_buf()
+        };
+        return Ok(ResolvedSkill {
+            skill_md: dir.join("SKILL.md"),
+            cache_paths: vec![dir],
+        });
+    }
+
+    let scan_result = Scanner::new(config.clone()).scan_all_agents();
+    let found = scan_result
+        .skills
+        .get(skill)
+        .ok_or_else(|| SikilError::SkillNotFound {
+            name: skill.to_string(),
+        })?;
+
+    let mut cache_paths: Vec<PathBuf> =
+        found.installations.iter().map(|i| i.path.clone()).collect();
+    cache_paths.extend(found.repo_path.clone());
+
+    let dir = match found.repo_path {
+        Some(ref repo_path) => repo_path.clone(),
+        None => {
+            let mut copies: Vec<PathBuf> = found
+                .installations
+                .iter()
+                .map(|i| fs::canonicalize(&i.path).unwrap_or_else(|_| i.path.clone()))
+                .collect();
+            copies.sort();
+            copies.dedup();
+            match copies.as_slice() {
+                [single] => single.clone(),
+                [] => {
+                    return Err(SikilError::SkillNotFound {
+                        name: skill.to_string(),
+                    }
+                    .into())
+                }
+                _ => {
+                    return Err(SikilError::ValidationError {
+                        reason: format!(
+                            "'{}' has {} unmanaged copies; pass the path of the one to edit: {}",
+                            skill,
+                            copies.len(),
+                            copies
+                                .iter()
+                                .map(|p| p.display().to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    }
+                    .into())
+                }
+            }
+        }
+    };
+
+    Ok(ResolvedSkill {
+        skill_md: dir.join("SKILL.md"),
+        cache_paths,
+    })
+}
+
+/// Replaces the `<content>` placeholder path in parser errors
+fn with_path(err: SikilError, path: &Path) -> SikilError {
+    match err {
+        SikilError::InvalidSkillMd {
+            reason,
+            line,
+            column,
+            ..
+        } => SikilError::InvalidSkillMd {
+            path: path.to_path_buf(),
+            reason,
+            line,
+            column,
+        },
+        other => other,
+    }
+}
+
+/// Formats a value for human output: strings as-is, everything else as YAML
+fn format_value(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::String(s) => s.clone(),
+        other => serde_yaml::to_string(other)
+            .map(|s| s.trim_end().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn run(dir: &Path, operation: MetaOperation) -> Result<()> {
+        let args = MetaArgs {
+            json_mode: true,
+            skill: dir.to_string_lossy().to_string(),
+            operation,
+        };
+        execute_meta(args, &Config::default())
+    }
+
+    fn create_skill(content: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("SKILL.md"), content).unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_set_keeps_comments_and_body() {
+        let temp_dir =
+            create_skill("---\n# team: docs\nname: my-skill\ndescription: d\n---\n\n# Body\n");
+        run(
+            temp_dir.path(),
+            MetaOperation::Set {
+                key: "license".to_string(),
+                value: "MIT".to_string(),
+                yaml: false,
+            },
+        )
+        .unwrap();
+
+        let content = fs::read_to_string(temp_dir.path().join("SKILL.md")).unwrap();
+        assert_eq!(
+            content,
+            "---\n# team: docs\nname: my-skill\ndescription: d\nlicense: MIT\n---\n\n# Body\n"
+        );
+    }
+
+    #[test]
+    fn test_edit_that_breaks_skill_is_rejected() {
+        let original = "---\nname: my-skill\ndescription: d\n---\n";
+        let temp_dir = create_skill(original);
+
+        let err = run(
+            temp_dir.path(),
+            MetaOperation::Unset {
+                key: "description".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("missing required field 'description'"));
+
+        let err = run(
+            temp_dir.path(),
+            MetaOperation::Set {
+                key: "name".to_string(),
+                value: "Not Valid".to_string(),
+                yaml: false,
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("'name' cannot be changed"));
+
+        let content = fs::read_to_string(temp_dir.path().join("SKILL.md")).unwrap();
+        assert_eq!(content, original);
+    }
+
+    #[test]
+    fn test_get_missing_key_is_an_error() {
+        let temp_dir = create_skill("---\nname: my-skill\ndescription: d\n---\n");
+        let err = run(
+            temp_dir.path(),
+            MetaOperation::Get {
+                key: Some("homepage".to_string()),
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("'homepage' is not set"));
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(&serde_yaml::Value::from("a: b")), "a: b");
+        let list: serde_yaml::Value = serde_yaml::from_str("[a, b]").unwrap();
+        assert_eq!(format_value(&list), "- a\n- b");
+    }
+}
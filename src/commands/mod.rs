@@ -1,25 +1,78 @@
 //! Commands module for implementing CLI subcommands
 
+use crate::core::errors::SikilError;
+use anyhow::Result;
+use std::io::{self, Write};
+
 pub mod adopt;
 pub mod agent_selection;
+pub mod cache;
 pub mod completions;
 pub mod config;
+pub mod doctor;
+pub mod gc;
 pub mod install;
 pub mod list;
+pub mod log;
+pub mod meta;
+pub mod permissions;
+pub mod recover;
 pub mod remove;
+pub mod repair;
+pub mod search;
 pub mod show;
 pub mod sync;
+pub mod trash;
+pub mod undo;
 pub mod unmanage;
 pub mod validate;
 
 pub use adopt::{execute_adopt, AdoptArgs};
 pub use agent_selection::{parse_agent_selection, prompt_agent_selection};
+pub use cache::{execute_cache, CacheArgs, CacheOperation};
 pub use completions::{execute_completions, CompletionsArgs};
 pub use config::{execute_config, ConfigArgs};
+pub use doctor::{execute_doctor, DoctorArgs};
+pub use gc::{execute_gc, GcArgs};
 pub use install::{execute_install_git, execute_install_local, InstallArgs};
 pub use list::{execute_list, ListArgs};
+pub use log::{execute_log, LogArgs};
+pub use meta::{execute_meta, MetaArgs, MetaOperation};
+pub use permissions::{execute_permissions, PermissionsArgs};
+pub use recover::{check_interrupted, execute_recover, RecoverArgs};
 pub use remove::{execute_remove, RemoveArgs};
+pub use repair::{execute_repair, RepairArgs};
+pub use search::{execute_search, SearchArgs};
 pub use show::{execute_show, ShowArgs};
 pub use sync::{execute_sync, SyncArgs};
+pub use trash::{execute_trash, TrashArgs, TrashOperation};
+pub use undo::{execute_undo, UndoArgs};
 pub use unmanage::{execute_unmanage, UnmanageArgs};
-pub use validate::{execute_validate, ValidateArgs};
+pub use validate::{execute_validate, ValidateArgs, ValidateFormat};
+
+/// Prompts the user for confirmation with a y/N prompt
+///
+/// Returns true only if the user answers 'y' or 'yes'.
+///
+/// # Errors
+///
+/// Returns an error if stdout cannot be flushed or stdin cannot be read.
+pub(crate) fn prompt_confirmation(prompt: &str) -> Result<bool> {
+    print!("{} [y/N]: ", prompt);
+    io::stdout()
+        .flush()
+        .map_err(|_e| SikilError::PermissionDenied {
+            operation: "flush stdout".to_string(),
+            path: "stdout".into(),
+        })?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|_e| SikilError::PermissionDenied {
+            operation: "read stdin".to_string(),
+            path: "stdin".into(),
+        })?;
+
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
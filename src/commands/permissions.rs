@@ -0,0 +1,274 @@
+//! Permissions command implementation
+//!
+//! This module implements `sikil permissions`, a report of the tools each
+//! installed skill pre-approves with `allowed-tools`. Skills that grant shell
+//! or network access are flagged, since an agent can use those tools without
+//! asking while the skill is active.
+
+use crate::cli::output::Output;
+use crate::core::config::Config;
+use crate::core::scanner::Scanner;
+use crate::core::skill::Skill;
+use crate::core::tools::{parse_allowed_tools, ToolCapability, ToolGrant};
+use anyhow::Result;
+
+/// Arguments for the permissions command
+#[derive(Debug, Clone)]
+pub struct PermissionsArgs {
+    /// Whether to output in JSON format
+    pub json_mode: bool,
+    /// Whether to disable cache
+    pub no_cache: bool,
+    /// Only show skills that grant shell or network access
+    pub flagged_only: bool,
+}
+
+/// A single tool grant with its security-relevant capabilities
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct GrantOutput {
+    /// The `allowed-tools` entry
+    pub tool: ToolGrant,
+    /// Shell or network access this grant gives
+    pub capabilities: Vec<ToolCapability>,
+}
+
+/// Tool permissions of one skill
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SkillPermissions {
+    /// Skill name
+    pub name: String,
+    /// Agents the skill is installed for
+    pub agents: Vec<String>,
+    /// Whether the skill is managed
+    pub managed: bool,
+    /// Tools the skill pre-approves
+    pub grants: Vec<GrantOutput>,
+    /// Union of the capabilities of all grants
+    pub flags: Vec<ToolCapability>,
+    /// Why `allowed-tools` could not be parsed (the skill's grants are unknown)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result of the permissions command
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PermissionsReport {
+    /// Skills that declare `allowed-tools`, sorted by name
+    pub skills: Vec<SkillPermissions>,
+    /// Number of installed skills without `allowed-tools`
+    pub undeclared: usize,
+}
+
+/// Executes the permissions command
+///
+/// # Errors
+///
+/// Returns an error if JSON output cannot be written.
+pub fn execute_permissions(args: PermissionsArgs, config: &Config) -> Result<()> {
+    let output = Output::new(args.json_mode);
+
+    let scanner = if args.no_cache {
+        Scanner::without_cache(config.clone())
+    } else {
+        Scanner::new(config.clone())
+    };
+    let scan_result = scanner.scan_all_agents();
+
+    let mut skills: Vec<&Skill> = scan_result.skills.values().collect();
+    skills.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+    let mut report = build_report(&skills);
+    if args.flagged_only {
+        report
+            .skills
+            .retain(|s| !s.flags.is_empty() || s.error.is_some());
+    }
+
+    if args.json_mode {
+        output.print_json(&report)?;
+    } else {
+        print_report(&output, &report);
+    }
+
+    Ok(())
+}
+
+/// Builds the permissions report for a list of skills
+pub fn build_report(skills: &[&Skill]) -> PermissionsReport {
+    let mut report = PermissionsReport {
+        skills: Vec::new(),
+        undeclared: 0,
+    };
+
+    for skill in skills {
+        let metadata = &skill.metadata;
+        let (grants, error) = match (&metadata.allowed_tools, metadata.extra.get("allowed-tools")) {
+            (Some(grants), _) => (grants.clone(), None),
+            // The parser keeps an invalid value in `extra`
+            (None, Some(value)) => (Vec::new(), parse_allowed_tools(value).err()),
+            (None, None) => {
+                report.undeclared += 1;
+                continue;
+            }
+        };
+
+        let grants: Vec<GrantOutput> = grants
+            .into_iter()
+            .map(|tool| GrantOutput {
+                capabilities: tool.capabilities(),
+                tool,
+            })
+            .collect();
+        let mut flags: Vec<ToolCapability> = grants
+            .iter()
+            .flat_map(|g| g.capabilities.iter().copied())
+            .collect();
+        flags.sort();
+        flags.dedup();
+
+        let mut agents: Vec<String> = skill
+            .installations
+            .iter()
+            .filter(|i| Some(&i.path) != skill.repo_path.as_ref())
+            .map(|i| i.agent.to_string())
+            .collect();
+        agents.sort();
+        agents.dedup();
+
+        report.skills.push(SkillPermissions {
+            name: metadata.name.clone(),
+            agents,
+            managed: skill.is_managed,
+            grants,
+            flags,
+            error,
+        });
+    }
+
+    report
+}
+
+/// Prints the report in human-readable form
+fn print_report(output: &Output, report: &PermissionsReport) {
+    if report.skills.is_empty() {
+        output.print_info("No skills declare allowed-tools.");
+    }
+
+    for skill in &report.skills {
+        let agents = if skill.agents.is_empty() {
+            "-".to_string()
+        } else {
+            skill.agents.join(", ")
+        };
+        output.print_info(&format!("{} [{}]", skill.name, agents));
+
+        if let Some(ref error) = skill.error {
+            output.print_warning(&format!("  invalid allowed-tools: {}", error));
+            continue;
+        }
+        let width = skill
+            .grants
+            .iter()
+            .map(|g| g.tool.to_string().len())
+            .max()
+            .unwrap_or(0);
+        for grant in &skill.grants {
+            let tool = grant.tool.to_string();
+            if grant.capabilities.is_empty() {
+                output.print_info(&format!("  {}", tool));
+            } else {
+                let capabilities: Vec<String> =
+                    grant.capabilities.iter().map(ToString::to_string).collect();
+                output.print_warning(&format!(
+                    "  {:<width$}  {}",
+                    tool,
+                    capabilities.join(", "),
+                    width = width
+                ));
+            }
+        }
+    }
+
+    let flagged: Vec<&str> = report
+        .skills
+        .iter()
+        .filter(|s| !s.flags.is_empty())
+        .map(|s| s.name.as_str())
+        .collect();
+    output.print_info("");
+    if !flagged.is_empty() {
+        output.print_warning(&format!(
+            "{} skill(s) grant shell or network access: {}",
+            flagged.len(),
+            flagged.join(", ")
+        ));
+    }
+    if report.undeclared > 0 {
+        output.print_info(&format!(
+            "{} skill(s) do not declare allowed-tools",
+            report.undeclared
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::skill::{Agent, Installation, Scope, SkillMetadata};
+    use std::path::PathBuf;
+
+    fn skill(name: &str, tools: Option<&str>) -> Skill {
+        let mut metadata = SkillMetadata::new(name.to_string(), "d".to_string());
+        if let Some(tools) = tools {
+            let value = serde_yaml::Value::from(tools);
+            match parse_allowed_tools(&value) {
+                Ok(grants) => metadata.allowed_tools = Some(grants),
+                Err(_) => {
+                    metadata.extra.insert("allowed-tools".to_string(), value);
+                }
+            }
+        }
+        Skill::new(metadata, name.to_string()).with_installation(Installation::new(
+            Agent::ClaudeCode,
+            PathBuf::from(format!("/skills/{}", name)),
+            Scope::Global,
+        ))
+    }
+
+    #[test]
+    fn test_build_report_flags_shell_and_network() {
+        let skills = [
+            skill("git-helper", Some("Read, Bash(git:*)")),
+            skill("plain", None),
+            skill("reader", Some("Read Grep")),
+            skill("broken", Some("Bash(git:*")),
+            skill("web", Some("WebFetch")),
+        ];
+        let refs: Vec<&Skill> = skills.iter().collect();
+        let report = build_report(&refs);
+
+        assert_eq!(report.undeclared, 1);
+        let names: Vec<&str> = report.skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["git-helper", "reader", "broken", "web"]);
+
+        assert_eq!(report.skills[0].flags, vec![ToolCapability::Shell]);
+        assert_eq!(report.skills[0].agents, vec!["claude-code"]);
+        assert!(report.skills[1].flags.is_empty());
+        assert!(report.skills[2]
+            .error
+            .as_ref()
+            .unwrap()
+            .contains("unclosed"));
+        assert_eq!(report.skills[3].flags, vec![ToolCapability::Network]);
+    }
+
+    #[test]
+    fn test_report_serialization() {
+        let skills = [skill("git-helper", Some("Bash(git:*)"))];
+        let refs: Vec<&Skill> = skills.iter().collect();
+        let json = serde_json::to_string(&build_report(&refs)).unwrap();
+        assert!(
+            json.contains("\"grants\":[{\"tool\":\"Bash(git:*)\",\"capabilities\":[\"shell\"]}]")
+        );
+        assert!(!json.contains("error"));
+    }
+}
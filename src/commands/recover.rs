@@ -0,0 +1,296 @@
+//! Recover command implementation
+//!
+//! This module settles operations that were interrupted part-way, as
+//! recorded by the write-ahead journal in `~/.sikil/journal/`. Each one can
+//! be rolled back to the state before it started, or rolled forward by
+//! running its remaining steps.
+
+use crate::cli::output::Output;
+use crate::core::errors::SikilError;
+use crate::core::journal::{Journal, Recovery};
+use crate::utils::paths::get_journal_dir;
+use anyhow::Result;
+use serde::Serialize;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Arguments for the recover command
+#[derive(Debug, Clone)]
+pub struct RecoverArgs {
+    /// Whether to output in JSON format
+    pub json_mode: bool,
+    /// How to settle interrupted operations (None lists them)
+    pub recovery: Option<Recovery>,
+}
+
+/// An interrupted operation and what was done about it
+#[derive(Debug, Clone, Serialize)]
+pub struct InterruptedOperation {
+    /// Command that started the operation
+    pub command: String,
+    /// Skill the operation acts on
+    pub skill: String,
+    /// Unix timestamp (seconds) when the operation started
+    pub started_at: u64,
+    /// Number of steps that completed
+    pub completed: usize,
+    /// Every planned step, in execution order
+    pub steps: Vec<String>,
+    /// `rolled-back`, `rolled-forward` or `pending`
+    pub outcome: &'static str,
+    /// Why recovery failed, if it did
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl InterruptedOperation {
+    fn new(journal: &Journal) -> Self {
+        let record = journal.record();
+        Self {
+            command: record.command.clone(),
+            skill: record.skill.clone(),
+            started_at: record.started_at,
+            completed: record.completed,
+            steps: record.steps.iter().map(|step| step.to_string()).collect(),
+            outcome: "pending",
+            error: None,
+        }
+    }
+
+    fn summary(&self) -> String {
+        let title = format!("{} {}", self.command, self.skill);
+        format!(
+            "Interrupted `{}` ({} of {} steps done)",
+            title.trim_end(),
+            self.completed,
+            self.steps.len()
+        )
+    }
+}
+
+/// Executes the recover command
+///
+/// Without a recovery mode, lists interrupted operations. With one, rolls
+/// each of them back or forward.
+///
+/// # Errors
+///
+/// Returns `SikilError::ValidationError` if any operation could not be
+/// recovered.
+pub fn execute_recover(args: RecoverArgs) -> Result<()> {
+    let output = Output::new(args.json_mode);
+    let operations = recover_in(&get_journal_dir(), args.recovery);
+
+    if args.json_mode {
+        output.print_json(&operations)?;
+    } else if operations.is_empty() {
+        output.print_success("No interrupted operations");
+    } else {
+        for operation in &operations {
+            print_operation(&output, operation);
+        }
+        if args.recovery.is_none() {
+            output.print_info("");
+            output.print_info(
+                "Run `sikil recover --rollback` or `sikil recover --forward` to settle them.",
+            );
+        }
+    }
+
+    let failed = operations.iter().filter(|op| op.error.is_some()).count();
+    if failed > 0 {
+        return Err(SikilError::ValidationError {
+            reason: format!("{} operation(s) could not be recovered", failed),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Looks for interrupted operations before a mutating command runs
+///
+/// In a terminal the user is asked to roll each one back or forward, or to
+/// leave it for later. Otherwise a warning points at `sikil recover`.
+///
+/// # Errors
+///
+/// Returns an error if a chosen recovery fails, so the command does not
+/// run on top of a half-finished operation.
+pub fn check_interrupted(json_mode: bool) -> Result<()> {
+    let journals = Journal::load_all(&get_journal_dir());
+    if journals.is_empty() {
+        return Ok(());
+    }
+
+    let output = Output::new(json_mode);
+    let interactive = !json_mode && atty::is(atty::Stream::Stdin);
+
+    for journal in journals {
+        let mut operation = InterruptedOperation::new(&journal);
+        if !interactive {
+            output.print_warning(&format!(
+                "{}; run `sikil recover --rollback` or `sikil recover --forward`",
+                operation.summary()
+            ));
+            continue;
+        }
+
+        print_operation(&output, &operation);
+        let Some(recovery) = prompt_recovery()? else {
+            output.print_info("Left for later (see `sikil recover`)");
+            continue;
+        };
+        settle(journal, recovery, &mut operation);
+        print_outcome(&output, &operation);
+        if let Some(error) = operation.error {
+            return Err(SikilError::ValidationError { reason: error }.into());
+        }
+    }
+    output.print_info("");
+    Ok(())
+}
+
+/// Settles every journal in `dir`, or only describes them when `recovery`
+/// is None
+pub fn recover_in(dir: &Path, recovery: Option<Recovery>) -> Vec<InterruptedOperation> {
+    Journal::load_all(dir)
+        .into_iter()
+        .map(|journal| {
+            let mut operation = InterruptedOperation::new(&journal);
+            if let Some(recovery) = recovery {
+                settle(journal, recovery, &mut operation);
+            }
+            operation
+        })
+        .collect()
+}
+
+fn settle(journal: Journal, recovery: Recovery, operation: &mut InterruptedOperation) {
+    operation.outcome = match recovery {
+        Recovery::RollBack => "rolled-back",
+        Recovery::RollForward => "rolled-forward",
+    };
+    if let Err(e) = journal.recover(recovery) {
+        operation.error = Some(e.to_string());
+    }
+}
+
+fn print_operation(output: &Output, operation: &InterruptedOperation) {
+    output.print_warning(&operation.summary());
+    for (index, step) in operation.steps.iter().enumerate() {
+        let mark = if index < operation.completed {
+            "done"
+        } else {
+            "todo"
+        };
+        output.print_info(&format!("  [{}] {}", mark, step));
+    }
+    print_outcome(output, operation);
+}
+
+fn print_outcome(output: &Output, operation: &InterruptedOperation) {
+    match (&operation.error, operation.outcome) {
+        (Some(error), _) => output.print_error(error),
+        (None, "rolled-back") => output.print_success("Rolled back"),
+        (None, "rolled-forward") => output.print_success("Rolled forward"),
+        _ => {}
+    }
+}
+
+/// Asks whether to roll back, roll forward or skip; skipping is the default
+fn prompt_recovery() -> Result<Option<Recovery>> {
+    print!("Roll back, roll forward or skip? [b/f/S]: ");
+    io::stdout()
+        .flush()
+        .map_err(|_e| SikilError::PermissionDenied {
+            operation: "flush stdout".to_string(),
+            path: "stdout".into(),
+        })?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|_e| SikilError::PermissionDenied {
+            operation: "read stdin".to_string(),
+            path: "stdin".into(),
+        })?;
+
+    Ok(match input.trim().to_lowercase().as_str() {
+        "b" | "back" => Some(Recovery::RollBack),
+        "f" | "forward" => Some(Recovery::RollForward),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::plan::Step;
+    use tempfile::TempDir;
+
+    fn interrupted_install(root: &Path) -> (std::path::PathBuf, std::path::PathBuf) {
+        let source = root.join("source");
+        let repo = root.join("repo/s");
+        std::fs::create_dir_all(&source).unwrap();
+        std::fs::write(
+            source.join("SKILL.md"),
+            "---\nname: s\ndescription: d\n---\n",
+        )
+        .unwrap();
+
+        let mut journal = Journal::begin_in(
+            &root.join("journal"),
+            "install",
+            "s",
+            vec![
+                Step::CopyDir {
+                    from: source,
+                    to: repo.clone(),
+                },
+                Step::CreateSymlink {
+                    target: repo.clone(),
+                    link: root.join("agent/s"),
+                },
+            ],
+        )
+        .unwrap();
+        journal.apply_next().unwrap();
+        (repo, root.join("agent/s"))
+    }
+
+    #[test]
+    fn test_recover_lists_without_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let (repo, _) = interrupted_install(temp_dir.path());
+
+        let operations = recover_in(&temp_dir.path().join("journal"), None);
+
+        assert_eq!(operations.len(), 1);
+        assert_eq!(operations[0].outcome, "pending");
+        assert_eq!(operations[0].completed, 1);
+        assert_eq!(operations[0].steps.len(), 2);
+        assert!(repo.exists());
+        assert_eq!(recover_in(&temp_dir.path().join("journal"), None).len(), 1);
+    }
+
+    #[test]
+    fn test_recover_rolls_back_and_forward() {
+        let temp_dir = TempDir::new().unwrap();
+        let (repo, link) = interrupted_install(temp_dir.path());
+        let journal_dir = temp_dir.path().join("journal");
+
+        let operations = recover_in(&journal_dir, Some(Recovery::RollBack));
+        assert_eq!(operations[0].outcome, "rolled-back");
+        assert!(operations[0].error.is_none());
+        assert!(!repo.exists());
+        assert!(recover_in(&journal_dir, None).is_empty());
+
+        let (repo, link2) = interrupted_install(temp_dir.path());
+        assert_eq!(link, link2);
+        let operations = recover_in(&journal_dir, Some(Recovery::RollForward));
+        assert_eq!(operations[0].outcome, "rolled-forward");
+        assert!(repo.join("SKILL.md").is_file());
+        assert!(link.is_symlink());
+        assert!(recover_in(&journal_dir, None).is_empty());
+    }
+}
@@ -7,12 +7,13 @@
 use crate::cli::output::Output;
 use crate::core::config::Config;
 use crate::core::errors::SikilError;
+use crate::core::plan::{Plan, Step};
 use crate::core::scanner::Scanner;
 use crate::core::skill::Agent;
-use crate::utils::atomic::safe_remove_dir;
-use crate::utils::paths::get_repo_path;
+use crate::core::trash::TrashEntry;
+use crate::utils::paths::{get_repo_path, get_trash_dir};
+use crate::utils::time::now_secs;
 use anyhow::Result;
-use fs_err as fs;
 use std::io::{self, Write};
 
 /// Arguments for the remove command
@@ -20,6 +21,8 @@ use std::io::{self, Write};
 pub struct RemoveArgs {
     /// Whether to output in JSON format
     pub json_mode: bool,
+    /// Print the plan instead of removing
+    pub dry_run: bool,
     /// Name of the skill to remove
     pub name: String,
     /// Optional agent(s) to remove from (comma-separated)
@@ -142,6 +145,7 @@ fn parse_agents(agent_str: &str) -> Result<Vec<Agent>> {
 /// let config = Config::default();
 /// let args = RemoveArgs {
 ///     json_mode: false,
+///     dry_run: false,
 ///     name: "my-skill".to_string(),
 ///     agent: Some("claude-code".to_string()),
 ///     all: false,
@@ -258,6 +262,54 @@ pub fn execute_remove(args: RemoveArgs, config: &Config) -> Result<()> {
         output.print_info("");
     }
 
+    // M3-E05-T01-S05: If --all and managed, delete from repo as the last step
+    let repo_to_delete = repo_path
+        .as_ref()
+        .filter(|repo| args.all && repo.exists())
+        .cloned();
+
+    // M3-E05-T01-S04: Remove installations (symlink or directory); directories
+    // go to the trash along with a record of where every installation lived
+    let mut trash = TrashEntry::new(&get_trash_dir(), &args.name, now_secs());
+    let mut plan = Plan::new("remove", &args.name);
+    for installation in &installations_to_remove {
+        let symlink_target = if installation.is_symlink == Some(true) {
+            let target = installation
+                .symlink_target
+                .clone()
+                .or_else(|| repo_path.clone())
+                .unwrap_or_default();
+            plan.push(Step::RemoveSymlink {
+                link: installation.path.clone(),
+                target: target.clone(),
+            });
+            Some(target)
+        } else {
+            None
+        };
+        trash.trash_installation(
+            installation.agent.cli_name(),
+            &installation.path,
+            symlink_target.as_deref(),
+            &mut plan,
+        );
+    }
+    if let Some(repo) = &repo_to_delete {
+        trash.trash_repo(repo, &mut plan);
+    }
+
+    if args.dry_run {
+        // Removing the last installation orphans the repository entry, which
+        // is deleted after confirmation
+        if let Some(repo) = repo_path.as_ref().filter(|repo| repo.exists()) {
+            if !args.all && installations_to_remove.len() == skill.installations.len() {
+                trash.trash_repo(repo, &mut plan);
+            }
+        }
+        output.print_plan(&plan)?;
+        return Ok(());
+    }
+
     // M3-E05-T02-S01, M3-E05-T02-S02: Prompt for confirmation (unless --yes or json mode)
     if !args.yes && !args.json_mode {
         let confirmed = prompt_confirmation("Continue?")?;
@@ -272,47 +324,40 @@ pub fn execute_remove(args: RemoveArgs, config: &Config) -> Result<()> {
         }
     }
 
-    let mut removed_count = 0;
-    let mut failed_installations = Vec::new();
-
-    // M3-E05-T01-S04, M3-E05-T01-S05: Remove installations
-    for installation in &installations_to_remove {
-        let install_path = &installation.path;
-
-        // Remove the installation (symlink or directory)
-        let remove_result = if installation.is_symlink == Some(true) {
-            // Remove symlink
-            fs::remove_file(install_path)
-        } else {
-            // Remove physical directory
-            fs::remove_dir_all(install_path)
-        };
-
-        if let Err(e) = remove_result {
-            let err_msg = format!("failed to remove {}: {}", install_path.display(), e);
-            output.print_error(&err_msg);
-            failed_installations.push(install_path.clone());
-            continue;
+    if trash.holds_data() {
+        trash.save()?;
+    }
+    let executed = plan.execute(|step| {
+        if args.json_mode {
+            return;
         }
-
-        removed_count += 1;
-
-        if !args.json_mode {
-            output.print_success(&format!(
+        let removed_path = match step {
+            Step::RemoveSymlink { link, .. } => link,
+            Step::MoveDir { from, .. } => from,
+            _ => return,
+        };
+        match installations_to_remove
+            .iter()
+            .find(|installation| &installation.path == removed_path)
+        {
+            Some(installation) => output.print_success(&format!(
                 "Removed from {} ({})",
                 installation.agent,
-                install_path.display()
-            ));
+                removed_path.display()
+            )),
+            None => output.print_success(&format!("Removed '{}' from repository", args.name)),
         }
-    }
-
-    if !failed_installations.is_empty() {
+    });
+    if let Err(e) = executed {
+        trash.discard_unused();
+        output.print_error(&format!("failed to remove '{}': {}", args.name, e));
         return Err(SikilError::PermissionDenied {
-            operation: format!("remove {} installation(s)", failed_installations.len()),
+            operation: "remove skill".to_string(),
             path: args.name.clone().into(),
         }
         .into());
     }
+    let removed_count = installations_to_remove.len();
 
     // M3-E05-T02-S04: After --agent removal, check if repo is orphaned and prompt
     if is_managed && !args.all && args.agent.is_some() {
@@ -351,7 +396,11 @@ pub fn execute_remove(args: RemoveArgs, config: &Config) -> Result<()> {
                             output.print_info("Removing orphaned repository entry...");
                         }
 
-                        if let Err(_e) = safe_remove_dir(repo, true) {
+                        let mut plan = Plan::new("remove", &args.name);
+                        trash.trash_repo(repo, &mut plan);
+                        trash.save()?;
+                        if let Err(_e) = plan.execute(|_| {}) {
+                            trash.discard_unused();
                             return Err(SikilError::PermissionDenied {
                                 operation: "remove orphaned repository entry".to_string(),
                                 path: repo.clone(),
@@ -376,35 +425,18 @@ pub fn execute_remove(args: RemoveArgs, config: &Config) -> Result<()> {
         }
     }
 
-    // M3-E05-T01-S05: If --all and managed, delete from repo
-    if args.all && is_managed {
-        if let Some(repo) = &repo_path {
-            if repo.exists() {
-                if !args.json_mode {
-                    output.print_info("Removing from repository...");
-                }
-
-                if let Err(_e) = safe_remove_dir(repo, true) {
-                    return Err(SikilError::PermissionDenied {
-                        operation: "remove skill from repository".to_string(),
-                        path: repo.clone(),
-                    }
-                    .into());
-                }
-
-                if !args.json_mode {
-                    output.print_success(&format!("Removed '{}' from repository", args.name));
-                }
-            }
-        }
-    }
-
     if !args.json_mode {
         output.print_info("");
         output.print_success(&format!(
             "Successfully removed '{}' from {} location(s)",
             args.name, removed_count
         ));
+        if trash.holds_data() {
+            output.print_info(&format!(
+                "Moved to trash; restore with `sikil trash restore {}`",
+                trash.id()
+            ));
+        }
     }
 
     Ok(())
@@ -476,6 +508,7 @@ This is a test skill."#,
     fn test_remove_args() {
         let args = RemoveArgs {
             json_mode: true,
+            dry_run: false,
             name: "my-skill".to_string(),
             agent: Some("claude-code".to_string()),
             all: false,
@@ -493,6 +526,7 @@ This is a test skill."#,
     fn test_remove_args_all() {
         let args = RemoveArgs {
             json_mode: false,
+            dry_run: false,
             name: "my-skill".to_string(),
             agent: None,
             all: true,
@@ -544,6 +578,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = RemoveArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: Some("claude-code".to_string()),
             all: false,
@@ -582,6 +617,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = RemoveArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: None,
             all: true,
@@ -614,6 +650,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = RemoveArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: None,
             all: true,
@@ -645,6 +682,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = RemoveArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: None,
             all: false, // Neither --agent nor --all
@@ -672,6 +710,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = RemoveArgs {
             json_mode: false,
+            dry_run: false,
             name: "non-existent".to_string(),
             agent: None,
             all: true,
@@ -700,6 +739,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = RemoveArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: Some("invalid-agent".to_string()),
             all: false,
@@ -761,6 +801,7 @@ This is a test skill."#,
         // Try to remove from claude-code (which doesn't have the skill)
         let args = RemoveArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: Some("claude-code".to_string()),
             all: false,
@@ -806,6 +847,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = RemoveArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: None,
             all: true,
@@ -841,6 +883,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = RemoveArgs {
             json_mode: true, // JSON mode should skip confirmation
+            dry_run: false,
             name: skill_name.to_string(),
             agent: None,
             all: true,
@@ -909,6 +952,7 @@ This is a test skill."#,
         // Remove from windsurf (leaves one symlink in claude-code)
         let args = RemoveArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: Some("windsurf".to_string()),
             all: false,
@@ -984,6 +1028,7 @@ This is a test skill."#,
         // Remove from both agents using comma-separated list
         let args = RemoveArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: Some("claude-code,windsurf".to_string()),
             all: false,
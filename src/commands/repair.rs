@@ -0,0 +1,593 @@
+//! Repair command implementation
+//!
+//! This module restores managed installations that have drifted from the
+//! repository: dangling symlinks whose skill is still managed, symlinks that
+//! point at an old repository location, and empty directories left behind in
+//! agent skill directories by a managed or trashed skill.
+//!
+//! A managed skill whose link was deleted outright leaves nothing behind to
+//! repair; `sikil sync` recreates such links.
+
+use crate::cli::output::Output;
+use crate::core::config::Config;
+use crate::core::errors::SikilError;
+use crate::core::scanner::Scanner;
+use crate::core::skill::{Agent, Scope};
+use crate::core::trash::TrashEntry;
+use crate::utils::paths::{get_repo_path, get_trash_dir};
+use crate::utils::symlink::{create_symlink, is_symlink, list_symlinks};
+use anyhow::Result;
+use fs_err as fs;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Arguments for the repair command
+#[derive(Debug, Clone)]
+pub struct RepairArgs {
+    /// Whether to output in JSON format
+    pub json_mode: bool,
+    /// Only report what would be repaired
+    pub dry_run: bool,
+}
+
+/// Kind of repair to perform
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RepairKind {
+    /// Replace a broken or stale symlink with one pointing into the repository
+    Relink,
+    /// Remove an empty directory from an agent skill directory
+    RemoveEmptyDir,
+    /// A problem that cannot be repaired automatically
+    Unrepairable,
+}
+
+/// State of a repair action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepairStatus {
+    /// Found but not applied (dry run)
+    Planned,
+    /// Applied successfully
+    Done,
+    /// Applying failed
+    Failed,
+    /// Left untouched
+    Skipped,
+}
+
+/// A single repair found in an agent directory
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RepairAction {
+    /// What kind of repair this is
+    pub action: RepairKind,
+    /// Agent whose directory contains the path
+    pub agent: String,
+    /// Path being repaired
+    pub path: PathBuf,
+    /// Current symlink target, if the path is a symlink
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_target: Option<PathBuf>,
+    /// Symlink target after the repair
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_target: Option<PathBuf>,
+    /// Why the repair is needed
+    pub reason: String,
+    /// Outcome of the action
+    pub status: RepairStatus,
+    /// Error message if the action failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Executes the repair command
+///
+/// This function:
+/// 1. Walks every enabled agent skill directory
+/// 2. Plans relinks for dangling or stale managed symlinks
+/// 3. Plans removal of empty directories named after a managed or trashed skill
+/// 4. Applies the plan unless `--dry-run` was given
+///
+/// # Arguments
+///
+/// * `args` - Repair arguments
+/// * `config` - Configuration for resolving agent paths
+/// * `repo_path` - Optional repo path override (for testing)
+///
+/// # Errors
+///
+/// Returns an error if any planned action fails to apply.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sikil::commands::repair::{execute_repair, RepairArgs};
+/// use sikil::core::config::Config;
+///
+/// let config = Config::default();
+/// let args = RepairArgs {
+///     json_mode: false,
+///     dry_run: true,
+/// };
+/// execute_repair(args, &config, None).unwrap();
+/// ```
+pub fn execute_repair(args: RepairArgs, config: &Config, repo_path: Option<PathBuf>) -> Result<()> {
+    let output = Output::new(args.json_mode);
+    let repo_path = repo_path.unwrap_or_else(get_repo_path);
+
+    let agent_dirs = Scanner::without_cache(config.clone())
+        .with_repo_root(&repo_path)
+        .agent_directories();
+
+    let mut actions = plan_repairs(&agent_dirs, &repo_path, &get_trash_dir());
+
+    if !args.dry_run {
+        for action in &mut actions {
+            apply_repair(action);
+        }
+    }
+
+    if args.json_mode {
+        output.print_json(&actions)?;
+    } else {
+        print_human_readable(&output, &actions, args.dry_run);
+    }
+
+    let failed = actions
+        .iter()
+        .filter(|a| a.status == RepairStatus::Failed)
+        .count();
+    if failed > 0 {
+        return Err(SikilError::SymlinkError {
+            reason: format!("{} repair action(s) failed", failed),
+            source: None,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Plans repairs for the given agent directories without changing anything
+///
+/// Every returned action is either `Planned` or, for problems that cannot be
+/// fixed automatically, `Skipped`. Only empty directories named after a skill
+/// in the repository or in `trash_dir` are removed; others may belong to the
+/// user.
+pub fn plan_repairs(
+    agent_dirs: &[(Agent, Scope, PathBuf)],
+    repo_path: &Path,
+    trash_dir: &Path,
+) -> Vec<RepairAction> {
+    let repo_real = repo_path
+        .canonicalize()
+        .unwrap_or_else(|_| repo_path.to_path_buf());
+    let skill_names = sikil_skill_names(repo_path, trash_dir);
+    let mut seen = HashSet::new();
+    let mut actions = Vec::new();
+
+    for (agent, _, dir) in agent_dirs {
+        if !seen.insert(dir.clone()) {
+            continue;
+        }
+
+        for link in list_symlinks(dir) {
+            if let Some(action) = plan_symlink(*agent, &link, repo_path, &repo_real) {
+                actions.push(action);
+            }
+        }
+
+        for empty in empty_subdirectories(dir, &skill_names) {
+            actions.push(RepairAction {
+                action: RepairKind::RemoveEmptyDir,
+                agent: agent.to_string(),
+                path: empty,
+                old_target: None,
+                new_target: None,
+                reason: "empty skill directory".to_string(),
+                status: RepairStatus::Planned,
+                error: None,
+            });
+        }
+    }
+
+    actions
+}
+
+/// Decides whether a symlink in an agent directory needs repair
+fn plan_symlink(
+    agent: Agent,
+    link: &Path,
+    repo_path: &Path,
+    repo_real: &Path,
+) -> Option<RepairAction> {
+    let raw_target = fs::read_link(link).ok()?;
+    let target = match link.parent() {
+        Some(parent) if raw_target.is_relative() => parent.join(&raw_target),
+        _ => raw_target.clone(),
+    };
+
+    let resolved = link.canonicalize().ok();
+    let into_repo = target.starts_with(repo_path)
+        || target.starts_with(repo_real)
+        || resolved
+            .as_ref()
+            .map(|r| r.starts_with(repo_real))
+            .unwrap_or(false);
+
+    // User symlinks that never pointed into a sikil repository are not ours
+    if !into_repo && !looks_like_repo_entry(&target) {
+        return None;
+    }
+
+    // Healthy managed link
+    if resolved.is_some() && into_repo {
+        return None;
+    }
+
+    let link_name = link.file_name()?.to_string_lossy().to_string();
+    let skill_name = if into_repo {
+        link_name
+    } else {
+        target
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or(link_name)
+    };
+
+    let candidate = repo_path.join(&skill_name);
+    let reason = match (resolved.is_some(), into_repo) {
+        (false, true) => "symlink target is missing",
+        (false, false) => "symlink points at a missing old repository location",
+        (true, _) => "symlink points at an old repository location",
+    };
+
+    if !candidate.join("SKILL.md").exists() {
+        return Some(RepairAction {
+            action: RepairKind::Unrepairable,
+            agent: agent.to_string(),
+            path: link.to_path_buf(),
+            old_target: Some(raw_target),
+            new_target: None,
+            reason: format!(
+                "{}; no managed copy of '{}' in the repository",
+                reason, skill_name
+            ),
+            status: RepairStatus::Skipped,
+            error: None,
+        });
+    }
+
+    Some(RepairAction {
+        action: RepairKind::Relink,
+        agent: agent.to_string(),
+        path: link.to_path_buf(),
+        old_target: Some(raw_target),
+        new_target: Some(candidate),
+        reason: reason.to_string(),
+        status: RepairStatus::Planned,
+        error: None,
+    })
+}
+
+/// Returns true if a path looks like an entry of some sikil repository
+/// (`.../.sikil/repo/<name>`), as left behind after the repository moved
+fn looks_like_repo_entry(target: &Path) -> bool {
+    let parent = match target.parent() {
+        Some(p) => p,
+        None => return false,
+    };
+    parent.file_name().map(|n| n == "repo").unwrap_or(false)
+        && parent
+            .parent()
+            .and_then(|p| p.file_name())
+            .map(|n| n == ".sikil")
+            .unwrap_or(false)
+}
+
+/// Returns the names of the skills in the repository and in the trash
+fn sikil_skill_names(repo_path: &Path, trash_dir: &Path) -> HashSet<String> {
+    let mut names: HashSet<String> = match fs::read_dir(repo_path) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .collect(),
+        Err(_) => HashSet::new(),
+    };
+    names.extend(
+        TrashEntry::load_all(trash_dir)
+            .into_iter()
+            .map(|entry| entry.record().name.clone()),
+    );
+    names
+}
+
+/// Returns the empty, non-symlink subdirectories of an agent directory whose
+/// name is in `skill_names`
+fn empty_subdirectories(dir: &Path, skill_names: &HashSet<String>) -> Vec<PathBuf> {
+    let mut empty: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .flatten()
+            .filter(|e| skill_names.contains(e.file_name().to_string_lossy().as_ref()))
+            .map(|e| e.path())
+            .filter(|p| !is_symlink(p) && p.is_dir())
+            .filter(|p| {
+                fs::read_dir(p)
+                    .map(|mut entries| entries.next().is_none())
+                    .unwrap_or(false)
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    empty.sort();
+    empty
+}
+
+/// Applies a planned action and records the outcome on it
+fn apply_repair(action: &mut RepairAction) {
+    if action.status != RepairStatus::Planned {
+        return;
+    }
+
+    let result = match action.action {
+        RepairKind::Relink => {
+            let new_target = action.new_target.clone().unwrap_or_default();
+            fs::remove_file(&action.path)
+                .map_err(|e| e.to_string())
+                .and_then(|_| create_symlink(&new_target, &action.path).map_err(|e| e.to_string()))
+        }
+        RepairKind::RemoveEmptyDir => fs::remove_dir(&action.path).map_err(|e| e.to_string()),
+        RepairKind::Unrepairable => return,
+    };
+
+    match result {
+        Ok(()) => action.status = RepairStatus::Done,
+        Err(e) => {
+            action.status = RepairStatus::Failed;
+            action.error = Some(e);
+        }
+    }
+}
+
+/// Prints human-readable output for the repair command
+fn print_human_readable(output: &Output, actions: &[RepairAction], dry_run: bool) {
+    if actions.is_empty() {
+        output.print_success("Nothing to repair");
+        return;
+    }
+
+    for action in actions {
+        let description = match action.action {
+            RepairKind::Relink => format!(
+                "relink {} -> {}",
+                action.path.display(),
+                action
+                    .new_target
+                    .as_ref()
+                    .map(|t| t.display().to_string())
+                    .unwrap_or_default()
+            ),
+            RepairKind::RemoveEmptyDir => format!("remove {}", action.path.display()),
+            RepairKind::Unrepairable => format!("cannot repair {}", action.path.display()),
+        };
+
+        match action.status {
+            RepairStatus::Planned => output.print_info(&format!(
+                "Would {} ({}: {})",
+                description, action.agent, action.reason
+            )),
+            RepairStatus::Done => output.print_success(&format!(
+                "✓ {} ({}: {})",
+                description, action.agent, action.reason
+            )),
+            RepairStatus::Failed => output.print_error(&format!(
+                "✗ {}: {}",
+                description,
+                action.error.as_deref().unwrap_or("unknown error")
+            )),
+            RepairStatus::Skipped => output.print_warning(&format!(
+                "⚠ {} ({}: {})",
+                description, action.agent, action.reason
+            )),
+        }
+    }
+
+    let count = |status| actions.iter().filter(|a| a.status == status).count();
+    output.print_info("");
+    if dry_run {
+        output.print_info(&format!(
+            "Dry run: {} repair(s) planned, {} skipped",
+            count(RepairStatus::Planned),
+            count(RepairStatus::Skipped)
+        ));
+    } else {
+        output.print_info(&format!(
+            "{} repaired, {} failed, {} skipped",
+            count(RepairStatus::Done),
+            count(RepairStatus::Failed),
+            count(RepairStatus::Skipped)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::os::unix::fs::symlink;
+    use tempfile::TempDir;
+
+    fn create_repo_skill(repo_dir: &Path, name: &str) -> PathBuf {
+        let skill_dir = repo_dir.join(name);
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            format!("---\nname: {}\ndescription: test\n---\n", name),
+        )
+        .unwrap();
+        skill_dir
+    }
+
+    fn agent_dirs(dir: &Path) -> Vec<(Agent, Scope, PathBuf)> {
+        vec![(Agent::ClaudeCode, Scope::Global, dir.to_path_buf())]
+    }
+
+    #[test]
+    fn test_healthy_link_needs_no_repair() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let trash = temp_dir.path().join("trash");
+        let agent = temp_dir.path().join("agent");
+        fs::create_dir_all(&agent).unwrap();
+        let skill = create_repo_skill(&repo, "good");
+        symlink(&skill, agent.join("good")).unwrap();
+
+        assert!(plan_repairs(&agent_dirs(&agent), &repo, &trash).is_empty());
+    }
+
+    #[test]
+    fn test_user_symlink_is_ignored() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let trash = temp_dir.path().join("trash");
+        let agent = temp_dir.path().join("agent");
+        let elsewhere = temp_dir.path().join("elsewhere/skill");
+        fs::create_dir_all(&agent).unwrap();
+        fs::create_dir_all(&elsewhere).unwrap();
+        symlink(&elsewhere, agent.join("skill")).unwrap();
+
+        assert!(plan_repairs(&agent_dirs(&agent), &repo, &trash).is_empty());
+    }
+
+    #[test]
+    fn test_dangling_link_with_repo_copy_is_relinked() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let trash = temp_dir.path().join("trash");
+        let agent = temp_dir.path().join("agent");
+        fs::create_dir_all(&agent).unwrap();
+        let skill = create_repo_skill(&repo, "moved");
+        symlink(
+            temp_dir.path().join("old/.sikil/repo/moved"),
+            agent.join("moved"),
+        )
+        .unwrap();
+
+        let mut actions = plan_repairs(&agent_dirs(&agent), &repo, &trash);
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].action, RepairKind::Relink);
+        assert_eq!(actions[0].new_target.as_ref(), Some(&skill));
+
+        apply_repair(&mut actions[0]);
+        assert_eq!(actions[0].status, RepairStatus::Done);
+        assert_eq!(fs::read_link(agent.join("moved")).unwrap(), skill);
+    }
+
+    #[test]
+    fn test_stale_repo_link_is_relinked() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("new/.sikil/repo");
+        let trash = temp_dir.path().join("trash");
+        let old_repo = temp_dir.path().join("old/.sikil/repo");
+        let agent = temp_dir.path().join("agent");
+        fs::create_dir_all(&agent).unwrap();
+        let skill = create_repo_skill(&repo, "stale");
+        let old_skill = create_repo_skill(&old_repo, "stale");
+        symlink(&old_skill, agent.join("stale")).unwrap();
+
+        let actions = plan_repairs(&agent_dirs(&agent), &repo, &trash);
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].action, RepairKind::Relink);
+        assert_eq!(actions[0].new_target.as_ref(), Some(&skill));
+        assert!(actions[0].reason.contains("old repository"));
+    }
+
+    #[test]
+    fn test_dangling_link_without_repo_copy_is_skipped() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let trash = temp_dir.path().join("trash");
+        let agent = temp_dir.path().join("agent");
+        fs::create_dir_all(&agent).unwrap();
+        symlink(repo.join("lost"), agent.join("lost")).unwrap();
+
+        let mut actions = plan_repairs(&agent_dirs(&agent), &repo, &trash);
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].action, RepairKind::Unrepairable);
+        assert_eq!(actions[0].status, RepairStatus::Skipped);
+
+        apply_repair(&mut actions[0]);
+        assert_eq!(actions[0].status, RepairStatus::Skipped);
+        assert!(is_symlink(&agent.join("lost")));
+    }
+
+    #[test]
+    fn test_empty_directory_is_removed() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let trash = temp_dir.path().join("trash");
+        let agent = temp_dir.path().join("agent");
+        create_repo_skill(&repo, "empty");
+        create_repo_skill(&repo, "full");
+        fs::create_dir_all(agent.join("empty")).unwrap();
+        fs::create_dir_all(agent.join("full")).unwrap();
+        fs::write(agent.join("full/SKILL.md"), "x").unwrap();
+
+        let mut actions = plan_repairs(&agent_dirs(&agent), &repo, &trash);
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].action, RepairKind::RemoveEmptyDir);
+
+        apply_repair(&mut actions[0]);
+        assert!(!agent.join("empty").exists());
+        assert!(agent.join("full").exists());
+    }
+
+    #[test]
+    fn test_unknown_empty_directory_is_kept() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let trash = temp_dir.path().join("trash");
+        let agent = temp_dir.path().join("agent");
+        fs::create_dir_all(&repo).unwrap();
+        fs::create_dir_all(agent.join("drafts")).unwrap();
+
+        assert!(plan_repairs(&agent_dirs(&agent), &repo, &trash).is_empty());
+    }
+
+    #[test]
+    fn test_empty_directory_of_trashed_skill_is_removed() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let trash = temp_dir.path().join("trash");
+        let agent = temp_dir.path().join("agent");
+        fs::create_dir_all(agent.join("binned")).unwrap();
+        TrashEntry::new(&trash, "binned", 1).save().unwrap();
+
+        let actions = plan_repairs(&agent_dirs(&agent), &repo, &trash);
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].action, RepairKind::RemoveEmptyDir);
+        assert_eq!(actions[0].path, agent.join("binned"));
+    }
+
+    #[test]
+    fn test_dry_run_changes_nothing() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let agent = temp_dir.path().join("agent");
+        create_repo_skill(&repo, "empty");
+        fs::create_dir_all(agent.join("empty")).unwrap();
+
+        let mut config = Config::new();
+        config.insert_agent(
+            "claude-code".to_string(),
+            crate::core::config::AgentConfig::new(true, agent.clone(), PathBuf::from(".skills")),
+        );
+
+        let args = RepairArgs {
+            json_mode: true,
+            dry_run: true,
+        };
+        execute_repair(args, &config, Some(repo)).unwrap();
+        assert!(agent.join("empty").exists());
+    }
+}
@@ -0,0 +1,264 @@
+//! Search command implementation
+//!
+//! This module implements `sikil search`, which ranks installed skills by how
+//! well their name, tags, description and SKILL.md body match a query, and
+//! shows the best matching line of each.
+
+use crate::cli::output::Output;
+use crate::core::config::Config;
+use crate::core::errors::SikilError;
+use crate::core::scanner::Scanner;
+use crate::core::search::{
+    search_document, Query, SearchDocument, SearchField, SearchHit, SearchIndex, SkillBody, Snippet,
+};
+use crate::core::skill::Skill;
+use anstyle::{AnsiColor, Color, Style};
+use anyhow::Result;
+use std::path::PathBuf;
+
+/// Arguments for the search command
+#[derive(Debug, Clone)]
+pub struct SearchArgs {
+    /// Whether to output in JSON format
+    pub json_mode: bool,
+    /// Whether to disable the scan cache and the search index
+    pub no_cache: bool,
+    /// Search query
+    pub query: String,
+    /// Maximum number of results
+    pub limit: usize,
+}
+
+/// A single search result
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SearchResultOutput {
+    /// Skill name
+    pub name: String,
+    /// Skill description
+    pub description: String,
+    /// Relevance score; higher is better
+    pub score: u32,
+    /// Fields that matched the query
+    pub matched: Vec<SearchField>,
+    /// SKILL.md that was searched
+    pub path: PathBuf,
+    /// Whether the skill is managed
+    pub managed: bool,
+    /// Agents the skill is installed for
+    pub agents: Vec<String>,
+    /// Best matching line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<Snippet>,
+}
+
+/// Executes the search command
+///
+/// # Errors
+///
+/// Returns an error if the query has no words or JSON output cannot be
+/// written.
+pub fn execute_search(args: SearchArgs, config: &Config) -> Result<()> {
+    let output = Output::new(args.json_mode);
+    let query = Query::parse(&args.query).ok_or_else(|| SikilError::ValidationError {
+        reason: "search query must contain at least one word".to_string(),
+    })?;
+
+    let scanner = if args.no_cache {
+        Scanner::without_cache(config.clone())
+    } else {
+        Scanner::new(config.clone())
+    };
+    let scan_result = scanner.scan_all_agents();
+
+    let mut index = (!args.no_cache).then(SearchIndex::open);
+    let mut results = Vec::new();
+    for skill in scan_result.skills.values() {
+        let Some(skill_md) = skill_md_path(skill) else {
+            continue;
+        };
+        let read_body;
+        let body = match index {
+            Some(ref mut index) => index.body(&skill_md),
+            None => {
+                read_body = SkillBody::read(&skill_md);
+                read_body.as_ref()
+            }
+        };
+        let doc = SearchDocument {
+            name: &skill.metadata.name,
+            description: &skill.metadata.description,
+            tags: &skill.metadata.tags,
+            body,
+        };
+        if let Some(hit) = search_document(&doc, &query) {
+            results.push(result_output(skill, skill_md, hit));
+        }
+    }
+
+    // The index is an optimization, so failing to write it is not an error
+    if let Some(ref mut index) = index {
+        let _ = index.save();
+    }
+
+    results.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.name.cmp(&b.name)));
+    let total = results.len();
+    results.truncate(args.limit);
+
+    if args.json_mode {
+        output.print_json(&results)?;
+    } else {
+        print_results(&output, &args.query, &results, total);
+    }
+
+    Ok(())
+}
+
+/// SKILL.md of a skill: the repository copy of a managed skill, otherwise
+/// its first installation
+fn skill_md_path(skill: &Skill) -> Option<PathBuf> {
+    skill
+        .repo_path
+        .clone()
+        .or_else(|| skill.installations.first().map(|i| i.path.clone()))
+        .map(|dir| dir.join("SKILL.md"))
+}
+
+/// Converts a search hit to its output form
+fn result_output(skill: &Skill, path: PathBuf, hit: SearchHit) -> SearchResultOutput {
+    let mut agents: Vec<String> = skill
+        .installations
+        .iter()
+        .filter(|i| Some(&i.path) != skill.repo_path.as_ref())
+        .map(|i| i.agent.to_string())
+        .collect();
+    agents.sort();
+    agents.dedup();
+
+    SearchResultOutput {
+        name: skill.metadata.name.clone(),
+        description: skill.metadata.description.clone(),
+        score: hit.score,
+        matched: hit.fields,
+        path,
+        managed: skill.is_managed,
+        agents,
+        snippet: hit.snippet,
+    }
+}
+
+/// Prints search results in human-readable form
+fn print_results(output: &Output, query: &str, results: &[SearchResultOutput], total: usize) {
+    if results.is_empty() {
+        output.print_info(&format!("No skills match '{}'.", query));
+        return;
+    }
+
+    if total > results.len() {
+        output.print_info(&format!(
+            "Found {} skills matching '{}' (showing the top {})",
+            total,
+            query,
+            results.len()
+        ));
+    } else {
+        output.print_info(&format!(
+            "Found {} skill{} matching '{}'",
+            total,
+            if total == 1 { "" } else { "s" },
+            query
+        ));
+    }
+
+    for result in results {
+        output.print_info("");
+        let agents = if result.agents.is_empty() {
+            "-".to_string()
+        } else {
+            result.agents.join(", ")
+        };
+        output.print_success(&format!("{} ({})", result.name, agents));
+        match result.snippet {
+            // Only the description matched, so highlight it there
+            Some(ref snippet @ Snippet { line: None, .. }) => {
+                output.print_info(&format!("  {}", highlight(output, snippet)));
+            }
+            Some(
+                ref snippet @ Snippet {
+                    line: Some(line), ..
+                },
+            ) => {
+                output.print_info(&format!("  {}", result.description));
+                output.print_info(&format!(
+                    "  SKILL.md:{}: {}",
+                    line,
+                    highlight(output, snippet)
+                ));
+            }
+            None => output.print_info(&format!("  {}", result.description)),
+        }
+    }
+}
+
+/// Renders a snippet with its highlights in bold yellow
+fn highlight(output: &Output, snippet: &Snippet) -> String {
+    if output.no_color {
+        return snippet.text.clone();
+    }
+
+    let style = Style::new()
+        .bold()
+        .fg_color(Some(Color::Ansi(AnsiColor::Yellow)));
+    let mut rendered = String::new();
+    let mut last = 0;
+    for range in &snippet.highlights {
+        rendered.push_str(&snippet.text[last..range.start]);
+        rendered.push_str(&format!(
+            "{}{}{}",
+            style.render(),
+            &snippet.text[range.clone()],
+            style.render_reset()
+        ));
+        last = range.end;
+    }
+    rendered.push_str(&snippet.text[last..]);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight() {
+        let snippet = Snippet {
+            line: Some(3),
+            text: "Read the PDF".to_string(),
+            highlights: vec![std::ops::Range { start: 9, end: 12 }],
+        };
+        let output = Output {
+            json_mode: false,
+            no_color: false,
+        };
+        let rendered = highlight(&output, &snippet);
+        assert!(rendered.starts_with("Read the \u{1b}["));
+        assert!(rendered.contains("PDF\u{1b}[0m"));
+
+        let output = Output {
+            json_mode: false,
+            no_color: true,
+        };
+        assert_eq!(highlight(&output, &snippet), "Read the PDF");
+    }
+
+    #[test]
+    fn test_empty_query_is_rejected() {
+        let args = SearchArgs {
+            json_mode: true,
+            no_cache: true,
+            query: " ?! ".to_string(),
+            limit: 10,
+        };
+        let err = execute_search(args, &Config::new()).unwrap_err();
+        assert!(err.to_string().contains("at least one word"));
+    }
+}
@@ -4,10 +4,14 @@
 //! about a specific Agent Skill.
 
 use crate::cli::output::Output;
+use crate::commands::validate::print_scripts;
 use crate::core::config::Config;
 use crate::core::errors::SikilError;
 use crate::core::scanner::Scanner;
+use crate::core::scripts::{inventory_scripts, ScriptInfo};
+use crate::core::tools::ToolGrant;
 use anyhow::Result;
+use std::collections::BTreeMap;
 
 /// Arguments for the show command
 #[derive(Debug, Clone)]
@@ -39,6 +43,24 @@ pub struct ShowOutput {
     /// License (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub license: Option<String>,
+    /// Homepage URL (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    /// Source repository URL (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    /// Environment requirements (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compatibility: Option<String>,
+    /// Tools the skill pre-approves (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_tools: Option<Vec<ToolGrant>>,
+    /// Tags (empty if none)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Frontmatter fields without a dedicated field
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, serde_yaml::Value>,
     /// Whether this skill is managed
     pub managed: bool,
     /// Canonical path (for managed skills)
@@ -52,6 +74,9 @@ pub struct ShowOutput {
     /// Total size in bytes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_size_bytes: Option<u64>,
+    /// Bundled scripts with their risk analysis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scripts: Option<Vec<ScriptInfo>>,
 }
 
 /// Output format for a single installation
@@ -189,12 +214,12 @@ fn build_show_output(skill: &crate::core::skill::Skill, name: &str) -> Result<Sh
         .or_else(|| skill.installations.first().map(|i| i.path.as_path()));
 
     // Build file tree information (only if we have a path to examine)
-    let (file_tree, total_size_bytes) = if let Some(bp) = base_path {
+    let (file_tree, total_size_bytes, scripts) = if let Some(bp) = base_path {
         let tree = build_file_tree(bp);
         let size = calculate_dir_size(bp).ok();
-        (Some(tree), size)
+        (Some(tree), size, Some(inventory_scripts(bp)))
     } else {
-        (None, None)
+        (None, None, None)
     };
 
     let directory_name = if skill.directory_name != name {
@@ -210,11 +235,18 @@ fn build_show_output(skill: &crate::core::skill::Skill, name: &str) -> Result<Sh
         version: skill.metadata.version.clone(),
         author: skill.metadata.author.clone(),
         license: skill.metadata.license.clone(),
+        homepage: skill.metadata.homepage.clone(),
+        repository: skill.metadata.repository.clone(),
+        compatibility: skill.metadata.compatibility.clone(),
+        allowed_tools: skill.metadata.allowed_tools.clone(),
+        tags: skill.metadata.tags.clone(),
+        extra: skill.metadata.extra.clone(),
         managed: skill.is_managed,
         canonical_path,
         installations,
         file_tree,
         total_size_bytes,
+        scripts,
     })
 }
 
@@ -342,6 +374,29 @@ fn print_human_readable(output: &Output, show_output: &ShowOutput) {
     if let Some(ref license) = show_output.license {
         output.print_info(&format!("License: {}", license));
     }
+    if let Some(ref homepage) = show_output.homepage {
+        output.print_info(&format!("Homepage: {}", homepage));
+    }
+    if let Some(ref repository) = show_output.repository {
+        output.print_info(&format!("Repository: {}", repository));
+    }
+    if let Some(ref compatibility) = show_output.compatibility {
+        output.print_info(&format!("Compatibility: {}", compatibility));
+    }
+    if !show_output.tags.is_empty() {
+        output.print_info(&format!("Tags: {}", show_output.tags.join(", ")));
+    }
+    if let Some(ref tools) = show_output.allowed_tools {
+        let tools: Vec<String> = tools.iter().map(ToString::to_string).collect();
+        output.print_info(&format!("Allowed tools: {}", tools.join(", ")));
+    }
+    if !show_output.extra.is_empty() {
+        output.print_info("Other fields:");
+        for (key, value) in &show_output.extra {
+            let rendered = serde_json::to_string(value).unwrap_or_default();
+            output.print_info(&format!("  {}: {}", key, rendered));
+        }
+    }
 
     // Print file tree info
     if let Some(ref tree) = show_output.file_tree {
@@ -371,6 +426,14 @@ fn print_human_readable(output: &Output, show_output: &ShowOutput) {
         output.print_info(&format!("Total size: {}", format_bytes(size)));
     }
 
+    // Print scripts so reviewers can triage them before installing
+    if let Some(ref scripts) = show_output.scripts {
+        if !scripts.is_empty() {
+            output.print_info("");
+            print_scripts(output, scripts);
+        }
+    }
+
     // Print installations
     output.print_info("");
     output.print_info(&format!(
@@ -480,6 +543,12 @@ mod tests {
             version: Some("1.0.0".to_string()),
             author: Some("Test Author".to_string()),
             license: Some("MIT".to_string()),
+            homepage: None,
+            repository: None,
+            compatibility: None,
+            allowed_tools: None,
+            tags: vec![],
+            extra: BTreeMap::new(),
             managed: true,
             canonical_path: Some("/home/user/.sikil/repo/my-skill".to_string()),
             installations: vec![ShowInstallationOutput {
@@ -496,6 +565,7 @@ mod tests {
                 file_count: 5,
             }),
             total_size_bytes: Some(1024),
+            scripts: Some(vec![]),
         };
 
         let json = serde_json::to_string(&output).unwrap();
@@ -513,11 +583,18 @@ mod tests {
             version: None,
             author: None,
             license: None,
+            homepage: None,
+            repository: None,
+            compatibility: None,
+            allowed_tools: None,
+            tags: vec![],
+            extra: BTreeMap::new(),
             managed: false,
             canonical_path: None,
             installations: vec![],
             file_tree: None,
             total_size_bytes: None,
+            scripts: None,
         };
 
         let json = serde_json::to_string(&output).unwrap();
@@ -533,11 +610,18 @@ mod tests {
             version: None,
             author: None,
             license: None,
+            homepage: None,
+            repository: None,
+            compatibility: None,
+            allowed_tools: None,
+            tags: vec![],
+            extra: BTreeMap::new(),
             managed: false,
             canonical_path: None,
             installations: vec![],
             file_tree: None,
             total_size_bytes: None,
+            scripts: None,
         };
 
         let json = serde_json::to_string(&output).unwrap();
@@ -548,6 +632,9 @@ mod tests {
         assert!(!json.contains("canonical_path"));
         assert!(!json.contains("file_tree"));
         assert!(!json.contains("total_size_bytes"));
+        assert!(!json.contains("homepage"));
+        assert!(!json.contains("extra"));
+        assert!(!json.contains("scripts"));
     }
 
     #[test]
@@ -674,6 +761,38 @@ mod tests {
         assert_eq!(tree.file_count, 3); // SKILL.md, run.sh, doc.md
     }
 
+    #[test]
+    fn test_build_show_output_includes_scripts() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let skill_path = temp_dir.path().join("risky");
+        std::fs::create_dir_all(skill_path.join("scripts")).unwrap();
+        std::fs::write(skill_path.join("SKILL.md"), "# Test").unwrap();
+        std::fs::write(
+            skill_path.join("scripts/setup.sh"),
+            "#!/bin/bash\nsudo cp tool /usr/local/bin\n",
+        )
+        .unwrap();
+
+        let skill = Skill::new(
+            SkillMetadata::new("risky".to_string(), "A risky skill".to_string()),
+            "risky".to_string(),
+        )
+        .with_installation(Installation::new(
+            Agent::ClaudeCode,
+            skill_path,
+            Scope::Global,
+        ));
+
+        let output = build_show_output(&skill, "risky").unwrap();
+        let scripts = output.scripts.unwrap();
+        assert_eq!(scripts.len(), 1);
+        assert_eq!(scripts[0].path, "scripts/setup.sh");
+        assert_eq!(scripts[0].shebang.as_deref(), Some("/bin/bash"));
+        assert_eq!(scripts[0].risks[0].id, "privilege-escalation");
+    }
+
     #[test]
     fn test_count_files_excludes_git() {
         use tempfile::TempDir;
@@ -7,9 +7,10 @@ use crate::cli::output::Output;
 use crate::commands::parse_agent_selection;
 use crate::core::config::Config;
 use crate::core::errors::SikilError;
+use crate::core::plan::{Plan, Step};
 use crate::core::skill::Agent;
 use crate::utils::paths::{ensure_dir_exists, get_repo_path};
-use crate::utils::symlink::{create_symlink, is_symlink};
+use crate::utils::symlink::is_symlink;
 use anyhow::Result;
 use fs_err as fs;
 use std::path::{Path, PathBuf};
@@ -19,6 +20,8 @@ use std::path::{Path, PathBuf};
 pub struct SyncArgs {
     /// Whether to output in JSON format
     pub json_mode: bool,
+    /// Print the plan instead of syncing
+    pub dry_run: bool,
     /// Name of the skill to sync (optional, --all syncs all)
     pub name: Option<String>,
     /// Whether to sync all managed skills
@@ -58,6 +61,7 @@ pub struct SyncArgs {
 /// let config = Config::default();
 /// let args = SyncArgs {
 ///     json_mode: false,
+///     dry_run: false,
 ///     name: Some("my-skill".to_string()),
 ///     all: false,
 ///     to: Some("claude-code".to_string()),
@@ -195,13 +199,16 @@ fn sync_single_skill(
     }
 
     // M4-E01-T01-S05: Create symlinks to missing agents
-    let mut synced_count = 0;
+    let mut plan = Plan::new("sync", skill_name);
+    let mut links = Vec::new();
     for agent in &missing_agents {
         if let Some(agent_config) = config.get_agent(&agent.to_string()) {
             let agent_skill_dir = &agent_config.global_path;
 
             // Ensure agent directory exists
-            if let Err(e) = ensure_dir_exists(agent_skill_dir) {
+            if args.dry_run {
+                // Leave the filesystem untouched
+            } else if let Err(e) = ensure_dir_exists(agent_skill_dir) {
                 output.print_warning(&format!(
                     "Failed to create agent directory for {}: {}",
                     agent, e
@@ -210,24 +217,29 @@ fn sync_single_skill(
             }
 
             let symlink_path = agent_skill_dir.join(skill_name);
+            plan.push(Step::CreateSymlink {
+                target: skill_repo_path.clone(),
+                link: symlink_path.clone(),
+            });
+            links.push((*agent, symlink_path));
+        }
+    }
+    if args.dry_run {
+        output.print_plan(&plan)?;
+        return Ok(());
+    }
 
-            match create_symlink(&skill_repo_path, &symlink_path) {
-                Ok(()) => {
-                    synced_count += 1;
-                    if !args.json_mode {
-                        output.print_success(&format!(
-                            "Linked to {} at {}",
-                            agent,
-                            symlink_path.display()
-                        ));
-                    }
-                }
-                Err(e) => {
-                    output.print_warning(&format!("Failed to create symlink for {}: {}", agent, e));
+    // Symlinks created so far are removed if one fails
+    plan.execute(|step| {
+        if let Step::CreateSymlink { link, .. } = step {
+            if let Some((agent, _)) = links.iter().find(|(_, path)| path == link) {
+                if !args.json_mode {
+                    output.print_success(&format!("Linked to {} at {}", agent, link.display()));
                 }
             }
         }
-    }
+    })?;
+    let synced_count = links.len();
 
     if !args.json_mode {
         output.print_info("");
@@ -301,6 +313,7 @@ fn sync_all_skills(
         // Create sync args for this skill (without --all)
         let skill_args = SyncArgs {
             json_mode: args.json_mode,
+            dry_run: args.dry_run,
             name: Some(skill_name.clone()),
             all: false,
             to: args.to.clone(),
@@ -336,7 +349,7 @@ mod tests {
     use tempfile::TempDir;
 
     /// Helper to create a test skill with SKILL.md
-    fn create_test_skill(dir: &PathBuf, name: &str) {
+    fn create_test_skill(dir: &Path, name: &str) {
         let content = format!(
             r#"---
 name: {}
@@ -354,7 +367,7 @@ This is a test skill."#,
     }
 
     /// Helper to create a test config with custom paths
-    fn create_test_config_with_paths(agent_path: &PathBuf) -> Config {
+    fn create_test_config_with_paths(agent_path: &Path) -> Config {
         let mut config = Config::new();
         config.insert_agent(
             "claude-code".to_string(),
@@ -371,6 +384,7 @@ This is a test skill."#,
     fn test_sync_args() {
         let args = SyncArgs {
             json_mode: true,
+            dry_run: false,
             name: Some("my-skill".to_string()),
             all: false,
             to: Some("claude-code".to_string()),
@@ -386,6 +400,7 @@ This is a test skill."#,
     fn test_sync_args_all() {
         let args = SyncArgs {
             json_mode: false,
+            dry_run: false,
             name: None,
             all: true,
             to: None,
@@ -410,6 +425,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = SyncArgs {
             json_mode: false,
+            dry_run: false,
             name: Some("non-existent-skill".to_string()),
             all: false,
             to: Some("claude-code".to_string()),
@@ -445,6 +461,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = SyncArgs {
             json_mode: false,
+            dry_run: false,
             name: Some("test-sync-skill".to_string()),
             all: false,
             to: Some("claude-code".to_string()),
@@ -491,6 +508,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = SyncArgs {
             json_mode: false,
+            dry_run: false,
             name: Some("symlink-test-skill".to_string()),
             all: false,
             to: Some("claude-code".to_string()),
@@ -545,6 +563,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = SyncArgs {
             json_mode: false,
+            dry_run: false,
             name: Some("skip-test-skill".to_string()),
             all: false,
             to: Some("claude-code".to_string()),
@@ -582,6 +601,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = SyncArgs {
             json_mode: false,
+            dry_run: false,
             name: None,
             all: false,
             to: None,
@@ -620,6 +640,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = SyncArgs {
             json_mode: false,
+            dry_run: false,
             name: Some("unmanaged-test-skill".to_string()),
             all: false,
             to: Some("claude-code".to_string()),
@@ -660,6 +681,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = SyncArgs {
             json_mode: false,
+            dry_run: false,
             name: Some("specific-agents-skill".to_string()),
             all: false,
             to: Some("claude-code".to_string()), // Only to claude-code
@@ -712,6 +734,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = SyncArgs {
             json_mode: false,
+            dry_run: false,
             name: Some("already-synced-skill".to_string()),
             all: false,
             to: Some("claude-code".to_string()),
@@ -752,6 +775,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = SyncArgs {
             json_mode: false,
+            dry_run: false,
             name: None,
             all: true,
             to: None,
@@ -780,6 +804,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = SyncArgs {
             json_mode: false,
+            dry_run: false,
             name: None,
             all: true,
             to: Some("claude-code".to_string()),
@@ -0,0 +1,207 @@
+//! Trash command implementation
+//!
+//! This module implements `sikil trash list|restore|empty` for skills that
+//! `sikil remove` moved to `~/.sikil/trash/`. Restoring puts the repository
+//! copy, unmanaged directories and symlinks back where they were; it is not
+//! recorded for `sikil undo`, so remove the skill again to reverse it.
+
+use crate::cli::output::Output;
+use crate::commands::gc::format_bytes;
+use crate::commands::prompt_confirmation;
+use crate::core::config::Config;
+use crate::core::errors::SikilError;
+use crate::core::plan::{Plan, Step};
+use crate::core::trash::{TrashEntry, TrashedInstallation};
+use crate::utils::paths::get_trash_dir;
+use crate::utils::time::format_timestamp;
+use anyhow::Result;
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Arguments for the trash command
+#[derive(Debug, Clone)]
+pub struct TrashArgs {
+    /// Whether to output in JSON format
+    pub json_mode: bool,
+    /// Print the restore or empty plan instead of running it
+    pub dry_run: bool,
+    /// What to do with the trash
+    pub operation: TrashOperation,
+}
+
+/// A trash operation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrashOperation {
+    /// List removed skills
+    List,
+    /// Put a removed skill back, by entry id or skill name
+    Restore { name: String },
+    /// Permanently delete everything in the trash
+    Empty { yes: bool },
+}
+
+/// A removed skill as shown by `sikil trash list`
+#[derive(Debug, Clone, Serialize)]
+pub struct TrashListing {
+    /// Entry id, accepted by `sikil trash restore`
+    pub id: String,
+    /// Name of the removed skill
+    pub name: String,
+    /// When the skill was removed (RFC 3339, UTC)
+    pub removed_at: String,
+    /// When the entry will be deleted, if it expires
+    pub expires_at: Option<String>,
+    /// Where the repository copy lived, if it was removed
+    pub repo_path: Option<PathBuf>,
+    /// Where each installation lived
+    pub installations: Vec<TrashedInstallation>,
+    /// Disk space used, in bytes
+    pub size_bytes: u64,
+}
+
+/// Executes the trash command
+///
+/// # Errors
+///
+/// Returns `SikilError::SkillNotFound` if the skill to restore is not in the
+/// trash, and `SikilError::AlreadyExists` if something now occupies a path
+/// it would be restored to.
+pub fn execute_trash(args: TrashArgs, config: &Config) -> Result<()> {
+    let output = Output::new(args.json_mode);
+    let trash_dir = get_trash_dir();
+
+    match args.operation {
+        TrashOperation::List => {
+            let retention_days = config.trash.retention_days;
+            let listings: Vec<TrashListing> = TrashEntry::load_all(&trash_dir)
+                .into_iter()
+                .filter(TrashEntry::is_intact)
+                .map(|entry| {
+                    let record = entry.record();
+                    TrashListing {
+                        id: entry.id(),
+                        name: record.name.clone(),
+                        removed_at: format_timestamp(record.removed_at),
+                        expires_at: (retention_days > 0)
+                            .then(|| format_timestamp(record.removed_at + retention_days * 86_400)),
+                        repo_path: record.repo_path.clone(),
+                        installations: record.installations.clone(),
+                        size_bytes: entry.size(),
+                    }
+                })
+                .collect();
+
+            if args.json_mode {
+                output.print_json(&listings)?;
+            } else if listings.is_empty() {
+                output.print_info("Trash is empty");
+            } else {
+                for listing in &listings {
+                    print_listing(&output, listing);
+                }
+            }
+        }
+        TrashOperation::Restore { name } => {
+            let entry = TrashEntry::find(&trash_dir, &name)?;
+            let plan = entry.restore_plan()?;
+            if args.dry_run {
+                output.print_plan(&plan)?;
+                return Ok(());
+            }
+
+            // Not recorded for `sikil undo`: moving the data back into the
+            // entry after it is deleted would leave it out of the trash
+            let skill = plan.skill.clone();
+            plan.execute_untracked(|step| {
+                if !args.json_mode {
+                    output.print_info(&format!("  {}", step));
+                }
+            })?;
+            let id = entry.id();
+            entry.delete()?;
+
+            if args.json_mode {
+                output.print_json(&serde_json::json!({ "id": id, "name": skill }))?;
+            } else {
+                output.print_success(&format!("Restored '{}' from the trash", skill));
+            }
+        }
+        TrashOperation::Empty { yes } => {
+            // Entries `sikil undo` emptied are not listed; delete them too
+            // without counting them as skills
+            let (entries, leftovers): (Vec<_>, Vec<_>) = TrashEntry::load_all(&trash_dir)
+                .into_iter()
+                .partition(TrashEntry::is_intact);
+            let mut plan = Plan::new("trash empty", "");
+            for entry in entries.iter().chain(&leftovers) {
+                plan.push(Step::RemoveDir {
+                    path: entry.dir().to_path_buf(),
+                });
+            }
+            if args.dry_run {
+                output.print_plan(&plan)?;
+                return Ok(());
+            }
+
+            if !entries.is_empty() && !yes && !args.json_mode {
+                let bytes: u64 = entries.iter().map(TrashEntry::size).sum();
+                let confirmed = prompt_confirmation(&format!(
+                    "Permanently delete {} skill(s) ({}) from the trash?",
+                    entries.len(),
+                    format_bytes(bytes)
+                ))?;
+                if !confirmed {
+                    output.print_warning("Operation cancelled by user.");
+                    return Err(SikilError::PermissionDenied {
+                        operation: "empty trash".to_string(),
+                        path: trash_dir,
+                    }
+                    .into());
+                }
+            }
+
+            if !plan.is_empty() {
+                plan.execute_untracked(|_| {})?;
+            }
+            let deleted = entries.len();
+            if args.json_mode {
+                output.print_json(&serde_json::json!({ "deleted": deleted }))?;
+            } else if deleted == 0 {
+                output.print_info("Trash is empty");
+            } else {
+                output.print_success(&format!("Deleted {} skill(s) from the trash", deleted));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_listing(output: &Output, listing: &TrashListing) {
+    let expiry = match &listing.expires_at {
+        Some(expires_at) => format!(", expires {}", expires_at),
+        None => String::new(),
+    };
+    output.print_info(&format!(
+        "{} ({}, removed {}{})",
+        listing.id,
+        format_bytes(listing.size_bytes),
+        listing.removed_at,
+        expiry
+    ));
+    if let Some(repo) = &listing.repo_path {
+        output.print_info(&format!("  - repository ({})", repo.display()));
+    }
+    for installation in &listing.installations {
+        let kind = if installation.symlink_target.is_some() {
+            "symlink"
+        } else {
+            "directory"
+        };
+        output.print_info(&format!(
+            "  - {} ({}) [{}]",
+            installation.agent,
+            installation.path.display(),
+            kind
+        ));
+    }
+}
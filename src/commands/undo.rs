@@ -0,0 +1,94 @@
+//! Undo command implementation
+//!
+//! This module reverses the most recent operation recorded in
+//! `~/.sikil/history/`: installed copies are deleted, removed symlinks are
+//! recreated, deleted directories are restored from the copy kept in the
+//! history, and adopted skills are moved back. Running it again undoes the
+//! operation before that.
+
+use crate::cli::output::Output;
+use crate::core::errors::SikilError;
+use crate::core::history::HistoryEntry;
+use crate::utils::paths::get_history_dir;
+use anyhow::Result;
+use serde::Serialize;
+
+/// Arguments for the undo command
+#[derive(Debug, Clone)]
+pub struct UndoArgs {
+    /// Whether to output in JSON format
+    pub json_mode: bool,
+    /// Whether to print the plan instead of running it
+    pub dry_run: bool,
+}
+
+/// The operation that was undone
+#[derive(Debug, Clone, Serialize)]
+pub struct UndoResult {
+    /// Command that ran the operation
+    pub command: String,
+    /// Skill the operation acted on
+    pub skill: String,
+    /// Steps performed to reverse it, in order
+    pub steps: Vec<String>,
+}
+
+/// Executes the undo command
+///
+/// # Errors
+///
+/// Returns `SikilError::ValidationError` if there is nothing to undo, or if
+/// a path the operation touched has changed since, in which case nothing is
+/// modified.
+pub fn execute_undo(args: UndoArgs) -> Result<()> {
+    let output = Output::new(args.json_mode);
+
+    let Some(entry) = HistoryEntry::latest(&get_history_dir()) else {
+        return Err(SikilError::ValidationError {
+            reason: "nothing to undo".to_string(),
+        }
+        .into());
+    };
+
+    let changed = entry.changed_paths();
+    if !changed.is_empty() {
+        let paths: Vec<String> = changed
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        return Err(SikilError::ValidationError {
+            reason: format!(
+                "cannot undo `{} {}`: changed since: {}",
+                entry.command,
+                entry.skill,
+                paths.join(", ")
+            ),
+        }
+        .into());
+    }
+
+    let plan = entry.inverse()?;
+    if args.dry_run {
+        output.print_plan(&plan)?;
+        return Ok(());
+    }
+
+    let result = UndoResult {
+        command: entry.command.clone(),
+        skill: entry.skill.clone(),
+        steps: plan.steps.iter().map(|step| step.to_string()).collect(),
+    };
+    plan.execute_untracked(|step| {
+        if !args.json_mode {
+            output.print_info(&format!("  {}", step));
+        }
+    })?;
+    entry.discard()?;
+
+    if args.json_mode {
+        output.print_json(&result)?;
+    } else {
+        output.print_success(&format!("Undid `{} {}`", result.command, result.skill));
+    }
+    Ok(())
+}
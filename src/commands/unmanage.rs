@@ -7,12 +7,11 @@
 use crate::cli::output::Output;
 use crate::core::config::Config;
 use crate::core::errors::SikilError;
+use crate::core::plan::{Plan, Step};
 use crate::core::scanner::Scanner;
 use crate::core::skill::Agent;
-use crate::utils::atomic::copy_skill_dir;
 use crate::utils::paths::get_repo_path;
 use anyhow::Result;
-use fs_err as fs;
 use std::io::{self, Write};
 
 /// Arguments for the unmanage command
@@ -20,6 +19,8 @@ use std::io::{self, Write};
 pub struct UnmanageArgs {
     /// Whether to output in JSON format
     pub json_mode: bool,
+    /// Print the plan instead of unmanaging
+    pub dry_run: bool,
     /// Name of the skill to unmanage
     pub name: String,
     /// Optional agent to unmanage from (for partial unmanage)
@@ -115,6 +116,7 @@ fn prompt_confirmation(prompt: &str) -> Result<bool> {
 /// let config = Config::default();
 /// let args = UnmanageArgs {
 ///     json_mode: false,
+///     dry_run: false,
 ///     name: "my-skill".to_string(),
 ///     agent: None,
 ///     yes: false,
@@ -233,6 +235,31 @@ pub fn execute_unmanage(args: UnmanageArgs, config: &Config) -> Result<()> {
         output.print_info("");
     }
 
+    // M3-E04-T01-S06: If all symlinks are removed, the skill leaves the repo
+    let removes_repo = installations_to_unmanage.len() == managed_installations.len();
+
+    // M3-E04-T01-S05: Remove symlink and copy content from repo
+    let mut plan = Plan::new("unmanage", &args.name);
+    for installation in &installations_to_unmanage {
+        plan.push(Step::RemoveSymlink {
+            link: installation.path.clone(),
+            target: installation.symlink_target.clone().unwrap_or_default(),
+        });
+        plan.push(Step::CopyDir {
+            from: skill_repo_path.clone(),
+            to: installation.path.clone(),
+        });
+    }
+    if removes_repo {
+        plan.push(Step::RemoveDir {
+            path: skill_repo_path.clone(),
+        });
+    }
+    if args.dry_run {
+        output.print_plan(&plan)?;
+        return Ok(());
+    }
+
     // M3-E04-T02-S01, M3-E04-T02-S02: Prompt for confirmation (unless --yes or json mode)
     if !args.yes && !args.json_mode {
         let confirmed = prompt_confirmation("Continue?")?;
@@ -247,78 +274,41 @@ pub fn execute_unmanage(args: UnmanageArgs, config: &Config) -> Result<()> {
         }
     }
 
-    let mut unmanaged_count = 0;
-    let mut failed_installations = Vec::new();
-
-    // M3-E04-T01-S05: Remove symlink and copy content from repo
-    for installation in &installations_to_unmanage {
-        let symlink_path = &installation.path;
-
-        // Remove the symlink
-        if let Err(e) = fs::remove_file(symlink_path) {
-            let err_msg = format!(
-                "failed to remove symlink at {}: {}",
-                symlink_path.display(),
-                e
-            );
-            output.print_error(&err_msg);
-            failed_installations.push(symlink_path.clone());
-            continue;
+    // Every symlink is restored if any installation fails
+    let executed = plan.execute(|step| {
+        if args.json_mode {
+            return;
         }
-
-        // Copy content from repo to the original location
-        if let Err(e) = copy_skill_dir(&skill_repo_path, symlink_path) {
-            let err_msg = format!("failed to copy skill to {}: {}", symlink_path.display(), e);
-            output.print_error(&err_msg);
-            failed_installations.push(symlink_path.clone());
-            // Try to restore the symlink
-            let _ = fs::remove_dir_all(symlink_path);
-            if let Some(target) = &installation.symlink_target {
-                let _ = fs_err::os::unix::fs::symlink(target, symlink_path);
+        match step {
+            Step::CopyDir { to, .. } => {
+                if let Some(installation) = installations_to_unmanage
+                    .iter()
+                    .find(|installation| &installation.path == to)
+                {
+                    output.print_success(&format!(
+                        "Unmanaged from {} ({})",
+                        installation.agent,
+                        to.display()
+                    ));
+                }
             }
-            continue;
-        }
-
-        unmanaged_count += 1;
-
-        if !args.json_mode {
-            output.print_success(&format!(
-                "Unmanaged from {} ({})",
-                installation.agent,
-                symlink_path.display()
-            ));
+            Step::RemoveDir { .. } => {
+                output.print_success(&format!("Removed '{}' from repository", args.name));
+            }
+            _ => {}
         }
-    }
-
-    if !failed_installations.is_empty() {
+    });
+    if let Err(e) = executed {
+        output.print_error(&format!("failed to unmanage '{}': {}", args.name, e));
         return Err(SikilError::SymlinkError {
-            reason: format!(
-                "failed to unmanage {} installation(s)",
-                failed_installations.len()
-            ),
+            reason: format!("failed to unmanage '{}'", args.name),
             source: None,
         }
         .into());
     }
 
-    // M3-E04-T01-S06: If all symlinks removed, delete from repo
-    if unmanaged_count == managed_installations.len() {
-        if !args.json_mode {
-            output.print_info("All installations unmanaged, removing from repository...");
-        }
-
-        if let Err(_e) = fs::remove_dir_all(&skill_repo_path) {
-            return Err(SikilError::PermissionDenied {
-                operation: "remove skill from repository".to_string(),
-                path: skill_repo_path,
-            }
-            .into());
-        }
-
-        if !args.json_mode {
-            output.print_success(&format!("Removed '{}' from repository", args.name));
-        }
-    } else if !args.json_mode {
+    let unmanaged_count = installations_to_unmanage.len();
+    if !removes_repo && !args.json_mode {
         output.print_info(&format!(
             "Still managed by {} other agent(s)",
             managed_installations.len() - unmanaged_count
@@ -394,6 +384,7 @@ This is a test skill."#,
     fn test_unmanage_args() {
         let args = UnmanageArgs {
             json_mode: true,
+            dry_run: false,
             name: "my-skill".to_string(),
             agent: Some("claude-code".to_string()),
             yes: false,
@@ -409,6 +400,7 @@ This is a test skill."#,
     fn test_unmanage_args_no_agent() {
         let args = UnmanageArgs {
             json_mode: false,
+            dry_run: false,
             name: "my-skill".to_string(),
             agent: None,
             yes: true,
@@ -437,6 +429,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = UnmanageArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: None,
             yes: true,
@@ -508,6 +501,7 @@ This is a test skill."#,
         // Unmanage from only windsurf
         let args = UnmanageArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: Some("windsurf".to_string()),
             yes: true,
@@ -554,6 +548,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = UnmanageArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: None,
             yes: true, // Skip confirmation
@@ -587,6 +582,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = UnmanageArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: None,
             yes: true,
@@ -611,6 +607,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = UnmanageArgs {
             json_mode: false,
+            dry_run: false,
             name: "non-existent".to_string(),
             agent: None,
             yes: true,
@@ -638,6 +635,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = UnmanageArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: Some("invalid-agent".to_string()),
             yes: true,
@@ -703,6 +701,7 @@ This is a test skill."#,
         // Try to unmanage from claude-code (which has unmanaged installation)
         let args = UnmanageArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.to_string(),
             agent: Some("claude-code".to_string()),
             yes: true,
@@ -753,6 +752,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = UnmanageArgs {
             json_mode: false,
+            dry_run: false,
             name: skill_name.clone(),
             agent: None,
             yes: true, // Skip confirmation
@@ -794,6 +794,7 @@ This is a test skill."#,
         let config = create_test_config_with_paths(&agent_dir);
         let args = UnmanageArgs {
             json_mode: true, // JSON mode should skip confirmation
+            dry_run: false,
             name: skill_name.to_string(),
             agent: None,
             yes: false, // Even without --yes, JSON mode skips confirmation
@@ -4,21 +4,94 @@
 //! by checking their SKILL.md files and directory structure.
 
 use crate::cli::output::Output;
+use crate::cli::report::{self, ReportEntry};
+use crate::commands::prompt_confirmation;
 use crate::core::config::Config;
 use crate::core::errors::SikilError;
-use crate::core::parser::{extract_frontmatter, parse_skill_md, validate_skill_name};
+use crate::core::fixer::{fix_skill_md, FixOptions};
+use crate::core::parser::{extract_body, parse_frontmatter, parse_skill_md, validate_skill_name};
+use crate::core::references::{
+    extract_references, find_broken_references, find_unreferenced_files,
+};
+use crate::core::rules::{
+    find_rule, frontmatter_key_line, Finding, LintConfig, RuleSet, Severity, Suppressions,
+    SKILL_LINT_FILE,
+};
 use crate::core::scanner::Scanner;
+use crate::core::scripts::{inventory_scripts, RiskLevel, ScriptInfo};
+use crate::core::secrets::scan_skill_dir;
+use crate::core::tools::parse_allowed_tools;
+use crate::utils::atomic::atomic_write;
+use crate::utils::diff::unified_diff;
 use anyhow::Result;
 use fs_err as fs;
 use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
 
 /// Arguments for the validate command
 #[derive(Debug, Clone)]
 pub struct ValidateArgs {
     /// Whether to output in JSON format
     pub json_mode: bool,
-    /// Path to the skill directory, SKILL.md file, or name of an installed skill
-    pub path_or_name: String,
+    /// Path to a skill directory, SKILL.md file, directory of skills, or name
+    /// of an installed skill (ignored when `all` is set)
+    pub path_or_name: Option<String>,
+    /// Validate every installed skill
+    pub all: bool,
+    /// Report format
+    pub format: ValidateFormat,
+    /// Inventory bundled scripts and flag risky constructs (SK013-SK015)
+    pub scripts: bool,
+    /// Rewrite SKILL.md to fix mechanical problems before validating
+    pub fix: bool,
+    /// Apply fixes without asking for confirmation
+    pub yes: bool,
+}
+
+/// Output format for validation results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidateFormat {
+    /// Human-readable text (or JSON with the global `--json` flag)
+    #[default]
+    Text,
+    /// JSON
+    Json,
+    /// SARIF 2.1.0 for code-scanning annotations
+    Sarif,
+    /// JUnit XML
+    Junit,
+}
+
+impl std::str::FromStr for ValidateFormat {
+    type Err = SikilError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            "junit" => Ok(Self::Junit),
+            _ => Err(SikilError::ValidationError {
+                reason: format!(
+                    "unknown format '{}' (expected text, json, sarif or junit)",
+                    s
+                ),
+            }),
+        }
+    }
+}
+
+/// Aggregated results of validating several skills
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BatchValidationResult {
+    /// Whether every skill passed
+    pub passed: bool,
+    /// Number of skills validated
+    pub total: usize,
+    /// Number of skills with error-level findings
+    pub failed: usize,
+    /// Per-skill results, sorted by path
+    pub results: Vec<ValidationResult>,
 }
 
 /// Result of validating a skill
@@ -30,15 +103,29 @@ pub struct ValidationResult {
     pub skill_path: String,
     /// Individual validation checks
     pub checks: Vec<ValidationCheck>,
+    /// Every rule violation, including failed checks
+    pub findings: Vec<Finding>,
+    /// Error-level findings that are not shown as checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<String>>,
     /// Warnings (non-fatal issues)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub warnings: Option<Vec<String>>,
+    /// Number of findings suppressed by inline comments
+    #[serde(skip_serializing_if = "is_zero")]
+    pub suppressed: usize,
+    /// Fixes applied to SKILL.md with `--fix`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixes: Option<Vec<String>>,
     /// Detected metadata from the skill
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<DetectedMetadata>,
     /// Detected directories in the skill
     #[serde(skip_serializing_if = "Option::is_none")]
     pub detected_directories: Option<DetectedDirectories>,
+    /// Script inventory (only with `--scripts`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scripts: Option<Vec<ScriptInfo>>,
 }
 
 /// Detected metadata from a skill's SKILL.md
@@ -62,6 +149,10 @@ pub struct DetectedMetadata {
 /// A single validation check
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct ValidationCheck {
+    /// ID of the rule backing this check (e.g. "SK004")
+    pub rule: String,
+    /// Effective severity of the rule
+    pub severity: Severity,
     /// Name of the check
     pub name: String,
     /// Whether the check passed
@@ -71,6 +162,23 @@ pub struct ValidationCheck {
     pub error: Option<String>,
 }
 
+impl ValidationCheck {
+    /// Creates a check for `rule` with the rule's default severity
+    fn new(rule: &str, name: &str, error: Option<String>) -> Self {
+        Self {
+            rule: rule.to_string(),
+            severity: find_rule(rule).map_or(Severity::Error, |r| r.default_severity),
+            name: name.to_string(),
+            passed: error.is_none(),
+            error,
+        }
+    }
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
 /// Detected directories in a skill
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct DetectedDirectories {
@@ -83,17 +191,24 @@ pub struct DetectedDirectories {
 /// Executes the validate command
 ///
 /// This function:
-/// 1. Resolves the input (path or installed skill name)
-/// 2. Checks if SKILL.md exists
-/// 3. Checks if YAML frontmatter is valid
-/// 4. Checks if required fields are present
-/// 5. Checks name format constraints
-/// 6. Checks description length (1-1024)
+/// 1. Resolves the input: a single skill (path or installed name), every
+///    skill below a directory, or every installed skill with `--all`
+/// 2. Builds the effective rule set from config `[lint.rules]` and each
+///    skill's `.sikil-lint.toml`
+/// 3. Runs every enabled rule (see [`crate::core::rules::RULES`]); the script
+///    rules SK013-SK015 only run with `--scripts`
+/// 4. Prints the results and fails with `SikilError::ValidationError` (exit
+///    code 2) if any error-level finding remains
+///
+/// With `--fix`, mechanical problems in each SKILL.md are fixed first (see
+/// [`crate::core::fixer::fix_skill_md`]): the diff is shown, confirmation is
+/// requested unless `--yes` or a machine-readable format is used, and the
+/// file is replaced atomically.
 ///
 /// # Arguments
 ///
 /// * `args` - Validate arguments including path_or_name and json_mode
-/// * `config` - Configuration for resolving installed skills
+/// * `config` - Configuration for resolving installed skills and lint overrides
 ///
 /// # Errors
 ///
@@ -101,6 +216,8 @@ pub struct DetectedDirectories {
 /// - The path does not exist
 /// - The path is not a directory or SKILL.md file
 /// - The skill name is not found among installed skills
+/// - A lint override names an unknown rule or reconfigures a fatal rule
+/// - Any validated skill has an error-level finding
 ///
 /// # Examples
 ///
@@ -111,126 +228,464 @@ pub struct DetectedDirectories {
 /// let config = Config::default();
 /// let args = ValidateArgs {
 ///     json_mode: false,
-///     path_or_name: "/path/to/skill".to_string(),
+///     path_or_name: Some("/path/to/skill".to_string()),
+///     all: false,
+///     format: Default::default(),
+///     scripts: false,
+///     fix: false,
+///     yes: false,
 /// };
 /// execute_validate(args, &config).unwrap();
 /// ```
 pub fn execute_validate(args: ValidateArgs, config: &Config) -> Result<()> {
-    let output = Output::new(args.json_mode);
+    let format = match args.format {
+        ValidateFormat::Text if args.json_mode => ValidateFormat::Json,
+        format => format,
+    };
+    let output = Output::new(format != ValidateFormat::Text);
 
-    // Determine the skill directory and SKILL.md path
-    let (skill_dir, skill_md_path) = resolve_paths(&args.path_or_name, config)?;
+    let targets = if args.all {
+        installed_skill_dirs(config)
+    } else {
+        let path_or_name =
+            args.path_or_name
+                .as_deref()
+                .ok_or_else(|| SikilError::ValidationError {
+                    reason: "a path or skill name is required unless --all is given".to_string(),
+                })?;
+        match discover_skill_dirs(Path::new(path_or_name)) {
+            Some(dirs) => dirs,
+            None => {
+                // Single skill: keep the detailed output
+                let (skill_dir, skill_md_path) = resolve_paths(path_or_name, config)?;
+                let rules = load_rule_set(config, &skill_dir)?;
+                let fixes = if args.fix {
+                    apply_fixes(&output, &skill_dir, &skill_md_path, &rules, args.yes)?
+                } else {
+                    None
+                };
+                let mut result = validate_skill(&skill_dir, &skill_md_path, &rules, args.scripts)?;
+                result.fixes = fixes;
+
+                match format {
+                    ValidateFormat::Text => print_human_readable(&output, &result),
+                    ValidateFormat::Json => output.print_json(&result)?,
+                    _ => print_report(&output, format, std::slice::from_ref(&result))?,
+                }
+
+                return if result.passed {
+                    Ok(())
+                } else {
+                    Err(SikilError::ValidationError {
+                        reason: format!("{} has error-level findings", result.skill_path),
+                    }
+                    .into())
+                };
+            }
+        }
+    };
 
-    // Run all validations
-    let mut checks: Vec<ValidationCheck> = Vec::new();
-    let mut warnings: Vec<String> = Vec::new();
+    // A bad global override affects every skill, so it still aborts the batch
+    RuleSet::from_overrides(&[&config.lint])?;
+
+    let mut results = Vec::with_capacity(targets.len());
+    for skill_dir in targets {
+        let rules = match load_rule_set(config, &skill_dir) {
+            Ok(rules) => rules,
+            Err(e) => {
+                // A broken .sikil-lint.toml only fails its own skill
+                results.push(config_error_result(&skill_dir, &e));
+                continue;
+            }
+        };
+        let skill_md_path = skill_dir.join("SKILL.md");
+        let fixes = if args.fix {
+            apply_fixes(&output, &skill_dir, &skill_md_path, &rules, args.yes)?
+        } else {
+            None
+        };
+        let mut result = validate_skill(&skill_dir, &skill_md_path, &rules, args.scripts)?;
+        result.fixes = fixes;
+        results.push(result);
+    }
 
-    // Check 1: SKILL.md exists
-    let check = check_skill_md_exists(&skill_md_path);
-    checks.push(check.clone());
+    let failed = results.iter().filter(|r| !r.passed).count();
+    let batch = BatchValidationResult {
+        passed: failed == 0,
+        total: results.len(),
+        failed,
+        results,
+    };
 
-    if !check.passed {
-        // If SKILL.md doesn't exist, we can't continue with other checks
-        let result = ValidationResult {
-            passed: false,
-            skill_path: skill_dir.to_string_lossy().to_string(),
-            checks,
-            warnings: Some(warnings),
-            metadata: None,
-            detected_directories: None,
-        };
+    match format {
+        ValidateFormat::Text => print_batch_human_readable(&output, &batch),
+        ValidateFormat::Json => output.print_json(&batch)?,
+        _ => print_report(&output, format, &batch.results)?,
+    }
 
-        if args.json_mode {
-            output.print_json(&result)?;
-        } else {
-            print_human_readable(&output, &result);
+    if batch.passed {
+        Ok(())
+    } else {
+        Err(SikilError::ValidationError {
+            reason: format!(
+                "{} of {} skill(s) have error-level findings",
+                batch.failed, batch.total
+            ),
         }
+        .into())
+    }
+}
 
-        std::process::exit(2);
+/// Returns the directories of all installed and managed skills, sorted by name
+fn installed_skill_dirs(config: &Config) -> Vec<PathBuf> {
+    let scanner = Scanner::new(config.clone());
+    let scan_result = scanner.scan_all_agents();
+
+    let mut skills: Vec<_> = scan_result.skills.values().collect();
+    skills.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+
+    skills
+        .into_iter()
+        .filter_map(|skill| {
+            skill
+                .installations
+                .first()
+                .map(|i| i.path.clone())
+                .or_else(|| skill.repo_path.clone())
+        })
+        .collect()
+}
+
+/// Finds every skill below `dir` when it is a directory of skills
+///
+/// Returns `None` if `dir` is not a directory, is itself a skill (contains
+/// SKILL.md), or contains no skills, so the caller can fall back to
+/// validating a single skill. Hidden directories are skipped and symlinks
+/// are not followed.
+fn discover_skill_dirs(dir: &Path) -> Option<Vec<PathBuf>> {
+    if !dir.is_dir() || dir.join("SKILL.md").exists() {
+        return None;
     }
 
-    // Check 2: YAML frontmatter is valid
-    let check = check_frontmatter_valid(&skill_md_path)?;
+    let mut dirs: Vec<PathBuf> = WalkDir::new(dir)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || !e.file_name().to_string_lossy().starts_with('.'))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && e.file_name() == "SKILL.md")
+        .filter_map(|e| e.path().parent().map(Path::to_path_buf))
+        .collect();
+    dirs.sort();
+
+    if dirs.is_empty() {
+        None
+    } else {
+        Some(dirs)
+    }
+}
 
-    checks.push(check.clone());
+/// Prints results in a CI report format (SARIF or JUnit)
+fn print_report(
+    output: &Output,
+    format: ValidateFormat,
+    results: &[ValidationResult],
+) -> Result<()> {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let files: Vec<String> = results
+        .iter()
+        .map(|r| {
+            let skill_md = Path::new(&r.skill_path).join("SKILL.md");
+            skill_md
+                .strip_prefix(&cwd)
+                .unwrap_or(&skill_md)
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+        .collect();
+    let entries: Vec<ReportEntry> = results
+        .iter()
+        .zip(files)
+        .map(|(r, file)| ReportEntry {
+            name: r
+                .metadata
+                .as_ref()
+                .map_or(r.skill_path.as_str(), |m| m.name.as_str()),
+            file,
+            findings: &r.findings,
+        })
+        .collect();
+
+    match format {
+        ValidateFormat::Sarif => output.print_json(&report::to_sarif(&entries))?,
+        _ => print!("{}", report::to_junit(&entries)),
+    }
+    Ok(())
+}
 
-    if !check.passed {
-        // If frontmatter is invalid, we can't parse metadata
-        let result = ValidationResult {
-            passed: false,
-            skill_path: skill_dir.to_string_lossy().to_string(),
-            checks,
-            warnings: Some(warnings),
-            metadata: None,
-            detected_directories: None,
-        };
+/// Fixes mechanical problems in a SKILL.md and writes it back atomically
+///
+/// Shows the planned fixes and a diff, then asks for confirmation unless
+/// `yes` is set or output is machine-readable. Returns the applied fixes, or
+/// `None` if there was nothing to fix or the user declined.
+fn apply_fixes(
+    output: &Output,
+    skill_dir: &Path,
+    skill_md_path: &Path,
+    rules: &RuleSet,
+    yes: bool,
+) -> Result<Option<Vec<String>>> {
+    if !skill_md_path.is_file() {
+        return Ok(None);
+    }
+    let original = fs::read_to_string(skill_md_path).map_err(|_| SikilError::PermissionDenied {
+        operation: "read".to_string(),
+        path: skill_md_path.to_path_buf(),
+    })?;
+
+    let options = FixOptions {
+        directory_name: skill_dir.file_name().and_then(|n| n.to_str()),
+        match_directory: rules.severity("SK009") != Severity::Off,
+    };
+    let outcome = fix_skill_md(&original, &options);
+    if outcome.fixes.is_empty() {
+        return Ok(None);
+    }
 
-        if args.json_mode {
-            output.print_json(&result)?;
-        } else {
-            print_human_readable(&output, &result);
-        }
+    output.print_info(&format!("Fixes for {}:", skill_md_path.display()));
+    for fix in &outcome.fixes {
+        output.print_info(&format!("  - {}", fix));
+    }
+    // BOM and line ending changes are listed above; keep them out of the diff
+    let comparable = original
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n");
+    let diff = unified_diff(&comparable, &outcome.content, "SKILL.md");
+    if !diff.is_empty() {
+        output.print_info("");
+        output.print_info(diff.trim_end());
+    }
+    output.print_info("");
 
-        std::process::exit(2);
+    if !yes && !output.json_mode && !prompt_confirmation("Apply these fixes?")? {
+        output.print_warning(&format!("Skipped fixes for {}", skill_md_path.display()));
+        output.print_info("");
+        return Ok(None);
     }
 
-    // Check 3: Parse metadata and validate required fields
-    let metadata = match parse_skill_md(&skill_md_path) {
-        Ok(meta) => meta,
-        Err(e) => {
-            // If parsing failed, add a check for required fields
-            checks.push(ValidationCheck {
-                name: "Required fields present".to_string(),
-                passed: false,
-                error: Some(e.to_string()),
-            });
+    atomic_write(skill_md_path, outcome.content.as_bytes())?;
+    output.print_success(&format!("✓ Fixed {}", skill_md_path.display()));
+    output.print_info("");
+    Ok(Some(outcome.fixes))
+}
 
-            let result = ValidationResult {
-                passed: false,
-                skill_path: skill_dir.to_string_lossy().to_string(),
-                checks,
-                warnings: Some(warnings),
-                metadata: None,
-                detected_directories: None,
-            };
+/// Builds the failed result for a skill whose `.sikil-lint.toml` cannot be
+/// loaded
+fn config_error_result(skill_dir: &Path, error: &SikilError) -> ValidationResult {
+    ValidationResult {
+        passed: false,
+        skill_path: skill_dir.to_string_lossy().to_string(),
+        checks: Vec::new(),
+        findings: vec![Finding {
+            rule: "config".to_string(),
+            severity: Severity::Error,
+            message: error.to_string(),
+            file: Some(SKILL_LINT_FILE.to_string()),
+            line: None,
+        }],
+        errors: Some(vec![error.to_string()]),
+        warnings: None,
+        suppressed: 0,
+        fixes: None,
+        metadata: None,
+        detected_directories: None,
+        scripts: None,
+    }
+}
 
-            if args.json_mode {
-                output.print_json(&result)?;
-            } else {
-                print_human_readable(&output, &result);
-            }
+/// Builds the effective rule set for a skill directory
+///
+/// Overrides from the global config are applied first, then those from the
+/// skill's `.sikil-lint.toml`.
+pub fn load_rule_set(config: &Config, skill_dir: &Path) -> Result<RuleSet, SikilError> {
+    match LintConfig::load_from_skill_dir(skill_dir)? {
+        Some(local) => RuleSet::from_overrides(&[&config.lint, &local]),
+        None => RuleSet::from_overrides(&[&config.lint]),
+    }
+}
+
+/// Runs all enabled rules against a skill and collects the results
+///
+/// Fatal rules (SK001-SK003) stop validation when they fail, since later
+/// rules depend on a parsed SKILL.md. With `analyze_scripts`, bundled scripts
+/// are inventoried and their risky constructs reported as SK013-SK015.
+///
+/// # Errors
+///
+/// Returns `SikilError::PermissionDenied` if SKILL.md cannot be read due to permissions.
+pub fn validate_skill(
+    skill_dir: &Path,
+    skill_md_path: &Path,
+    rules: &RuleSet,
+    analyze_scripts: bool,
+) -> Result<ValidationResult, SikilError> {
+    let mut linter = Linter::new(rules);
+
+    // SK001: SKILL.md exists
+    if !linter.check(check_skill_md_exists(skill_md_path), None) {
+        return Ok(linter.finish(skill_dir, None, None));
+    }
 
-            std::process::exit(2);
+    // SK002: YAML frontmatter is valid
+    let (frontmatter_check, frontmatter_line) = check_frontmatter_valid(skill_md_path)?;
+    if !linter.check(frontmatter_check, frontmatter_line) {
+        return Ok(linter.finish(skill_dir, None, None));
+    }
+
+    let content = fs::read_to_string(skill_md_path).map_err(|e| SikilError::InvalidSkillMd {
+        path: skill_md_path.to_path_buf(),
+        reason: e.to_string(),
+        line: None,
+        column: None,
+    })?;
+    linter.suppressions = Suppressions::parse(&content);
+
+    // SK003: Required fields present
+    let metadata = match parse_skill_md(skill_md_path) {
+        Ok(meta) => {
+            linter.check(
+                ValidationCheck::new("SK003", "Required fields present", None),
+                None,
+            );
+            meta
+        }
+        Err(e) => {
+            linter.check(
+                ValidationCheck::new("SK003", "Required fields present", Some(e.to_string())),
+                None,
+            );
+            return Ok(linter.finish(skill_dir, None, None));
         }
     };
 
-    checks.push(ValidationCheck {
-        name: "Required fields present".to_string(),
-        passed: true,
-        error: None,
-    });
+    // SK004: Name format constraints
+    linter.check(
+        check_name_format(&metadata.name),
+        frontmatter_key_line(&content, "name"),
+    );
 
-    // Check 4: Name format constraints
-    let check = check_name_format(&metadata.name);
-    checks.push(check);
+    // SK005: Description length (1-1024)
+    linter.check(
+        check_description_length(&metadata.description),
+        frontmatter_key_line(&content, "description"),
+    );
 
-    // Check 5: Description length (1-1024)
-    let check = check_description_length(&metadata.description);
-    checks.push(check);
-
-    // Check for optional fields (warnings)
+    // SK006-SK008: Optional fields
     if metadata.version.is_none() {
-        warnings.push("Optional field 'version' is missing".to_string());
+        linter.advise("SK006", "Optional field 'version' is missing", None);
     }
     if metadata.author.is_none() {
-        warnings.push("Optional field 'author' is missing".to_string());
+        linter.advise("SK007", "Optional field 'author' is missing", None);
     }
     if metadata.license.is_none() {
-        warnings.push("Optional field 'license' is missing".to_string());
+        linter.advise("SK008", "Optional field 'license' is missing", None);
     }
 
-    // Detect directories
-    let detected_directories = detect_directories(&skill_dir);
+    // SK009: Name matches directory
+    if let Some(dir_name) = skill_dir.file_name().and_then(|n| n.to_str()) {
+        if dir_name != metadata.name {
+            linter.advise(
+                "SK009",
+                &format!(
+                    "Skill name '{}' does not match directory '{}'",
+                    metadata.name, dir_name
+                ),
+                frontmatter_key_line(&content, "name"),
+            );
+        }
+    }
+
+    // SK010/SK011: File references in the body
+    if let Ok((body, body_line)) = extract_body(&content) {
+        let refs = extract_references(body, body_line);
+        for broken in find_broken_references(skill_dir, &refs) {
+            linter.advise(
+                "SK010",
+                &format!(
+                    "Line {}: referenced file '{}' does not exist",
+                    broken.line, broken.target
+                ),
+                Some(broken.line),
+            );
+        }
+        for file in find_unreferenced_files(skill_dir, &refs) {
+            linter.advise(
+                "SK011",
+                &format!(
+                    "File '{}' is not referenced from SKILL.md",
+                    file.to_string_lossy().replace('\\', "/")
+                ),
+                None,
+            );
+        }
+    }
+
+    // SK012: Secrets in any skill file
+    for secret in scan_skill_dir(skill_dir) {
+        let message = format!(
+            "{}:{}: possible {} ({})",
+            secret.file.display(),
+            secret.line,
+            secret.kind,
+            secret.excerpt
+        );
+        let file = (secret.file != Path::new("SKILL.md")).then_some(secret.file.as_path());
+        linter.advise_in_file("SK012", &message, file, Some(secret.line));
+    }
+
+    // SK013-SK015: Risky constructs in scripts
+    if analyze_scripts {
+        let scripts = inventory_scripts(skill_dir);
+        for script in &scripts {
+            for risk in &script.risks {
+                let rule = match risk.level {
+                    RiskLevel::High => "SK013",
+                    RiskLevel::Medium => "SK014",
+                    RiskLevel::Low => "SK015",
+                };
+                let message = format!(
+                    "{}:{}: {} - {} ({})",
+                    script.path, risk.line, risk.id, risk.description, risk.excerpt
+                );
+                linter.advise_in_file(
+                    rule,
+                    &message,
+                    Some(Path::new(&script.path)),
+                    Some(risk.line),
+                );
+            }
+        }
+        linter.scripts = Some(scripts);
+    }
+
+    // SK016: allowed-tools syntax
+    if let Some(Err(reason)) = metadata.extra.get("allowed-tools").map(parse_allowed_tools) {
+        linter.advise(
+            "SK016",
+            &format!("Invalid 'allowed-tools': {}", reason),
+            frontmatter_key_line(&content, "allowed-tools"),
+        );
+    }
+
+    // SK017: string fields given another YAML type are kept in `extra`
+    for key in ["homepage", "repository", "compatibility"] {
+        if metadata.extra.contains_key(key) {
+            linter.advise(
+                "SK017",
+                &format!("'{}' should be a string; the value is ignored", key),
+                frontmatter_key_line(&content, key),
+            );
+        }
+    }
 
     // Build detected metadata
     let detected_metadata = DetectedMetadata {
@@ -241,34 +696,131 @@ pub fn execute_validate(args: ValidateArgs, config: &Config) -> Result<()> {
         license: metadata.license.clone(),
     };
 
-    // Determine if all checks passed
-    let passed = checks.iter().all(|c| c.passed);
+    Ok(linter.finish(
+        skill_dir,
+        Some(detected_metadata),
+        Some(detect_directories(skill_dir)),
+    ))
+}
 
-    let result = ValidationResult {
-        passed,
-        skill_path: skill_dir.to_string_lossy().to_string(),
-        checks,
-        warnings: if warnings.is_empty() {
-            None
-        } else {
-            Some(warnings)
-        },
-        metadata: Some(detected_metadata),
-        detected_directories: Some(detected_directories),
-    };
+/// Collects checks and findings while applying severities and suppressions
+struct Linter<'a> {
+    rules: &'a RuleSet,
+    suppressions: Suppressions,
+    checks: Vec<ValidationCheck>,
+    findings: Vec<Finding>,
+    errors: Vec<String>,
+    warnings: Vec<String>,
+    suppressed: usize,
+    scripts: Option<Vec<ScriptInfo>>,
+}
 
-    // Output results
-    if args.json_mode {
-        output.print_json(&result)?;
-    } else {
-        print_human_readable(&output, &result);
+impl<'a> Linter<'a> {
+    fn new(rules: &'a RuleSet) -> Self {
+        Self {
+            rules,
+            suppressions: Suppressions::default(),
+            checks: Vec::new(),
+            findings: Vec::new(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            suppressed: 0,
+            scripts: None,
+        }
     }
 
-    // Exit with appropriate code
-    if passed {
-        Ok(())
-    } else {
-        std::process::exit(2);
+    /// Returns true if a failure of `rule` at `line` should be reported
+    fn is_reportable(&mut self, rule: &str, line: Option<usize>) -> bool {
+        let fatal = find_rule(rule).is_some_and(|r| r.fatal);
+        if !fatal && self.suppressions.is_suppressed(rule, line) {
+            self.suppressed += 1;
+            return false;
+        }
+        true
+    }
+
+    /// Records a named check; returns false only if the check failed
+    fn check(&mut self, mut check: ValidationCheck, line: Option<usize>) -> bool {
+        let severity = self.rules.severity(&check.rule);
+        if severity == Severity::Off {
+            return true;
+        }
+        check.severity = severity;
+
+        if !check.passed {
+            if !self.is_reportable(&check.rule, line) {
+                return true;
+            }
+            self.findings.push(Finding {
+                rule: check.rule.clone(),
+                severity,
+                message: check.error.clone().unwrap_or_else(|| check.name.clone()),
+                file: None,
+                line,
+            });
+        }
+
+        let passed = check.passed;
+        self.checks.push(check);
+        passed
+    }
+
+    /// Records a finding in SKILL.md that is not shown as a named check
+    fn advise(&mut self, rule: &str, message: &str, line: Option<usize>) {
+        self.advise_in_file(rule, message, None, line);
+    }
+
+    /// Records a finding in another file of the skill
+    ///
+    /// Line-level suppressions only apply to SKILL.md; findings in other
+    /// files can only be suppressed file-wide.
+    fn advise_in_file(
+        &mut self,
+        rule: &str,
+        message: &str,
+        file: Option<&Path>,
+        line: Option<usize>,
+    ) {
+        let severity = self.rules.severity(rule);
+        let suppression_line = if file.is_some() { None } else { line };
+        if severity == Severity::Off || !self.is_reportable(rule, suppression_line) {
+            return;
+        }
+
+        let text = format!("[{}] {}", rule, message);
+        if severity == Severity::Error {
+            self.errors.push(text);
+        } else {
+            self.warnings.push(text);
+        }
+        self.findings.push(Finding {
+            rule: rule.to_string(),
+            severity,
+            message: message.to_string(),
+            file: file.map(|f| f.to_string_lossy().replace('\\', "/")),
+            line,
+        });
+    }
+
+    fn finish(
+        self,
+        skill_dir: &Path,
+        metadata: Option<DetectedMetadata>,
+        detected_directories: Option<DetectedDirectories>,
+    ) -> ValidationResult {
+        ValidationResult {
+            passed: self.findings.iter().all(|f| f.severity != Severity::Error),
+            skill_path: skill_dir.to_string_lossy().to_string(),
+            checks: self.checks,
+            findings: self.findings,
+            errors: (!self.errors.is_empty()).then_some(self.errors),
+            warnings: (!self.warnings.is_empty()).then_some(self.warnings),
+            suppressed: self.suppressed,
+            fixes: None,
+            metadata,
+            detected_directories,
+            scripts: self.scripts,
+        }
     }
 }
 
@@ -329,6 +881,8 @@ fn resolve_path_direct(path: &Path) -> Result<(PathBuf, PathBuf)> {
             Err(SikilError::InvalidSkillMd {
                 path: path.to_path_buf(),
                 reason: "expected SKILL.md file or skill directory".to_string(),
+                line: None,
+                column: None,
             }
             .into())
         }
@@ -344,24 +898,19 @@ fn resolve_path_direct(path: &Path) -> Result<(PathBuf, PathBuf)> {
     }
 }
 
-/// Checks if SKILL.md exists
+/// Checks if SKILL.md exists (SK001)
 fn check_skill_md_exists(path: &Path) -> ValidationCheck {
-    let exists = path.exists();
-    ValidationCheck {
-        name: "SKILL.md exists".to_string(),
-        passed: exists,
-        error: if exists {
-            None
-        } else {
-            Some("SKILL.md file not found".to_string())
-        },
-    }
+    let error = (!path.exists()).then(|| "SKILL.md file not found".to_string());
+    ValidationCheck::new("SK001", "SKILL.md exists", error)
 }
 
-/// Checks if YAML frontmatter is valid
+/// Checks if YAML frontmatter is valid (SK002)
 ///
+/// Returns the check and, on failure, the line of the problem when known.
 /// Returns `Err(SikilError::PermissionDenied)` if the file cannot be read due to permissions.
-fn check_frontmatter_valid(path: &Path) -> Result<ValidationCheck, SikilError> {
+fn check_frontmatter_valid(path: &Path) -> Result<(ValidationCheck, Option<usize>), SikilError> {
+    const NAME: &str = "YAML frontmatter is valid";
+
     let content = match fs::read_to_string(path) {
         Ok(c) => c,
         Err(e) => {
@@ -372,63 +921,61 @@ fn check_frontmatter_valid(path: &Path) -> Result<ValidationCheck, SikilError> {
                     path: path.to_path_buf(),
                 });
             }
-            return Ok(ValidationCheck {
-                name: "YAML frontmatter is valid".to_string(),
-                passed: false,
-                error: Some(format!("Failed to read file: {}", e)),
-            });
+            return Ok((
+                ValidationCheck::new("SK002", NAME, Some(format!("Failed to read file: {}", e))),
+                None,
+            ));
         }
     };
 
-    match extract_frontmatter(&content) {
-        Ok(_) => Ok(ValidationCheck {
-            name: "YAML frontmatter is valid".to_string(),
-            passed: true,
-            error: None,
-        }),
-        Err(e) => Ok(ValidationCheck {
-            name: "YAML frontmatter is valid".to_string(),
-            passed: false,
-            error: Some(e.to_string()),
-        }),
-    }
+    let (error, line) = match parse_frontmatter::<serde_yaml::Value>(&content) {
+        Ok(_) => (None, None),
+        Err(SikilError::InvalidSkillMd {
+            reason,
+            line,
+            column,
+            ..
+        }) => {
+            let error = SikilError::InvalidSkillMd {
+                path: path.to_path_buf(),
+                reason,
+                line,
+                column,
+            };
+            (Some(error.to_string()), line)
+        }
+        Err(e) => (Some(e.to_string()), None),
+    };
+    Ok((ValidationCheck::new("SK002", NAME, error), line))
 }
 
-/// Checks if the name format is valid
+/// Checks if the name format is valid (SK004)
+///
+/// The finding carries the bare reason, without the error's
+/// "Validation failed" prefix.
 fn check_name_format(name: &str) -> ValidationCheck {
-    match validate_skill_name(name) {
-        Ok(_) => ValidationCheck {
-            name: "Name format is valid".to_string(),
-            passed: true,
-            error: None,
-        },
-        Err(e) => ValidationCheck {
-            name: "Name format is valid".to_string(),
-            passed: false,
-            error: Some(e.to_string()),
-        },
-    }
+    let error = validate_skill_name(name).err().map(|e| match e {
+        SikilError::ValidationError { reason } => reason,
+        e => e.to_string(),
+    });
+    ValidationCheck::new("SK004", "Name format is valid", error)
 }
 
-/// Checks if the description length is within bounds (1-1024)
+/// Checks if the description length is within bounds (1-1024) (SK005)
 fn check_description_length(description: &str) -> ValidationCheck {
     let len = description.len();
-    let valid = (1..=1024).contains(&len);
+    let error = if len == 0 {
+        Some("Description is empty".to_string())
+    } else if len > 1024 {
+        Some(format!(
+            "Description is too long: {} characters (max 1024)",
+            len
+        ))
+    } else {
+        None
+    };
 
-    ValidationCheck {
-        name: "Description length is valid (1-1024)".to_string(),
-        passed: valid,
-        error: if valid {
-            None
-        } else if len == 0 {
-            Some("Description is empty".to_string())
-        } else {
-            Some(format!(
-                "Description is too long: {} characters (max 1024)",
-                len
-            ))
-        },
-    }
+    ValidationCheck::new("SK005", "Description length is valid (1-1024)", error)
 }
 
 /// Detects standard directories in the skill
@@ -442,6 +989,86 @@ fn detect_directories(skill_dir: &Path) -> DetectedDirectories {
     }
 }
 
+/// Prints a compact human-readable summary for batch validation
+fn print_batch_human_readable(output: &Output, batch: &BatchValidationResult) {
+    for result in &batch.results {
+        if result.passed {
+            output.print_success(&format!("✓ {}", result.skill_path));
+        } else {
+            output.print_error(&format!("✗ {}", result.skill_path));
+        }
+
+        for finding in &result.findings {
+            let location = finding
+                .line
+                .map_or(String::new(), |l| format!(" (line {})", l));
+            let line = format!(
+                "  {} {}{}: {}",
+                finding.rule, finding.severity, location, finding.message
+            );
+            match finding.severity {
+                Severity::Error => output.print_error(&line),
+                Severity::Warning => output.print_warning(&line),
+                _ => output.print_info(&line),
+            }
+        }
+    }
+
+    output.print_info("");
+    let summary = format!(
+        "Validated {} skill(s): {} passed, {} failed",
+        batch.total,
+        batch.total - batch.failed,
+        batch.failed
+    );
+    if batch.passed {
+        output.print_success(&summary);
+    } else {
+        output.print_error(&summary);
+    }
+}
+
+/// Prints a script inventory with the risks found in each script
+///
+/// Shared with `show`, which includes the same section.
+pub(crate) fn print_scripts(output: &Output, scripts: &[ScriptInfo]) {
+    output.print_info(&format!("Scripts ({}):", scripts.len()));
+    if scripts.is_empty() {
+        output.print_info("  (none)");
+    }
+    for script in scripts {
+        let mut details = Vec::new();
+        if let Some(ref shebang) = script.shebang {
+            details.push(format!("#!{}", shebang));
+        }
+        if script.executable {
+            details.push("executable".to_string());
+        }
+        let label = if details.is_empty() {
+            format!("  {}", script.path)
+        } else {
+            format!("  {} ({})", script.path, details.join(", "))
+        };
+        match script.max_level() {
+            Some(RiskLevel::High) => output.print_error(&format!("{} - high risk", label)),
+            Some(RiskLevel::Medium) => output.print_warning(&format!("{} - medium risk", label)),
+            Some(RiskLevel::Low) => output.print_info(&format!("{} - low risk", label)),
+            None => output.print_info(&label),
+        }
+        for risk in &script.risks {
+            let line = format!(
+                "    {} line {}: {} - {}",
+                risk.level, risk.line, risk.id, risk.excerpt
+            );
+            match risk.level {
+                RiskLevel::High => output.print_error(&line),
+                RiskLevel::Medium => output.print_warning(&line),
+                RiskLevel::Low => output.print_info(&line),
+            }
+        }
+    }
+}
+
 /// Prints human-readable output for the validate command
 fn print_human_readable(output: &Output, result: &ValidationResult) {
     output.print_info(&format!("Validating skill at: {}", result.skill_path));
@@ -451,11 +1078,16 @@ fn print_human_readable(output: &Output, result: &ValidationResult) {
     for check in &result.checks {
         if check.passed {
             output.print_success(&format!("✓ {}", check.name));
-        } else {
-            output.print_error(&format!("✗ {}", check.name));
+        } else if check.severity == Severity::Error {
+            output.print_error(&format!("✗ {} [{}]", check.name, check.rule));
             if let Some(ref error) = check.error {
                 output.print_error(&format!("  {}", error));
             }
+        } else {
+            output.print_warning(&format!("⚠ {} [{}]", check.name, check.rule));
+            if let Some(ref error) = check.error {
+                output.print_warning(&format!("  {}", error));
+            }
         }
     }
 
@@ -475,6 +1107,15 @@ fn print_human_readable(output: &Output, result: &ValidationResult) {
         }
     }
 
+    // Print errors not covered by checks
+    if let Some(ref errors) = result.errors {
+        output.print_info("");
+        output.print_error("Errors:");
+        for error in errors {
+            output.print_error(&format!("  - {}", error));
+        }
+    }
+
     // Print warnings
     if let Some(ref warnings) = result.warnings {
         if !warnings.is_empty() {
@@ -500,6 +1141,19 @@ fn print_human_readable(output: &Output, result: &ValidationResult) {
         ));
     }
 
+    if let Some(ref scripts) = result.scripts {
+        output.print_info("");
+        print_scripts(output, scripts);
+    }
+
+    if result.suppressed > 0 {
+        output.print_info("");
+        output.print_info(&format!(
+            "{} finding(s) suppressed by inline comments",
+            result.suppressed
+        ));
+    }
+
     // Print final status
     output.print_info("");
     if result.passed {
@@ -583,8 +1237,9 @@ This is a test skill."#;
         create_valid_skill_md(temp_dir.path());
 
         let skill_md = temp_dir.path().join("SKILL.md");
-        let check = check_frontmatter_valid(&skill_md).unwrap();
+        let (check, line) = check_frontmatter_valid(&skill_md).unwrap();
         assert!(check.passed);
+        assert!(line.is_none());
         assert!(check.error.is_none());
     }
 
@@ -596,11 +1251,79 @@ description: No frontmatter markers"#;
         fs::write(temp_dir.path().join("SKILL.md"), content).unwrap();
 
         let skill_md = temp_dir.path().join("SKILL.md");
-        let check = check_frontmatter_valid(&skill_md).unwrap();
+        let (check, _) = check_frontmatter_valid(&skill_md).unwrap();
         assert!(!check.passed);
         assert!(check.error.is_some());
     }
 
+    #[test]
+    fn test_check_frontmatter_valid_reports_yaml_error_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_md = temp_dir.path().join("SKILL.md");
+        fs::write(
+            &skill_md,
+            "---\nname: test-skill\ndescription: ok\nbad: a: b\n---\n",
+        )
+        .unwrap();
+
+        let (check, line) = check_frontmatter_valid(&skill_md).unwrap();
+        assert!(!check.passed);
+        assert_eq!(line, Some(4));
+        assert!(check
+            .error
+            .unwrap()
+            .contains("SKILL.md:4:7: failed to parse YAML"));
+    }
+
+    #[test]
+    fn test_validate_skill_reports_invalid_allowed_tools() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("tools-skill");
+        fs::create_dir(&skill_dir).unwrap();
+        let skill_md = skill_dir.join("SKILL.md");
+        fs::write(
+            &skill_md,
+            "---\nname: tools-skill\ndescription: d\nallowed-tools: Read, Bash(git:*\n---\n",
+        )
+        .unwrap();
+
+        let result = validate_skill(&skill_dir, &skill_md, &RuleSet::default(), false).unwrap();
+        let finding = result.findings.iter().find(|f| f.rule == "SK016").unwrap();
+        assert_eq!(finding.line, Some(4));
+        assert!(finding.message.contains("unclosed '('"));
+        assert!(!result.passed);
+
+        fs::write(
+            &skill_md,
+            "---\nname: tools-skill\ndescription: d\nallowed-tools: [Read, 'Bash(git:*)']\n---\n",
+        )
+        .unwrap();
+        let result = validate_skill(&skill_dir, &skill_md, &RuleSet::default(), false).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule == "SK016"));
+    }
+
+    #[test]
+    fn test_validate_skill_warns_about_non_string_fields() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("compat-skill");
+        fs::create_dir(&skill_dir).unwrap();
+        let skill_md = skill_dir.join("SKILL.md");
+        fs::write(
+            &skill_md,
+            "---\nname: compat-skill\ndescription: d\ncompatibility: [git, node]\n---\n",
+        )
+        .unwrap();
+
+        let result = validate_skill(&skill_dir, &skill_md, &RuleSet::default(), false).unwrap();
+        let finding = result.findings.iter().find(|f| f.rule == "SK017").unwrap();
+        assert_eq!(finding.severity, Severity::Warning);
+        assert_eq!(finding.line, Some(4));
+        assert!(finding
+            .message
+            .contains("'compatibility' should be a string"));
+        assert!(result.passed);
+    }
+
     #[test]
     fn test_check_name_format_when_valid() {
         let check = check_name_format("test-skill");
@@ -612,7 +1335,8 @@ description: No frontmatter markers"#;
     fn test_check_name_format_when_invalid() {
         let check = check_name_format("-invalid");
         assert!(!check.passed);
-        assert!(check.error.is_some());
+        let error = check.error.unwrap();
+        assert!(error.starts_with("invalid skill name '-invalid'"));
     }
 
     #[test]
@@ -670,12 +1394,12 @@ description: No frontmatter markers"#;
         let result = ValidationResult {
             passed: true,
             skill_path: "/path/to/skill".to_string(),
-            checks: vec![ValidationCheck {
-                name: "Test check".to_string(),
-                passed: true,
-                error: None,
-            }],
+            checks: vec![ValidationCheck::new("SK004", "Test check", None)],
+            findings: Vec::new(),
+            errors: None,
             warnings: None,
+            suppressed: 0,
+            fixes: None,
             metadata: Some(DetectedMetadata {
                 name: "test-skill".to_string(),
                 description: "A test skill".to_string(),
@@ -687,6 +1411,7 @@ description: No frontmatter markers"#;
                 has_scripts: true,
                 has_references: false,
             }),
+            scripts: None,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -700,13 +1425,11 @@ description: No frontmatter markers"#;
 
     #[test]
     fn test_validation_check_serialization() {
-        let check = ValidationCheck {
-            name: "Test check".to_string(),
-            passed: false,
-            error: Some("Test error".to_string()),
-        };
+        let check = ValidationCheck::new("SK004", "Test check", Some("Test error".to_string()));
 
         let json = serde_json::to_string(&check).unwrap();
+        assert!(json.contains("\"rule\":\"SK004\""));
+        assert!(json.contains("\"severity\":\"error\""));
         assert!(json.contains("\"name\":\"Test check\""));
         assert!(json.contains("\"passed\":false"));
         assert!(json.contains("\"error\":\"Test error\""));
@@ -724,6 +1447,227 @@ description: No frontmatter markers"#;
         assert!(json.contains("\"has_references\":true"));
     }
 
+    fn minimal_skill(dir: &Path, frontmatter_extra: &str) -> PathBuf {
+        let skill_dir = dir.join("min-skill");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let content = format!(
+            "---\n{}name: min-skill\ndescription: Minimal skill\n---\n\n# Body\n",
+            frontmatter_extra
+        );
+        fs::write(skill_dir.join("SKILL.md"), content).unwrap();
+        skill_dir
+    }
+
+    #[test]
+    fn test_validate_skill_default_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = minimal_skill(temp_dir.path(), "");
+
+        let result = validate_skill(
+            &skill_dir,
+            &skill_dir.join("SKILL.md"),
+            &RuleSet::default(),
+            false,
+        )
+        .unwrap();
+        assert!(result.passed);
+        assert_eq!(result.checks.len(), 5);
+        let rules: Vec<&str> = result.findings.iter().map(|f| f.rule.as_str()).collect();
+        assert_eq!(rules, vec!["SK006", "SK007", "SK008"]);
+        assert!(result.warnings.unwrap()[0].starts_with("[SK006]"));
+    }
+
+    #[test]
+    fn test_validate_skill_overrides_escalate_and_disable() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = minimal_skill(temp_dir.path(), "");
+        let mut lint = LintConfig::default();
+        lint.rules.insert("SK006".to_string(), Severity::Error);
+        lint.rules.insert("SK007".to_string(), Severity::Off);
+        lint.rules.insert("SK008".to_string(), Severity::Off);
+        let rules = RuleSet::from_overrides(&[&lint]).unwrap();
+
+        let result =
+            validate_skill(&skill_dir, &skill_dir.join("SKILL.md"), &rules, false).unwrap();
+        assert!(!result.passed);
+        assert_eq!(result.findings.len(), 1);
+        assert_eq!(result.errors.unwrap().len(), 1);
+        assert!(result.warnings.is_none());
+    }
+
+    #[test]
+    fn test_validate_skill_downgraded_check_passes() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("bad");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: Bad_Name\ndescription: d\n---\n",
+        )
+        .unwrap();
+        let mut lint = LintConfig::default();
+        lint.rules.insert("SK004".to_string(), Severity::Warning);
+        let rules = RuleSet::from_overrides(&[&lint]).unwrap();
+
+        let result =
+            validate_skill(&skill_dir, &skill_dir.join("SKILL.md"), &rules, false).unwrap();
+        assert!(result.passed);
+        let name_check = result.checks.iter().find(|c| c.rule == "SK004").unwrap();
+        assert!(!name_check.passed);
+        assert_eq!(name_check.severity, Severity::Warning);
+        assert_eq!(result.findings[0].line, Some(2));
+    }
+
+    #[test]
+    fn test_validate_skill_inline_suppression() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = minimal_skill(
+            temp_dir.path(),
+            "# sikil-disable SK006 SK007 SK008\n# sikil-disable-next-line SK004\n",
+        );
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            fs::read_to_string(skill_dir.join("SKILL.md"))
+                .unwrap()
+                .replace("name: min-skill", "name: Min_Skill"),
+        )
+        .unwrap();
+
+        let result = validate_skill(
+            &skill_dir,
+            &skill_dir.join("SKILL.md"),
+            &RuleSet::default(),
+            false,
+        )
+        .unwrap();
+        assert!(result.passed);
+        assert!(result.findings.is_empty());
+        assert_eq!(result.suppressed, 4);
+    }
+
+    #[test]
+    fn test_validate_skill_name_matches_directory_opt_in() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("other-dir");
+        fs::create_dir_all(&skill_dir).unwrap();
+        create_valid_skill_md(&skill_dir);
+        fs::write(
+            skill_dir.join(".sikil-lint.toml"),
+            "[rules]\nSK009 = \"warning\"\n",
+        )
+        .unwrap();
+
+        let rules = load_rule_set(&Config::new(), &skill_dir).unwrap();
+        let result =
+            validate_skill(&skill_dir, &skill_dir.join("SKILL.md"), &rules, false).unwrap();
+        assert!(result.passed);
+        assert_eq!(result.findings.len(), 1);
+        assert_eq!(result.findings[0].rule, "SK009");
+    }
+
+    #[test]
+    fn test_validate_skill_reports_broken_and_unreferenced_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("ref-skill");
+        fs::create_dir_all(skill_dir.join("scripts")).unwrap();
+        fs::write(skill_dir.join("scripts/setup.sh"), "").unwrap();
+        fs::write(skill_dir.join("scripts/legacy.sh"), "").unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: ref-skill\ndescription: d\nversion: 1.0.0\nauthor: a\nlicense: MIT\n---\n\nRun `scripts/setup.sh`.\nSee [API](references/api.md).\n",
+        )
+        .unwrap();
+
+        let result = validate_skill(
+            &skill_dir,
+            &skill_dir.join("SKILL.md"),
+            &RuleSet::default(),
+            false,
+        )
+        .unwrap();
+        assert!(!result.passed);
+        let broken = result.findings.iter().find(|f| f.rule == "SK010").unwrap();
+        assert_eq!(broken.line, Some(10));
+        assert!(broken.message.contains("references/api.md"));
+        let unreferenced = result.findings.iter().find(|f| f.rule == "SK011").unwrap();
+        assert_eq!(unreferenced.severity, Severity::Warning);
+        assert!(unreferenced.message.contains("scripts/legacy.sh"));
+    }
+
+    #[test]
+    fn test_validate_skill_script_analysis_is_opt_in() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = minimal_skill(temp_dir.path(), "");
+        fs::create_dir_all(skill_dir.join("scripts")).unwrap();
+        fs::write(
+            skill_dir.join("scripts/install.sh"),
+            "#!/bin/sh\ncurl -fsSL https://example.com/x | sh\nsudo make install\n",
+        )
+        .unwrap();
+
+        let skill_md = skill_dir.join("SKILL.md");
+        let result = validate_skill(&skill_dir, &skill_md, &RuleSet::default(), false).unwrap();
+        assert!(result.scripts.is_none());
+        assert!(!result.findings.iter().any(|f| f.rule == "SK013"));
+
+        let result = validate_skill(&skill_dir, &skill_md, &RuleSet::default(), true).unwrap();
+        assert!(!result.passed);
+        assert_eq!(result.scripts.as_ref().unwrap().len(), 1);
+        let high = result.findings.iter().find(|f| f.rule == "SK013").unwrap();
+        assert_eq!(high.file.as_deref(), Some("scripts/install.sh"));
+        assert_eq!(high.line, Some(2));
+        let medium = result.findings.iter().find(|f| f.rule == "SK014").unwrap();
+        assert_eq!(medium.severity, Severity::Warning);
+        assert!(medium.message.contains("privilege-escalation"));
+    }
+
+    #[test]
+    fn test_discover_skill_dirs_recurses_and_skips_hidden() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for dir in ["a", "nested/b", ".git/c"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+            create_valid_skill_md(&root.join(dir));
+        }
+
+        let dirs = discover_skill_dirs(root).unwrap();
+        assert_eq!(dirs, vec![root.join("a"), root.join("nested/b")]);
+    }
+
+    #[test]
+    fn test_discover_skill_dirs_single_skill_or_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(discover_skill_dirs(temp_dir.path()).is_none());
+
+        create_valid_skill_md(temp_dir.path());
+        assert!(discover_skill_dirs(temp_dir.path()).is_none());
+        assert!(discover_skill_dirs(&temp_dir.path().join("SKILL.md")).is_none());
+    }
+
+    #[test]
+    fn test_validate_format_from_str() {
+        assert_eq!(
+            "sarif".parse::<ValidateFormat>().unwrap(),
+            ValidateFormat::Sarif
+        );
+        assert_eq!(
+            "junit".parse::<ValidateFormat>().unwrap(),
+            ValidateFormat::Junit
+        );
+        assert!("xml".parse::<ValidateFormat>().is_err());
+    }
+
+    #[test]
+    fn test_load_rule_set_rejects_unknown_rule() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(".sikil-lint.toml"),
+            "[rules]\nSK123 = \"off\"\n",
+        )
+        .unwrap();
+        assert!(load_rule_set(&Config::new(), temp_dir.path()).is_err());
+    }
+
     #[test]
     fn test_resolve_paths_with_skill_name() {
         let temp_dir = TempDir::new().unwrap();
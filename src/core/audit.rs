@@ -0,0 +1,341 @@
+//! Append-only audit log of skill changes
+//!
+//! Every mutating command appends one JSON line to `~/.sikil/log.jsonl`
+//! saying who ran it, when, on which skill, from which source and for which
+//! agents, what the repository copy hashed to afterwards and whether it
+//! succeeded. Lines are only ever appended; `sikil log` reads them back.
+
+use crate::cli::{CacheAction, Commands, MetaAction, TrashAction};
+use crate::core::config::Config;
+use crate::core::errors::SikilError;
+use crate::core::history::HistoryEntry;
+use crate::core::parser::parse_skill_md;
+use crate::utils::paths::{ensure_dir_exists, get_history_dir, get_repo_path, hash_dir};
+use crate::utils::time::{format_timestamp, now_secs, parse_date};
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+
+/// How a logged command ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    /// The command completed
+    Success,
+    /// The command returned an error
+    Failure,
+}
+
+/// One line of the audit log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// When the command finished, as an RFC 3339 UTC timestamp
+    pub timestamp: String,
+    /// Login name of the user who ran the command
+    pub user: String,
+    /// Command that ran, e.g. `install` or `meta set`
+    pub command: String,
+    /// Skill the command acted on, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill: Option<String>,
+    /// Where the skill came from (install path or Git URL, adopt agent)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Agents the command acted on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub agents: Vec<String>,
+    /// Hash of the skill's repository copy after the command, if it has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+    /// Whether the command succeeded
+    pub outcome: Outcome,
+    /// The error a failed command reported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl AuditEntry {
+    /// Creates a successful entry for `command`, run now by the current user
+    pub fn new(command: &str) -> Self {
+        Self {
+            timestamp: format_timestamp(now_secs()),
+            user: current_user(),
+            command: command.to_string(),
+            skill: None,
+            source: None,
+            agents: Vec::new(),
+            content_hash: None,
+            outcome: Outcome::Success,
+            error: None,
+        }
+    }
+
+    /// Returns the entry's time as seconds since the Unix epoch
+    pub fn timestamp_secs(&self) -> Option<u64> {
+        parse_date(&self.timestamp)
+    }
+
+    /// Describes a mutating command for the audit log, before it runs
+    ///
+    /// The skill, source and agents are taken from the arguments; anything
+    /// only known once the command has run is filled in by
+    /// [`finish`](Self::finish).
+    pub fn for_command(command: &Commands) -> Self {
+        let split = |agents: &Option<String>| -> Vec<String> {
+            agents
+                .iter()
+                .flat_map(|agents| agents.split(','))
+                .map(|agent| agent.trim().to_string())
+                .filter(|agent| !agent.is_empty())
+                .collect()
+        };
+
+        let (name, skill, source, agents) = match command {
+            Commands::Install { source, r#to, .. } => {
+                // A local skill's name is known up front; a Git one's only once
+                // cloned, and there is no SKILL.md at its URL
+                let skill = parse_skill_md(&Path::new(source).join("SKILL.md"))
+                    .ok()
+                    .map(|metadata| metadata.name);
+                ("install", skill, Some(source.clone()), split(to))
+            }
+            Commands::Adopt { name, from, .. } => {
+                ("adopt", Some(name.clone()), from.clone(), split(from))
+            }
+            Commands::Unmanage { name, agent, .. } => {
+                ("unmanage", Some(name.clone()), None, split(agent))
+            }
+            Commands::Remove { name, agent, .. } => {
+                ("remove", Some(name.clone()), None, split(agent))
+            }
+            Commands::Sync { name, r#to, .. } => ("sync", name.clone(), None, split(to)),
+            Commands::Validate { path, .. } => ("validate --fix", path.clone(), None, Vec::new()),
+            Commands::Meta { action } => match action {
+                MetaAction::Get { skill, .. } => {
+                    ("meta get", Some(skill.clone()), None, Vec::new())
+                }
+                MetaAction::Set { skill, .. } => {
+                    ("meta set", Some(skill.clone()), None, Vec::new())
+                }
+                MetaAction::Unset { skill, .. } => {
+                    ("meta unset", Some(skill.clone()), None, Vec::new())
+                }
+            },
+            Commands::Undo => (
+                "undo",
+                HistoryEntry::latest(&get_history_dir()).map(|entry| entry.skill),
+                None,
+                Vec::new(),
+            ),
+            Commands::Config { .. } => ("config", None, None, Vec::new()),
+            Commands::Gc { .. } => ("gc", None, None, Vec::new()),
+            Commands::Repair => ("repair", None, None, Vec::new()),
+            Commands::Recover { .. } => ("recover", None, None, Vec::new()),
+            Commands::Cache { action } => match action {
+                CacheAction::Clear => ("cache clear", None, None, Vec::new()),
+                CacheAction::Prune => ("cache prune", None, None, Vec::new()),
+                CacheAction::Stats => ("cache stats", None, None, Vec::new()),
+                CacheAction::Verify => ("cache verify", None, None, Vec::new()),
+            },
+            Commands::Trash { action } => match action {
+                TrashAction::List => ("trash list", None, None, Vec::new()),
+                TrashAction::Restore { name } => {
+                    ("trash restore", Some(name.clone()), None, Vec::new())
+                }
+                TrashAction::Empty { .. } => ("trash empty", None, None, Vec::new()),
+            },
+            Commands::List { .. } => ("list", None, None, Vec::new()),
+            Commands::Show { name } => ("show", Some(name.clone()), None, Vec::new()),
+            Commands::Search { .. } => ("search", None, None, Vec::new()),
+            Commands::Permissions { .. } => ("permissions", None, None, Vec::new()),
+            Commands::Doctor => ("doctor", None, None, Vec::new()),
+            Commands::Log { skill, .. } => ("log", skill.clone(), None, Vec::new()),
+            Commands::Completions { .. } => ("completions", None, None, Vec::new()),
+        };
+
+        let mut entry = Self::new(name);
+        entry.skill = skill;
+        entry.source = source;
+        entry.agents = agents;
+        entry
+    }
+
+    /// Records how a command ended and the state it left the skill in
+    pub fn finish(mut self, result: &anyhow::Result<()>, config: &Config) -> Self {
+        if let Err(e) = result {
+            self.outcome = Outcome::Failure;
+            self.error = Some(e.to_string());
+        }
+
+        if self.skill.is_none() && self.command == "install" && result.is_ok() {
+            self.skill = HistoryEntry::latest(&get_history_dir())
+                .filter(|recorded| recorded.command == "install")
+                .map(|recorded| recorded.skill);
+        }
+        let Some(skill) = self.skill.clone() else {
+            return self;
+        };
+
+        // Without --to, install and sync link to agents chosen as they run
+        if self.agents.is_empty() && matches!(self.command.as_str(), "install" | "sync") {
+            let mut agents: Vec<String> = config
+                .agents
+                .iter()
+                .filter(|(_, agent)| agent.enabled && agent.global_path.join(&skill).is_symlink())
+                .map(|(name, _)| name.clone())
+                .collect();
+            agents.sort();
+            self.agents = agents;
+        }
+
+        let repo = get_repo_path().join(&skill);
+        if repo.is_dir() {
+            self.content_hash = Some(hash_dir(&repo));
+        }
+        self
+    }
+}
+
+/// Appends an entry to the log at `path`, creating it if needed
+pub fn append_entry(path: &Path, entry: &AuditEntry) -> Result<(), SikilError> {
+    if let Some(parent) = path.parent() {
+        ensure_dir_exists(parent).map_err(|_| SikilError::PermissionDenied {
+            operation: "create audit log directory".to_string(),
+            path: parent.to_path_buf(),
+        })?;
+    }
+
+    let mut line = serde_json::to_string(entry).map_err(|e| SikilError::ValidationError {
+        reason: format!("failed to serialize audit entry: {}", e),
+    })?;
+    line.push('\n');
+
+    // A single write to an O_APPEND file keeps concurrent lines whole
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .map_err(|_| SikilError::PermissionDenied {
+            operation: "append to audit log".to_string(),
+            path: path.to_path_buf(),
+        })
+}
+
+/// Reads every entry in the log at `path`, oldest first
+///
+/// A missing log has no entries. Lines that cannot be parsed are skipped.
+pub fn read_entries(path: &Path) -> Result<Vec<AuditEntry>, SikilError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(_) => {
+            return Err(SikilError::PermissionDenied {
+                operation: "read audit log".to_string(),
+                path: path.to_path_buf(),
+            })
+        }
+    };
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Returns the login name of the current user
+///
+/// The name comes from `USER`, `USERNAME` or `LOGNAME`. When none is set, it
+/// is looked up from the process's user id, and the id itself is used if it
+/// has no name. Only on other platforms without those variables is the user
+/// `unknown`.
+pub fn current_user() -> String {
+    ["USER", "USERNAME", "LOGNAME"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|name| !name.is_empty()))
+        .or_else(user_from_uid)
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Returns the name of the process's real user id, or `uid <n>` if the
+/// user database has no entry for it
+#[cfg(unix)]
+fn user_from_uid() -> Option<String> {
+    use std::ffi::CStr;
+
+    // SAFETY: getuid cannot fail and has no preconditions
+    let uid = unsafe { libc::getuid() };
+    // SAFETY: passwd is plain data, for which all zeroes is a valid value
+    let mut passwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut buf = vec![0 as libc::c_char; 16 * 1024];
+    let mut found: *mut libc::passwd = std::ptr::null_mut();
+    // SAFETY: every pointer refers to live storage of the given size, and
+    // the strings in `passwd` point into `buf`, which outlives their use
+    let status =
+        unsafe { libc::getpwuid_r(uid, &mut passwd, buf.as_mut_ptr(), buf.len(), &mut found) };
+    let name = (status == 0 && !found.is_null() && !passwd.pw_name.is_null())
+        .then(|| {
+            unsafe { CStr::from_ptr(passwd.pw_name) }
+                .to_string_lossy()
+                .into_owned()
+        })
+        .filter(|name| !name.is_empty());
+    Some(name.unwrap_or_else(|| format!("uid {}", uid)))
+}
+
+#[cfg(not(unix))]
+fn user_from_uid() -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_append_and_read_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("nested/log.jsonl");
+
+        let mut install = AuditEntry::new("install");
+        install.skill = Some("s".to_string());
+        install.agents = vec!["claude-code".to_string()];
+        append_entry(&path, &install).unwrap();
+
+        let mut failed = AuditEntry::new("remove");
+        failed.outcome = Outcome::Failure;
+        failed.error = Some("boom".to_string());
+        append_entry(&path, &failed).unwrap();
+
+        let entries = read_entries(&path).unwrap();
+        assert_eq!(entries, vec![install, failed]);
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+    }
+
+    #[test]
+    fn test_read_entries_skips_bad_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("log.jsonl");
+        assert!(read_entries(&path).unwrap().is_empty());
+
+        std::fs::write(
+            &path,
+            "not json\n{\"timestamp\":\"2024-05-01T00:00:00Z\",\"user\":\"u\",\"command\":\"gc\",\"outcome\":\"success\"}\n",
+        )
+        .unwrap();
+
+        let entries = read_entries(&path).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].command, "gc");
+        assert_eq!(entries[0].timestamp_secs(), Some(1_714_521_600));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_user_from_uid() {
+        let name = user_from_uid().unwrap();
+        assert!(!name.is_empty());
+        assert_ne!(name, "unknown");
+    }
+}
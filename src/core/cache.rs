@@ -3,31 +3,55 @@
 //! This module provides a caching layer to avoid repeated filesystem scanning
 //! by storing scan results with metadata (mtime, size, content hash) for
 //! invalidation.
+//!
+//! A valid entry carries the full parsed `SkillMetadata`, so a cache hit is
+//! answered without reading SKILL.md. Entries are checked against a
+//! [`SkillStamp`] on every lookup: the mtime and size of SKILL.md plus the
+//! mtime of the skill directory, which changes when files are added, removed
+//! or replaced by an atomic rename.
+//!
+//! Two backends implement [`Cache`]: [`JsonCache`], a single JSON file that
+//! is rewritten on every update, and `SqliteCache` (behind the `sqlite`
+//! feature), which updates rows in place and is safe to share between
+//! processes. The `[cache]` section of the config selects one; see
+//! [`open_cache`].
 
+use crate::core::skill::SkillMetadata;
 use crate::core::SikilError;
-use crate::utils::paths::{ensure_dir_exists, get_cache_path};
+use crate::utils::atomic::atomic_write;
+use crate::utils::paths::{ensure_dir_exists, get_cache_path, get_sqlite_cache_path};
 use fs_err as fs;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use std::time::SystemTime;
 
 /// Cache file format version.
 /// Increment this when the format changes to trigger full cache clear.
-const CACHE_VERSION: u32 = 1;
+const CACHE_VERSION: u32 = 4;
 
 /// Maximum size of content hash to store (SHA256 = 64 hex chars)
-const MAX_HASH_SIZE: usize = 64;
+pub(crate) const MAX_HASH_SIZE: usize = 64;
 
 /// Maximum cache file size (15 MB) - exceeding triggers full clear
 const MAX_CACHE_SIZE: u64 = 15 * 1024 * 1024;
 
 /// Trait defining cache operations for skill scan results.
-pub trait Cache {
+pub trait Cache: Send + Sync {
+    /// Returns the path of the file backing the cache.
+    fn path(&self) -> &Path;
+
+    /// Inspects the cache without modifying it.
+    ///
+    /// Unlike the other operations, which silently discard an unreadable
+    /// cache, this reports why it would be discarded.
+    fn check(&self) -> CacheHealth;
+
     /// Get cached scan entry for a directory path.
     ///
-    /// Returns `None` if not cached or if the entry is invalid due to
-    /// mtime mismatch.
+    /// Returns `None` if not cached or if the entry no longer matches the
+    /// directory's current [`SkillStamp`].
     fn get(&self, path: &Path) -> Result<Option<ScanEntry>, SikilError>;
 
     /// Put a scan entry into the cache.
@@ -35,6 +59,32 @@ pub trait Cache {
     /// Replaces any existing entry for the same path.
     fn put(&self, entry: &ScanEntry) -> Result<(), SikilError>;
 
+    /// Put several scan entries into the cache at once.
+    ///
+    /// Implementations that rewrite a whole file should override this to
+    /// write once instead of once per entry.
+    fn put_all(&self, entries: &[ScanEntry]) -> Result<(), SikilError> {
+        for entry in entries {
+            self.put(entry)?;
+        }
+        Ok(())
+    }
+
+    /// Returns every cached entry without checking it against the filesystem.
+    fn entries(&self) -> Result<Vec<ScanEntry>, SikilError>;
+
+    /// Returns the paths of cached entries whose SKILL.md no longer exists.
+    ///
+    /// These are the entries `clean_stale` would remove.
+    fn stale_paths(&self) -> Vec<PathBuf> {
+        self.entries()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|entry| entry.path)
+            .filter(|path| !path.join("SKILL.md").exists())
+            .collect()
+    }
+
     /// Invalidate a cached entry by path.
     fn invalidate(&self, path: &Path) -> Result<(), SikilError>;
 
@@ -47,18 +97,128 @@ pub trait Cache {
     fn clear(&self) -> Result<(), SikilError>;
 }
 
+/// Storage backend for the scan cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheBackend {
+    /// Single JSON file at `~/.sikil/cache.json`
+    #[default]
+    Json,
+    /// SQLite database at `~/.sikil/cache.db`
+    Sqlite,
+}
+
+impl CacheBackend {
+    /// Returns the default location of this backend's cache file.
+    pub fn default_path(self) -> PathBuf {
+        match self {
+            CacheBackend::Json => get_cache_path(),
+            CacheBackend::Sqlite => get_sqlite_cache_path(),
+        }
+    }
+}
+
+/// Scan cache settings (`[cache]` section of the config)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Which backend stores the cache
+    #[serde(default)]
+    pub backend: CacheBackend,
+}
+
+impl CacheConfig {
+    /// Returns true if every setting has its default value
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Opens the configured cache at its default location.
+///
+/// Opening the SQLite backend imports and removes a leftover JSON cache
+/// file, so switching backends keeps existing entries.
+///
+/// # Errors
+///
+/// Returns an error if the cache cannot be opened, or if the SQLite backend
+/// is selected in a build without the `sqlite` feature.
+pub fn open_cache(config: &CacheConfig) -> Result<Box<dyn Cache>, SikilError> {
+    match config.backend {
+        CacheBackend::Json => Ok(Box::new(JsonCache::open()?)),
+        #[cfg(feature = "sqlite")]
+        CacheBackend::Sqlite => {
+            let cache = crate::core::sqlite_cache::SqliteCache::open()?;
+            // A failed migration only costs a rescan
+            let _ = cache.migrate_from_json(&get_cache_path());
+            Ok(Box::new(cache))
+        }
+        #[cfg(not(feature = "sqlite"))]
+        CacheBackend::Sqlite => Err(sqlite_unavailable()),
+    }
+}
+
+/// Opens a cache of the given backend at a specific path.
+///
+/// # Errors
+///
+/// Returns an error if the cache cannot be opened, or if the SQLite backend
+/// is selected in a build without the `sqlite` feature.
+pub fn open_cache_at(backend: CacheBackend, path: &Path) -> Result<Box<dyn Cache>, SikilError> {
+    match backend {
+        CacheBackend::Json => Ok(Box::new(JsonCache::open_at(path)?)),
+        #[cfg(feature = "sqlite")]
+        CacheBackend::Sqlite => Ok(Box::new(crate::core::sqlite_cache::SqliteCache::open_at(
+            path,
+        )?)),
+        #[cfg(not(feature = "sqlite"))]
+        CacheBackend::Sqlite => Err(sqlite_unavailable()),
+    }
+}
+
+/// Inspects a cache file of the given backend without opening it for use.
+///
+/// Unlike [`open_cache_at`], this never creates, rebuilds or migrates the
+/// file, so it is safe for read-only health checks.
+pub fn check_cache_at(backend: CacheBackend, path: &Path) -> CacheHealth {
+    match backend {
+        CacheBackend::Json => match JsonCache::open_at(path) {
+            Ok(cache) => cache.check(),
+            Err(e) => CacheHealth::Corrupt {
+                reason: e.to_string(),
+            },
+        },
+        #[cfg(feature = "sqlite")]
+        CacheBackend::Sqlite => crate::core::sqlite_cache::SqliteCache::check_at(path),
+        #[cfg(not(feature = "sqlite"))]
+        CacheBackend::Sqlite => CacheHealth::Corrupt {
+            reason: sqlite_unavailable().to_string(),
+        },
+    }
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn sqlite_unavailable() -> SikilError {
+    SikilError::ConfigError {
+        reason: "this build of sikil does not include the sqlite cache backend".to_string(),
+    }
+}
+
 /// A cached scan entry for a skill directory.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScanEntry {
     /// Absolute path to the directory that was scanned
     pub path: PathBuf,
 
-    /// Last modification time of SKILL.md (for invalidation)
+    /// Last modification time of SKILL.md in unix nanoseconds (for invalidation)
     pub mtime: u64,
 
     /// Size of SKILL.md in bytes
     pub size: u64,
 
+    /// Last modification time of the skill directory in unix nanoseconds
+    pub dir_mtime: u64,
+
     /// Hash of the directory contents (for content-based invalidation)
     pub content_hash: String,
 
@@ -70,6 +230,127 @@ pub struct ScanEntry {
 
     /// Whether the path contains a valid skill
     pub is_valid_skill: bool,
+
+    /// Full parsed metadata, including extra frontmatter fields (valid skills only)
+    pub metadata: Option<SkillMetadata>,
+}
+
+impl ScanEntry {
+    /// Returns the change signals this entry was cached with.
+    pub fn stamp(&self) -> SkillStamp {
+        SkillStamp {
+            mtime: self.mtime,
+            size: self.size,
+            dir_mtime: self.dir_mtime,
+        }
+    }
+
+    /// Returns true if SKILL.md still exists and neither it nor the
+    /// directory changed since this entry was cached.
+    pub fn is_fresh(&self) -> bool {
+        matches!(SkillStamp::read(&self.path), Ok(stamp) if stamp == self.stamp())
+    }
+}
+
+/// Change signals of a skill directory, compared on every cache lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillStamp {
+    /// Last modification time of SKILL.md in unix nanoseconds
+    pub mtime: u64,
+    /// Size of SKILL.md in bytes
+    pub size: u64,
+    /// Last modification time of the skill directory in unix nanoseconds
+    pub dir_mtime: u64,
+}
+
+impl SkillStamp {
+    /// Reads the current stamp of a skill directory, following symlinks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or its SKILL.md cannot be read.
+    pub fn read(path: &Path) -> Result<Self, SikilError> {
+        let skill_md =
+            fs::metadata(path.join("SKILL.md")).map_err(|_| SikilError::DirectoryNotFound {
+                path: path.to_path_buf(),
+            })?;
+        let dir = fs::metadata(path).map_err(|_| SikilError::DirectoryNotFound {
+            path: path.to_path_buf(),
+        })?;
+
+        Ok(Self {
+            mtime: unix_nanos(&skill_md, path)?,
+            size: skill_md.len(),
+            dir_mtime: unix_nanos(&dir, path)?,
+        })
+    }
+}
+
+/// Modification time of a file in unix nanoseconds.
+fn unix_nanos(metadata: &std::fs::Metadata, path: &Path) -> Result<u64, SikilError> {
+    let modified = metadata.modified().map_err(|_| SikilError::ConfigError {
+        reason: format!("unable to get mtime for {}", path.display()),
+    })?;
+
+    let duration_since_epoch = modified
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_err(|_| SikilError::ConfigError {
+            reason: format!("mtime before unix epoch for {}", path.display()),
+        })?;
+
+    Ok(duration_since_epoch.as_nanos() as u64)
+}
+
+/// Name of the file holding [`CacheRunStats`], next to the cache file
+const RUN_STATS_FILE: &str = "cache-stats.json";
+
+/// Cache hits and misses of the most recent scan
+///
+/// The scanner records these next to the cache file after each scan so that
+/// `sikil cache stats` can report the hit rate of the last run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheRunStats {
+    /// Skills answered from the cache
+    pub hits: usize,
+    /// Skills that had to be parsed
+    pub misses: usize,
+    /// When the scan finished (unix seconds)
+    pub recorded_at: u64,
+}
+
+impl CacheRunStats {
+    /// Returns the fraction of lookups answered from the cache, if any were made.
+    pub fn hit_rate(&self) -> Option<f64> {
+        let total = self.hits + self.misses;
+        (total > 0).then(|| self.hits as f64 / total as f64)
+    }
+
+    /// Loads the stats recorded for the cache at `cache_path`, if any.
+    pub fn load(cache_path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(Self::path_for(cache_path)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Records these stats for the cache at `cache_path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stats file cannot be written.
+    pub fn save(&self, cache_path: &Path) -> Result<(), SikilError> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| SikilError::ConfigError {
+            reason: format!("failed to serialize cache stats: {}", e),
+        })?;
+        atomic_write(&Self::path_for(cache_path), json.as_bytes())
+    }
+
+    /// Removes the stats recorded for the cache at `cache_path`.
+    pub fn remove(cache_path: &Path) {
+        let _ = fs::remove_file(Self::path_for(cache_path));
+    }
+
+    fn path_for(cache_path: &Path) -> PathBuf {
+        cache_path.with_file_name(RUN_STATS_FILE)
+    }
 }
 
 /// JSON cache file structure.
@@ -84,10 +365,12 @@ struct CacheFile {
 /// Entry stored in JSON cache (path is the key, not in the struct)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CachedEntry {
-    /// Last modification time of SKILL.md (unix seconds)
+    /// Last modification time of SKILL.md (unix nanoseconds)
     mtime: u64,
     /// Size of SKILL.md in bytes
     size: u64,
+    /// Last modification time of the skill directory (unix nanoseconds)
+    dir_mtime: u64,
     /// SHA256 hash of SKILL.md content
     content_hash: String,
     /// Timestamp when entry was cached (unix seconds)
@@ -96,6 +379,9 @@ struct CachedEntry {
     skill_name: Option<String>,
     /// Whether the path contains a valid skill
     is_valid_skill: bool,
+    /// Full parsed metadata (valid skills only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    metadata: Option<SkillMetadata>,
 }
 
 impl From<&ScanEntry> for CachedEntry {
@@ -103,10 +389,12 @@ impl From<&ScanEntry> for CachedEntry {
         Self {
             mtime: entry.mtime,
             size: entry.size,
+            dir_mtime: entry.dir_mtime,
             content_hash: entry.content_hash.clone(),
             cached_at: entry.cached_at,
             skill_name: entry.skill_name.clone(),
             is_valid_skill: entry.is_valid_skill,
+            metadata: entry.metadata.clone(),
         }
     }
 }
@@ -117,10 +405,12 @@ impl CachedEntry {
             path,
             mtime: self.mtime,
             size: self.size,
+            dir_mtime: self.dir_mtime,
             content_hash: self.content_hash.clone(),
             cached_at: self.cached_at,
             skill_name: self.skill_name.clone(),
             is_valid_skill: self.is_valid_skill,
+            metadata: self.metadata.clone(),
         }
     }
 }
@@ -135,9 +425,49 @@ impl CacheFile {
     }
 }
 
+/// Health of a cache file on disk, as reported by [`Cache::check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheHealth {
+    /// No cache file has been written yet
+    Missing,
+    /// The cache file is readable and uses the current format
+    Healthy {
+        /// Number of cached entries
+        entries: usize,
+    },
+    /// The cache file exceeds the size limit and will be cleared on next write
+    TooLarge {
+        /// Size of the cache file in bytes
+        size: u64,
+    },
+    /// The cache file was written with a different format version
+    VersionMismatch {
+        /// Version found in the file
+        found: u32,
+    },
+    /// The cache file cannot be read or parsed
+    Corrupt {
+        /// Why the file could not be loaded
+        reason: String,
+    },
+}
+
 /// JSON-based cache implementation.
+///
+/// The parsed cache file is kept in memory between lookups and reloaded when
+/// the file on disk changes, so a scan parses the JSON once rather than once
+/// per skill.
 pub struct JsonCache {
     cache_path: PathBuf,
+    /// Last loaded cache file, with the length and mtime it was loaded at
+    loaded: Mutex<Option<LoadedFile>>,
+}
+
+/// A cache file as loaded from disk
+struct LoadedFile {
+    len: u64,
+    modified: Option<SystemTime>,
+    file: Arc<CacheFile>,
 }
 
 impl JsonCache {
@@ -158,11 +488,19 @@ impl JsonCache {
 
         Ok(Self {
             cache_path: path.to_path_buf(),
+            loaded: Mutex::new(None),
         })
     }
 
-    /// Load the cache file, returning None if it doesn't exist or on error.
+    /// Load the cache file for modification, returning None if it doesn't
+    /// exist or on error.
     fn load(&self) -> Option<CacheFile> {
+        self.load_shared()
+            .map(|shared| Arc::try_unwrap(shared).unwrap_or_else(|shared| (*shared).clone()))
+    }
+
+    /// Load the cache file, reusing the last parse if the file is unchanged.
+    fn load_shared(&self) -> Option<Arc<CacheFile>> {
         // Check file size first
         let metadata = fs::metadata(&self.cache_path).ok()?;
         if metadata.len() > MAX_CACHE_SIZE {
@@ -170,6 +508,14 @@ impl JsonCache {
             return None;
         }
 
+        let modified = metadata.modified().ok();
+        let mut loaded = self.loaded.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(ref file) = *loaded {
+            if file.len == metadata.len() && file.modified == modified && modified.is_some() {
+                return Some(Arc::clone(&file.file));
+            }
+        }
+
         let content = fs::read_to_string(&self.cache_path).ok()?;
         let cache_file: CacheFile = serde_json::from_str(&content).ok()?;
 
@@ -178,7 +524,13 @@ impl JsonCache {
             return None;
         }
 
-        Some(cache_file)
+        let file = Arc::new(cache_file);
+        *loaded = Some(LoadedFile {
+            len: metadata.len(),
+            modified,
+            file: Arc::clone(&file),
+        });
+        Some(file)
     }
 
     /// Write cache file atomically (temp file + rename).
@@ -220,40 +572,86 @@ impl JsonCache {
             }
         })?;
 
+        // Drop the in-memory copy so the next lookup sees this write even if
+        // the file's length and mtime happen to be unchanged
+        *self.loaded.lock().unwrap_or_else(|e| e.into_inner()) = None;
+
         Ok(())
     }
+}
 
-    /// Get the mtime for a SKILL.md file at the given path.
-    fn get_skill_mtime(path: &Path) -> Result<u64, SikilError> {
-        let skill_md_path = path.join("SKILL.md");
-        let metadata =
-            fs::metadata(&skill_md_path).map_err(|_e| SikilError::DirectoryNotFound {
-                path: path.to_path_buf(),
-            })?;
+impl Cache for JsonCache {
+    fn path(&self) -> &Path {
+        &self.cache_path
+    }
 
-        let modified = metadata.modified().map_err(|_| SikilError::ConfigError {
-            reason: format!("unable to get mtime for {}", path.display()),
-        })?;
+    fn check(&self) -> CacheHealth {
+        let metadata = match fs::metadata(&self.cache_path) {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return CacheHealth::Missing,
+            Err(e) => {
+                return CacheHealth::Corrupt {
+                    reason: e.to_string(),
+                }
+            }
+        };
 
-        let duration_since_epoch =
-            modified
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .map_err(|_| SikilError::ConfigError {
-                    reason: format!("mtime before unix epoch for {}", path.display()),
-                })?;
+        if metadata.len() > MAX_CACHE_SIZE {
+            return CacheHealth::TooLarge {
+                size: metadata.len(),
+            };
+        }
+
+        let content = match fs::read_to_string(&self.cache_path) {
+            Ok(content) => content,
+            Err(e) => {
+                return CacheHealth::Corrupt {
+                    reason: e.to_string(),
+                }
+            }
+        };
+
+        // Check the version before the full structure so that files from other
+        // format versions are reported as such rather than as corrupt
+        let value: serde_json::Value = match serde_json::from_str(&content) {
+            Ok(value) => value,
+            Err(e) => {
+                return CacheHealth::Corrupt {
+                    reason: format!("invalid JSON: {}", e),
+                }
+            }
+        };
+        match value.get("version").and_then(|v| v.as_u64()) {
+            Some(version) if version == u64::from(CACHE_VERSION) => {}
+            Some(version) => {
+                return CacheHealth::VersionMismatch {
+                    found: version as u32,
+                }
+            }
+            None => {
+                return CacheHealth::Corrupt {
+                    reason: "missing format version".to_string(),
+                }
+            }
+        }
 
-        Ok(duration_since_epoch.as_secs())
+        match serde_json::from_value::<CacheFile>(value) {
+            Ok(cache_file) => CacheHealth::Healthy {
+                entries: cache_file.entries.len(),
+            },
+            Err(e) => CacheHealth::Corrupt {
+                reason: format!("unexpected structure: {}", e),
+            },
+        }
     }
-}
 
-impl Cache for JsonCache {
     fn get(&self, path: &Path) -> Result<Option<ScanEntry>, SikilError> {
         let path_str = path.to_str().ok_or_else(|| SikilError::ConfigError {
             reason: format!("invalid path: {}", path.display()),
         })?;
 
         // Load cache file (non-fatal if it fails)
-        let cache_file = match self.load() {
+        let cache_file = match self.load_shared() {
             Some(file) => file,
             None => return Ok(None),
         };
@@ -264,48 +662,59 @@ impl Cache for JsonCache {
             None => return Ok(None),
         };
 
-        // Check if SKILL.md still exists and mtime matches
-        match Self::get_skill_mtime(path) {
-            Ok(current_mtime) => {
-                if current_mtime != cached_entry.mtime {
-                    // SKILL.md has been modified, return None to trigger rescan
-                    return Ok(None);
-                }
-            }
-            Err(_) => {
-                // SKILL.md no longer exists
-                return Ok(None);
-            }
-        }
-
-        Ok(Some(cached_entry.to_scan_entry(path.to_path_buf())))
+        // Modified or gone, return None to trigger rescan
+        let entry = cached_entry.to_scan_entry(path.to_path_buf());
+        Ok(Some(entry).filter(ScanEntry::is_fresh))
     }
 
     fn put(&self, entry: &ScanEntry) -> Result<(), SikilError> {
-        // Validate content hash length
-        if entry.content_hash.len() > MAX_HASH_SIZE {
-            return Err(SikilError::ValidationError {
-                reason: format!("content hash too long: {}", entry.content_hash.len()),
-            });
-        }
+        self.put_all(std::slice::from_ref(entry))
+    }
 
-        let path_str = entry.path.to_str().ok_or_else(|| SikilError::ConfigError {
-            reason: format!("invalid path: {}", entry.path.display()),
-        })?;
+    fn put_all(&self, entries: &[ScanEntry]) -> Result<(), SikilError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
 
         // Load existing cache or create new
         let mut cache_file = self.load().unwrap_or_else(CacheFile::new);
 
-        // Insert/replace entry
-        cache_file
-            .entries
-            .insert(path_str.to_string(), CachedEntry::from(entry));
+        for entry in entries {
+            // Validate content hash length
+            if entry.content_hash.len() > MAX_HASH_SIZE {
+                return Err(SikilError::ValidationError {
+                    reason: format!("content hash too long: {}", entry.content_hash.len()),
+                });
+            }
+
+            let path_str = entry.path.to_str().ok_or_else(|| SikilError::ConfigError {
+                reason: format!("invalid path: {}", entry.path.display()),
+            })?;
+
+            // Insert/replace entry
+            cache_file
+                .entries
+                .insert(path_str.to_string(), CachedEntry::from(entry));
+        }
 
         // Write (non-fatal on failure)
         let _ = self.write(&cache_file);
         Ok(())
     }
 
+    fn entries(&self) -> Result<Vec<ScanEntry>, SikilError> {
+        Ok(self
+            .load_shared()
+            .map(|cache_file| {
+                cache_file
+                    .entries
+                    .iter()
+                    .map(|(path, entry)| entry.to_scan_entry(PathBuf::from(path)))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
     fn invalidate(&self, path: &Path) -> Result<(), SikilError> {
         let path_str = path.to_str().ok_or_else(|| SikilError::ConfigError {
             reason: format!("invalid path: {}", path.display()),
@@ -370,6 +779,7 @@ mod tests {
             path: PathBuf::from(path),
             mtime,
             size: 1024,
+            dir_mtime: 0,
             content_hash: "abc123".to_string(),
             cached_at: SystemTime::now()
                 .duration_since(SystemTime::UNIX_EPOCH)
@@ -377,6 +787,7 @@ mod tests {
                 .unwrap_or(0),
             skill_name: Some("test-skill".to_string()),
             is_valid_skill: true,
+            metadata: None,
         }
     }
 
@@ -434,6 +845,54 @@ mod tests {
         assert!(loaded.entries.contains_key("/test/skill"));
     }
 
+    #[test]
+    fn test_cache_put_all_writes_every_entry() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = JsonCache::open_at(&temp_dir.path().join("cache.json")).unwrap();
+        cache.put(&create_test_entry("/test/existing", 1)).unwrap();
+
+        let entries: Vec<ScanEntry> = (0..3)
+            .map(|i| create_test_entry(&format!("/test/skill-{}", i), i))
+            .collect();
+        cache.put_all(&entries).unwrap();
+
+        let loaded = cache.load().unwrap();
+        assert_eq!(loaded.entries.len(), 4);
+        assert!(loaded.entries.contains_key("/test/existing"));
+        assert!(loaded.entries.contains_key("/test/skill-2"));
+    }
+
+    #[test]
+    fn test_cache_stores_full_metadata() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("meta-skill");
+        fs::create_dir(&skill_dir).unwrap();
+        fs::write(skill_dir.join("SKILL.md"), "# Test").unwrap();
+        let cache = JsonCache::open_at(&temp_dir.path().join("cache.json")).unwrap();
+
+        let stamp = SkillStamp::read(&skill_dir).unwrap();
+        let mut entry = create_test_entry(skill_dir.to_str().unwrap(), stamp.mtime);
+        entry.size = stamp.size;
+        entry.dir_mtime = stamp.dir_mtime;
+        entry.metadata = Some(
+            SkillMetadata::new("meta-skill".to_string(), "Has extras".to_string())
+                .with_homepage("https://example.com".to_string())
+                .with_extra(
+                    "tags".to_string(),
+                    serde_yaml::from_str("[git, review]").unwrap(),
+                ),
+        );
+        cache.put(&entry).unwrap();
+
+        let cached = cache.get(&skill_dir).unwrap().unwrap();
+        let metadata = cached.metadata.unwrap();
+        assert_eq!(metadata.homepage, Some("https://example.com".to_string()));
+        assert_eq!(
+            metadata.extra["tags"],
+            serde_yaml::from_str::<serde_yaml::Value>("[git, review]").unwrap()
+        );
+    }
+
     #[test]
     fn test_cache_get_returns_none_for_nonexistent() {
         let temp_dir = TempDir::new().unwrap();
@@ -521,6 +980,7 @@ mod tests {
             path: skill_dir.clone(),
             mtime: 1234567890,
             size: 1024,
+            dir_mtime: 0,
             content_hash: "existing".to_string(),
             cached_at: SystemTime::now()
                 .duration_since(SystemTime::UNIX_EPOCH)
@@ -528,6 +988,7 @@ mod tests {
                 .unwrap_or(0),
             skill_name: Some("existing-skill".to_string()),
             is_valid_skill: true,
+            metadata: None,
         };
         cache.put(&existing_entry).unwrap();
 
@@ -535,9 +996,16 @@ mod tests {
         let stale_entry = create_test_entry("/nonexistent/path/that/does/not/exist", 1234567890);
         cache.put(&stale_entry).unwrap();
 
+        // stale_paths reports what clean_stale will remove
+        assert_eq!(
+            cache.stale_paths(),
+            vec![PathBuf::from("/nonexistent/path/that/does/not/exist")]
+        );
+
         // Clean stale entries
         let removed = cache.clean_stale().unwrap();
         assert_eq!(removed, 1);
+        assert!(cache.stale_paths().is_empty());
 
         // Verify stale entry is removed
         let loaded = cache.load().unwrap();
@@ -643,7 +1111,8 @@ mod tests {
         // This ensures the file size exceeds MAX_CACHE_SIZE
         let padding_size = MAX_CACHE_SIZE as usize + 1;
         let huge_content = format!(
-            r#"{{"version": 1, "entries": {{"/test/skill": {{"mtime": 1234567890, "size": 1024, "content_hash": "{}", "cached_at": 1234567890, "skill_name": null, "is_valid_skill": true}}}}}}"#,
+            r#"{{"version": {}, "entries": {{"/test/skill": {{"mtime": 1234567890, "size": 1024, "content_hash": "{}", "cached_at": 1234567890, "skill_name": null, "is_valid_skill": true}}}}}}"#,
+            CACHE_VERSION,
             "a".repeat(padding_size)
         );
         fs::write(&cache_path, &huge_content).unwrap();
@@ -723,14 +1192,15 @@ mod tests {
         let skill_md_path = skill_dir.join("SKILL.md");
         fs::write(&skill_md_path, "# Test").unwrap();
 
-        // Get actual mtime
-        let actual_mtime = JsonCache::get_skill_mtime(&skill_dir).unwrap();
+        // Get actual stamp
+        let stamp = SkillStamp::read(&skill_dir).unwrap();
 
         // Cache entry with wrong mtime
         let entry = ScanEntry {
             path: skill_dir.clone(),
-            mtime: actual_mtime - 1000, // Wrong mtime
-            size: 1024,
+            mtime: stamp.mtime - 1000, // Wrong mtime
+            size: stamp.size,
+            dir_mtime: stamp.dir_mtime,
             content_hash: "abc123".to_string(),
             cached_at: SystemTime::now()
                 .duration_since(SystemTime::UNIX_EPOCH)
@@ -738,6 +1208,7 @@ mod tests {
                 .unwrap_or(0),
             skill_name: Some("test-skill".to_string()),
             is_valid_skill: true,
+            metadata: None,
         };
         cache.put(&entry).unwrap();
 
@@ -746,6 +1217,49 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_cache_get_checks_size_and_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = JsonCache::open_at(&temp_dir.path().join("cache.json")).unwrap();
+        let skill_dir = temp_dir.path().join("test-skill");
+        fs::create_dir(&skill_dir).unwrap();
+        fs::write(skill_dir.join("SKILL.md"), "# Test").unwrap();
+
+        let stamp = SkillStamp::read(&skill_dir).unwrap();
+        let mut entry = create_test_entry(skill_dir.to_str().unwrap(), stamp.mtime);
+        entry.size = stamp.size;
+        entry.dir_mtime = stamp.dir_mtime;
+        cache.put(&entry).unwrap();
+        assert!(cache.get(&skill_dir).unwrap().is_some());
+
+        // Same SKILL.md mtime, different size
+        let mut resized = entry.clone();
+        resized.size += 1;
+        cache.put(&resized).unwrap();
+        assert!(cache.get(&skill_dir).unwrap().is_none());
+
+        // SKILL.md untouched, but a file was added to the directory
+        cache.put(&entry).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        fs::write(skill_dir.join("notes.md"), "new").unwrap();
+        assert_ne!(SkillStamp::read(&skill_dir).unwrap(), stamp);
+        assert!(cache.get(&skill_dir).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_cache_reloads_file_changed_by_another_handle() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_path = temp_dir.path().join("cache.json");
+        let reader = JsonCache::open_at(&cache_path).unwrap();
+        let writer = JsonCache::open_at(&cache_path).unwrap();
+
+        writer.put(&create_test_entry("/test/a", 1)).unwrap();
+        assert_eq!(reader.load_shared().unwrap().entries.len(), 1);
+
+        writer.put(&create_test_entry("/test/b", 2)).unwrap();
+        assert_eq!(reader.load_shared().unwrap().entries.len(), 2);
+    }
+
     #[test]
     fn test_cache_file_pretty_printed() {
         let temp_dir = TempDir::new().unwrap();
@@ -760,4 +1274,40 @@ mod tests {
         assert!(content.contains('\n'));
         assert!(content.contains("  ")); // Indentation
     }
+
+    #[test]
+    fn test_cache_check_missing() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = JsonCache::open_at(&temp_dir.path().join("cache.json")).unwrap();
+        assert_eq!(cache.check(), CacheHealth::Missing);
+    }
+
+    #[test]
+    fn test_cache_check_healthy() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = JsonCache::open_at(&temp_dir.path().join("cache.json")).unwrap();
+        cache.put(&create_test_entry("/test/skill", 1)).unwrap();
+
+        assert_eq!(cache.check(), CacheHealth::Healthy { entries: 1 });
+    }
+
+    #[test]
+    fn test_cache_check_corrupt() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_path = temp_dir.path().join("cache.json");
+        fs::write(&cache_path, "{ not json").unwrap();
+
+        let cache = JsonCache::open_at(&cache_path).unwrap();
+        assert!(matches!(cache.check(), CacheHealth::Corrupt { .. }));
+    }
+
+    #[test]
+    fn test_cache_check_version_mismatch() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_path = temp_dir.path().join("cache.json");
+        fs::write(&cache_path, r#"{"version": 999, "entries": {}}"#).unwrap();
+
+        let cache = JsonCache::open_at(&cache_path).unwrap();
+        assert_eq!(cache.check(), CacheHealth::VersionMismatch { found: 999 });
+    }
 }
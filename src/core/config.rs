@@ -1,4 +1,7 @@
+use crate::core::cache::CacheConfig;
 use crate::core::errors::ConfigError;
+use crate::core::rules::LintConfig;
+use crate::core::trash::TrashConfig;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
@@ -33,6 +36,15 @@ impl AgentConfig {
 pub struct Config {
     /// Agent configurations indexed by agent name
     pub agents: HashMap<String, AgentConfig>,
+    /// Validation rule severity overrides (`[lint.rules]`)
+    #[serde(default, skip_serializing_if = "LintConfig::is_empty")]
+    pub lint: LintConfig,
+    /// Scan cache settings (`[cache]`)
+    #[serde(default, skip_serializing_if = "CacheConfig::is_default")]
+    pub cache: CacheConfig,
+    /// Trash settings (`[trash]`)
+    #[serde(default, skip_serializing_if = "TrashConfig::is_default")]
+    pub trash: TrashConfig,
 }
 
 impl Config {
@@ -40,6 +52,9 @@ impl Config {
     pub fn new() -> Self {
         Self {
             agents: HashMap::new(),
+            lint: LintConfig::default(),
+            cache: CacheConfig::default(),
+            trash: TrashConfig::default(),
         }
     }
 
@@ -154,7 +169,12 @@ impl Default for Config {
             },
         );
 
-        Self { agents }
+        Self {
+            agents,
+            lint: LintConfig::default(),
+            cache: CacheConfig::default(),
+            trash: TrashConfig::default(),
+        }
     }
 }
 
@@ -303,6 +323,73 @@ workspace_path = ".test/workspace"
         assert_eq!(agent.unwrap().global_path, PathBuf::from("/test/global"));
     }
 
+    #[test]
+    fn test_config_load_lint_rules() {
+        let temp_file = tempfile::NamedTempFile::new().expect("Failed to create temp file");
+        let temp_path = temp_file.path();
+
+        let toml_content = r#"
+[agents.test-agent]
+enabled = true
+global_path = "/test/global"
+workspace_path = ".test/workspace"
+
+[lint.rules]
+SK006 = "error"
+SK008 = "off"
+"#;
+
+        std::fs::write(temp_path, toml_content).expect("Failed to write temp file");
+
+        let config = Config::load(temp_path).unwrap();
+        assert_eq!(
+            config.lint.rules.get("SK006"),
+            Some(&crate::core::rules::Severity::Error)
+        );
+        assert_eq!(
+            config.lint.rules.get("SK008"),
+            Some(&crate::core::rules::Severity::Off)
+        );
+    }
+
+    #[test]
+    fn test_config_load_cache_backend() {
+        let temp_file = tempfile::NamedTempFile::new().expect("Failed to create temp file");
+        let temp_path = temp_file.path();
+
+        std::fs::write(temp_path, "agents = {}\n\n[cache]\nbackend = \"sqlite\"\n")
+            .expect("Failed to write temp file");
+        let config = Config::load(temp_path).unwrap();
+        assert_eq!(
+            config.cache.backend,
+            crate::core::cache::CacheBackend::Sqlite
+        );
+
+        std::fs::write(temp_path, "agents = {}\n\n[cache]\nbackend = \"redis\"\n")
+            .expect("Failed to write temp file");
+        assert!(Config::load(temp_path).is_err());
+
+        assert_eq!(
+            Config::new().cache.backend,
+            crate::core::cache::CacheBackend::Json
+        );
+    }
+
+    #[test]
+    fn test_config_load_trash_retention() {
+        let temp_file = tempfile::NamedTempFile::new().expect("Failed to create temp file");
+        let temp_path = temp_file.path();
+
+        std::fs::write(temp_path, "agents = {}\n\n[trash]\nretention_days = 7\n")
+            .expect("Failed to write temp file");
+        assert_eq!(Config::load(temp_path).unwrap().trash.retention_days, 7);
+
+        assert_eq!(
+            Config::new().trash.retention_days,
+            crate::core::trash::DEFAULT_RETENTION_DAYS
+        );
+    }
+
     #[test]
     fn test_config_load_partial_merges_with_defaults() {
         let temp_file = tempfile::NamedTempFile::new().expect("Failed to create temp file");
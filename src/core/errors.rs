@@ -10,8 +10,15 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum SikilError {
     /// SKILL.md file is invalid or malformed
-    #[error("Invalid SKILL.md in {path}: {reason}")]
-    InvalidSkillMd { path: PathBuf, reason: String },
+    ///
+    /// `line` and `column` are 1-based positions in the file, when known.
+    #[error("Invalid SKILL.md in {path}{}: {reason}", format_location(*.line, *.column))]
+    InvalidSkillMd {
+        path: PathBuf,
+        reason: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
 
     /// A skill could not be found
     #[error("Skill not found: {name}")]
@@ -64,6 +71,27 @@ pub enum SikilError {
     /// Configuration file exceeds maximum size
     #[error("Configuration file too large: {size} bytes (maximum 1048576 bytes)")]
     ConfigTooLarge { size: u64 },
+
+    /// Another sikil process held the lock for too long
+    #[error("Timed out waiting for {}{}", format_holder(*.pid), .path.display())]
+    LockTimeout { path: PathBuf, pid: Option<u32> },
+}
+
+/// Describes the process holding the lock for `LockTimeout`
+fn format_holder(pid: Option<u32>) -> String {
+    match pid {
+        Some(pid) => format!("another sikil process (PID {}) to release ", pid),
+        None => "another sikil process to release ".to_string(),
+    }
+}
+
+/// Formats an optional file position as a `:line:column` suffix
+fn format_location(line: Option<usize>, column: Option<usize>) -> String {
+    match (line, column) {
+        (Some(line), Some(column)) => format!(":{}:{}", line, column),
+        (Some(line), None) => format!(":{}", line),
+        _ => String::new(),
+    }
 }
 
 impl SikilError {
@@ -122,6 +150,8 @@ mod tests {
         let err = SikilError::InvalidSkillMd {
             path: PathBuf::from("/test/skill/SKILL.md"),
             reason: "missing required field 'name'".to_string(),
+            line: None,
+            column: None,
         };
         assert_eq!(
             err.to_string(),
@@ -129,6 +159,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_display_invalid_skill_md_with_location() {
+        let err = SikilError::InvalidSkillMd {
+            path: PathBuf::from("/test/skill/SKILL.md"),
+            reason: "failed to parse YAML: did not find expected key".to_string(),
+            line: Some(4),
+            column: Some(7),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Invalid SKILL.md in /test/skill/SKILL.md:4:7: failed to parse YAML: did not find expected key"
+        );
+    }
+
     #[test]
     fn test_error_display_skill_not_found() {
         let err = SikilError::SkillNotFound {
@@ -201,6 +245,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_display_lock_timeout() {
+        let err = SikilError::LockTimeout {
+            path: PathBuf::from("/home/user/.sikil/sikil.lock"),
+            pid: Some(4242),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Timed out waiting for another sikil process (PID 4242) to release /home/user/.sikil/sikil.lock"
+        );
+    }
+
     #[test]
     fn test_error_display_path_traversal() {
         let err = SikilError::PathTraversal {
@@ -260,6 +316,8 @@ mod tests {
         let err = SikilError::InvalidSkillMd {
             path: PathBuf::from("/test/SKILL.md"),
             reason: "missing field".to_string(),
+            line: None,
+            column: None,
         };
         assert_eq!(err.exit_code(), 2);
 
@@ -0,0 +1,425 @@
+//! Mechanical fixes for SKILL.md files
+//!
+//! Most validation failures are mechanical: a BOM or CRLF line endings, an
+//! uppercase name, a missing description, or a stray `---` line. This module
+//! rewrites SKILL.md text to fix them. Frontmatter is edited line by line, so
+//! unknown keys, comments, key order and the body are preserved.
+
+use crate::core::frontmatter::{is_delimiter, locate_frontmatter, set_key, yaml_scalar};
+use crate::core::parser::validate_skill_name;
+
+/// Maximum length of a description derived from the body
+const DERIVED_DESCRIPTION_MAX: usize = 200;
+
+/// Options controlling which fixes are applied
+#[derive(Debug, Clone, Default)]
+pub struct FixOptions<'a> {
+    /// Name of the skill directory, used for a missing or invalid name
+    pub directory_name: Option<&'a str>,
+    /// Rename the skill to its directory name when they differ (SK009)
+    pub match_directory: bool,
+}
+
+/// The result of fixing a SKILL.md file
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixOutcome {
+    /// The fixed content (equal to the input if nothing was fixed)
+    pub content: String,
+    /// Human-readable descriptions of the applied fixes, in order
+    pub fixes: Vec<String>,
+}
+
+/// Fixes mechanical problems in SKILL.md content
+///
+/// Applied fixes:
+/// - Removes a byte order mark
+/// - Converts CRLF line endings to LF
+/// - Removes stray `---` lines directly after the frontmatter and at the end of the body
+/// - Adds a missing `name` from the directory name
+/// - Replaces an invalid `name` with the directory name if that is a valid
+///   name, and otherwise normalizes it (lowercase, `-` for invalid characters)
+/// - Renames the skill to its directory name with `match_directory`
+/// - Adds a missing or empty `description` from the first paragraph of the body
+///
+/// Frontmatter fixes are skipped if the frontmatter cannot be located or is
+/// not a valid YAML mapping.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::core::fixer::{fix_skill_md, FixOptions};
+///
+/// let outcome = fix_skill_md("---\r\nname: My_Skill\r\ndescription: d\r\n---\r\n", &FixOptions::default());
+/// assert_eq!(outcome.content, "---\nname: my_skill\ndescription: d\n---\n");
+/// assert_eq!(outcome.fixes.len(), 2);
+/// ```
+pub fn fix_skill_md(content: &str, options: &FixOptions) -> FixOutcome {
+    let mut fixes = Vec::new();
+
+    let mut text = content;
+    if let Some(stripped) = text.strip_prefix('\u{feff}') {
+        fixes.push("Removed byte order mark".to_string());
+        text = stripped;
+    }
+    let mut text = text.to_string();
+    if text.contains("\r\n") {
+        fixes.push("Converted CRLF line endings to LF".to_string());
+        text = text.replace("\r\n", "\n");
+    }
+
+    let trailing_newline = text.ends_with('\n');
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+
+    if let Some((open, close)) = locate_frontmatter(&lines) {
+        fix_stray_delimiters(&mut lines, close, &mut fixes);
+        fix_frontmatter(&mut lines, open, close, options, &mut fixes);
+    }
+
+    let mut content = lines.join("\n");
+    if trailing_newline {
+        content.push('\n');
+    }
+
+    FixOutcome { content, fixes }
+}
+
+/// Removes duplicated closing delimiters and a trailing `---` line in the body
+fn fix_stray_delimiters(lines: &mut Vec<String>, close: usize, fixes: &mut Vec<String>) {
+    loop {
+        let next = lines[close + 1..]
+            .iter()
+            .position(|l| !l.trim().is_empty())
+            .map(|i| i + close + 1);
+        match next {
+            Some(i) if is_delimiter(&lines[i]) => {
+                lines.remove(i);
+                fixes.push(format!(
+                    "Removed stray '---' line {} after the frontmatter",
+                    i + 1
+                ));
+            }
+            _ => break,
+        }
+    }
+
+    if let Some(last) = lines.iter().rposition(|l| !l.trim().is_empty()) {
+        if last > close && is_delimiter(&lines[last]) {
+            lines.remove(last);
+            fixes.push(format!(
+                "Removed trailing '---' line {} from the body",
+                last + 1
+            ));
+        }
+    }
+}
+
+/// Fixes `name` and `description` between the delimiters at `open` and `close`
+fn fix_frontmatter(
+    lines: &mut Vec<String>,
+    open: usize,
+    close: usize,
+    options: &FixOptions,
+    fixes: &mut Vec<String>,
+) {
+    let yaml = lines[open + 1..close].join("\n");
+    let mapping = match serde_yaml::from_str::<serde_yaml::Value>(&yaml) {
+        Ok(serde_yaml::Value::Mapping(m)) => m,
+        Ok(serde_yaml::Value::Null) => serde_yaml::Mapping::new(),
+        _ => return,
+    };
+    // Non-string values (such as nested mappings) are never touched
+    let is_missing = |key: &str| match mapping.get(key) {
+        None | Some(serde_yaml::Value::Null) => true,
+        Some(serde_yaml::Value::String(s)) => s.trim().is_empty(),
+        Some(_) => false,
+    };
+    let get_str = |key: &str| {
+        mapping
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    };
+    let directory_name = options
+        .directory_name
+        .filter(|d| validate_skill_name(d).is_ok());
+
+    let mut frontmatter: Vec<String> = lines[open + 1..close].to_vec();
+
+    // name
+    let current = get_str("name").filter(|n| !n.trim().is_empty());
+    let mut name = current.clone();
+    match current {
+        None if is_missing("name") => {
+            if let Some(dir) = directory_name {
+                set_key(&mut frontmatter, "name", &yaml_scalar(dir), None);
+                fixes.push(format!(
+                    "Added missing 'name: {}' from the directory name",
+                    dir
+                ));
+                name = Some(dir.to_string());
+            }
+        }
+        Some(ref current) if validate_skill_name(current).is_err() => {
+            // The directory name is the best guess at the intended name
+            let normalized = directory_name
+                .map(str::to_string)
+                .unwrap_or_else(|| normalize_name(current));
+            if validate_skill_name(&normalized).is_ok() {
+                set_key(&mut frontmatter, "name", &yaml_scalar(&normalized), None);
+                fixes.push(format!("Normalized name '{}' to '{}'", current, normalized));
+                name = Some(normalized);
+            }
+        }
+        _ => {}
+    }
+    if options.match_directory {
+        if let (Some(dir), Some(current)) = (directory_name, name.as_deref()) {
+            if current != dir {
+                set_key(&mut frontmatter, "name", &yaml_scalar(dir), None);
+                fixes.push(format!(
+                    "Renamed '{}' to '{}' to match the directory",
+                    current, dir
+                ));
+            }
+        }
+    }
+
+    // description
+    if is_missing("description") {
+        let body = lines[close + 1..].join("\n");
+        if let Some(description) = derive_description(&body) {
+            set_key(
+                &mut frontmatter,
+                "description",
+                &yaml_scalar(&description),
+                Some("name"),
+            );
+            fixes.push(
+                "Added missing 'description' from the first paragraph of the body".to_string(),
+            );
+        }
+    }
+
+    lines.splice(open + 1..close, frontmatter);
+}
+
+/// Lowercases a name and replaces characters that are not allowed with `-`
+fn normalize_name(name: &str) -> String {
+    let mut out = String::new();
+    for c in name.trim().chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-' {
+            out.push(c);
+        } else if !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    let out = out.trim_start_matches(['-', '_']).trim_end_matches('-');
+    out.chars()
+        .take(64)
+        .collect::<String>()
+        .trim_end_matches('-')
+        .to_string()
+}
+
+/// Derives a description from the first paragraph of the body
+///
+/// Falls back to the first heading if the body has no paragraph text.
+fn derive_description(body: &str) -> Option<String> {
+    let mut heading = None;
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut in_fence = false;
+
+    for line in body.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence || trimmed.starts_with("<!--") {
+            continue;
+        }
+        if trimmed.is_empty() {
+            if !paragraph.is_empty() {
+                break;
+            }
+            continue;
+        }
+        if let Some(text) = trimmed.strip_prefix('#') {
+            if !paragraph.is_empty() {
+                break;
+            }
+            heading.get_or_insert_with(|| text.trim_start_matches('#').trim().to_string());
+            continue;
+        }
+        paragraph.push(trimmed);
+    }
+
+    let text = if paragraph.is_empty() {
+        heading.filter(|h| !h.is_empty())?
+    } else {
+        paragraph.join(" ")
+    };
+    Some(truncate_words(&text, DERIVED_DESCRIPTION_MAX))
+}
+
+/// Truncates text at a word boundary, appending `...` when shortened
+fn truncate_words(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    for word in text.split_whitespace() {
+        if out.chars().count() + word.chars().count() + 1 > max - 3 {
+            break;
+        }
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(word);
+    }
+    out.push_str("...");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fix(content: &str) -> FixOutcome {
+        fix_skill_md(content, &FixOptions::default())
+    }
+
+    #[test]
+    fn test_valid_content_is_unchanged() {
+        let content = "---\nname: ok\ndescription: d\n---\n\n# Ok\n";
+        let outcome = fix(content);
+        assert_eq!(outcome.content, content);
+        assert!(outcome.fixes.is_empty());
+    }
+
+    #[test]
+    fn test_bom_and_crlf() {
+        let outcome = fix("\u{feff}---\r\nname: ok\r\ndescription: d\r\n---\r\nBody\r\n");
+        assert_eq!(
+            outcome.content,
+            "---\nname: ok\ndescription: d\n---\nBody\n"
+        );
+        assert_eq!(
+            outcome.fixes,
+            vec![
+                "Removed byte order mark",
+                "Converted CRLF line endings to LF"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize_name_preserves_unknown_keys_and_comments() {
+        let content = "---\n# owned by docs team\nname: My Cool.Skill\ndescription: d\ncustom:\n  nested: [1, 2]\n---\nBody\n";
+        let outcome = fix(content);
+        assert_eq!(
+            outcome.content,
+            "---\n# owned by docs team\nname: my-cool-skill\ndescription: d\ncustom:\n  nested: [1, 2]\n---\nBody\n"
+        );
+        assert_eq!(
+            outcome.fixes,
+            vec!["Normalized name 'My Cool.Skill' to 'my-cool-skill'"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_name_is_normalized_to_directory_name() {
+        let options = FixOptions {
+            directory_name: Some("my-fix"),
+            match_directory: false,
+        };
+        let outcome = fix_skill_md("---\nname: My_Fix\ndescription: d\n---\n", &options);
+        assert_eq!(outcome.content, "---\nname: my-fix\ndescription: d\n---\n");
+        assert_eq!(outcome.fixes, vec!["Normalized name 'My_Fix' to 'my-fix'"]);
+
+        // An invalid directory name is not a target
+        let options = FixOptions {
+            directory_name: Some("My Dir"),
+            match_directory: false,
+        };
+        let outcome = fix_skill_md("---\nname: My_Fix\ndescription: d\n---\n", &options);
+        assert_eq!(outcome.content, "---\nname: my_fix\ndescription: d\n---\n");
+    }
+
+    #[test]
+    fn test_missing_name_and_description() {
+        let options = FixOptions {
+            directory_name: Some("pdf-tools"),
+            match_directory: false,
+        };
+        let content = "---\nlicense: MIT\n---\n\n# PDF Tools\n\nExtract text and tables\nfrom PDF files.\n\nMore.\n";
+        let outcome = fix_skill_md(content, &options);
+        assert_eq!(
+            outcome.content,
+            "---\nname: pdf-tools\ndescription: Extract text and tables from PDF files.\nlicense: MIT\n---\n\n# PDF Tools\n\nExtract text and tables\nfrom PDF files.\n\nMore.\n"
+        );
+        assert_eq!(outcome.fixes.len(), 2);
+    }
+
+    #[test]
+    fn test_empty_block_description_is_replaced() {
+        let content = "---\nname: x\ndescription: |\nauthor: a\n---\n# Title Only\n";
+        let outcome = fix(content);
+        assert_eq!(
+            outcome.content,
+            "---\nname: x\ndescription: Title Only\nauthor: a\n---\n# Title Only\n"
+        );
+    }
+
+    #[test]
+    fn test_non_string_values_are_left_alone() {
+        let content = "---\nname: x\ndescription:\n  en: English\n---\nBody\n";
+        assert!(fix(content).fixes.is_empty());
+    }
+
+    #[test]
+    fn test_match_directory() {
+        let options = FixOptions {
+            directory_name: Some("new-name"),
+            match_directory: true,
+        };
+        let outcome = fix_skill_md("---\nname: old-name\ndescription: d\n---\n", &options);
+        assert_eq!(
+            outcome.content,
+            "---\nname: new-name\ndescription: d\n---\n"
+        );
+
+        let options = FixOptions {
+            directory_name: Some("new-name"),
+            match_directory: false,
+        };
+        assert!(
+            fix_skill_md("---\nname: old-name\ndescription: d\n---\n", &options)
+                .fixes
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_stray_delimiters() {
+        let outcome = fix("---\nname: x\ndescription: d\n---\n\n---\n# Body\n\nText\n---\n\n");
+        assert_eq!(
+            outcome.content,
+            "---\nname: x\ndescription: d\n---\n\n# Body\n\nText\n\n"
+        );
+        assert_eq!(outcome.fixes.len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_yaml_only_gets_text_fixes() {
+        let outcome = fix("---\r\nname: [broken\r\n---\r\n");
+        assert_eq!(outcome.content, "---\nname: [broken\n---\n");
+        assert_eq!(outcome.fixes.len(), 1);
+    }
+
+    #[test]
+    fn test_truncate_words() {
+        assert_eq!(truncate_words("short", 10), "short");
+        assert_eq!(truncate_words("one two three four", 12), "one two...");
+    }
+}
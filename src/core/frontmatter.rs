@@ -0,0 +1,432 @@
+//! Round-trip editing of SKILL.md frontmatter
+//!
+//! Top-level keys are edited on the frontmatter lines themselves instead of
+//! re-serializing the YAML, so comments, key order, formatting, line endings
+//! and the body survive an edit.
+
+use crate::core::errors::SikilError;
+use crate::core::parser::{extract_frontmatter, parse_frontmatter};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::PathBuf;
+
+/// Keys that can be edited: plain (unquoted) YAML keys
+static EDITABLE_KEY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[A-Za-z0-9_][A-Za-z0-9_.-]*$").unwrap());
+
+/// SKILL.md content split around its frontmatter for editing
+///
+/// # Examples
+///
+/// ```
+/// use sikil::core::frontmatter::FrontmatterEditor;
+///
+/// let content = "---\nname: x # keep me\ndescription: d\n---\nBody\n";
+/// let mut editor = FrontmatterEditor::parse(content).unwrap();
+/// editor.set("license", &serde_yaml::Value::from("MIT")).unwrap();
+/// assert_eq!(
+///     editor.render(),
+///     "---\nname: x # keep me\ndescription: d\nlicense: MIT\n---\nBody\n"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrontmatterEditor {
+    /// Whether the content started with a byte order mark
+    bom: bool,
+    /// Line ending used when rendering
+    newline: &'static str,
+    /// Whether the content ended with a line ending
+    trailing_newline: bool,
+    /// Lines up to and including the opening delimiter
+    head: Vec<String>,
+    /// Lines between the delimiters
+    frontmatter: Vec<String>,
+    /// The closing delimiter and the body
+    tail: Vec<String>,
+}
+
+impl FrontmatterEditor {
+    /// Splits SKILL.md content into its frontmatter and the surrounding lines
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidSkillMd` if the frontmatter delimiters are missing or
+    /// malformed (see [`extract_frontmatter`]).
+    pub fn parse(content: &str) -> Result<Self, SikilError> {
+        extract_frontmatter(content)?;
+
+        let bom = content.starts_with('\u{feff}');
+        let text = content.strip_prefix('\u{feff}').unwrap_or(content);
+        let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+        let (open, close) = locate_frontmatter(&lines)
+            .ok_or_else(|| invalid("missing frontmatter delimiters (no '---' markers found)"))?;
+
+        let tail = lines.split_off(close);
+        let frontmatter = lines.split_off(open + 1);
+        Ok(Self {
+            bom,
+            newline: if text.contains("\r\n") { "\r\n" } else { "\n" },
+            trailing_newline: text.ends_with('\n'),
+            head: lines,
+            frontmatter,
+            tail,
+        })
+    }
+
+    /// Parses the frontmatter as a YAML mapping
+    ///
+    /// Empty frontmatter is an empty mapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidSkillMd` if the YAML is invalid or not a mapping.
+    pub fn mapping(&self) -> Result<serde_yaml::Mapping, SikilError> {
+        match parse_frontmatter::<serde_yaml::Value>(&self.render())? {
+            serde_yaml::Value::Mapping(mapping) => Ok(mapping),
+            serde_yaml::Value::Null => Ok(serde_yaml::Mapping::new()),
+            _ => Err(invalid("frontmatter is not a YAML mapping")),
+        }
+    }
+
+    /// Returns the value of a top-level key, or `None` if it is not set
+    pub fn get(&self, key: &str) -> Result<Option<serde_yaml::Value>, SikilError> {
+        Ok(self.mapping()?.get(key).cloned())
+    }
+
+    /// Sets a top-level key, replacing its value in place or appending it
+    ///
+    /// Strings are written as plain scalars where YAML allows it; other
+    /// values are written in flow style on a single line.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` if the key is not a plain YAML key or is
+    /// written in a form that cannot be edited line by line (such as a
+    /// quoted key), and `InvalidSkillMd` if the frontmatter is invalid.
+    pub fn set(&mut self, key: &str, value: &serde_yaml::Value) -> Result<(), SikilError> {
+        let exists = self.check_editable(key)?;
+        let rendered = render_value(value).map_err(|e| SikilError::ValidationError {
+            reason: format!("cannot write a value for '{}': {}", key, e),
+        })?;
+
+        if exists {
+            let comment = self.trailing_comment(key)?;
+            set_key(&mut self.frontmatter, key, &rendered, None);
+            if let Some(comment) = comment {
+                if let Some(line) = self
+                    .frontmatter
+                    .iter_mut()
+                    .find(|l| key_line(key).is_match(l))
+                {
+                    line.push_str(&comment);
+                }
+            }
+        } else {
+            // Append after the last key, before any trailing blank lines
+            let insert_at = self
+                .frontmatter
+                .iter()
+                .rposition(|l| !l.trim().is_empty())
+                .map_or(0, |i| i + 1);
+            self.frontmatter
+                .insert(insert_at, format!("{}: {}", key, rendered));
+        }
+        Ok(())
+    }
+
+    /// Removes a top-level key and its indented continuation lines
+    ///
+    /// Returns `false` if the key was not set.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`FrontmatterEditor::set`].
+    pub fn unset(&mut self, key: &str) -> Result<bool, SikilError> {
+        if !self.check_editable(key)? {
+            return Ok(false);
+        }
+        let index = self
+            .frontmatter
+            .iter()
+            .position(|l| key_line(key).is_match(l))
+            .unwrap_or_default();
+        let end = index + 1 + continuation_len(&self.frontmatter[index + 1..]);
+        self.frontmatter.drain(index..end);
+        Ok(true)
+    }
+
+    /// Renders the edited content, keeping the original BOM and line endings
+    pub fn render(&self) -> String {
+        let lines: Vec<&str> = self
+            .head
+            .iter()
+            .chain(&self.frontmatter)
+            .chain(&self.tail)
+            .map(String::as_str)
+            .collect();
+
+        let mut out = String::new();
+        if self.bom {
+            out.push('\u{feff}');
+        }
+        out.push_str(&lines.join(self.newline));
+        if self.trailing_newline {
+            out.push_str(self.newline);
+        }
+        out
+    }
+
+    /// Returns the ` # comment` that follows a single-line value, if any
+    ///
+    /// A ` #` only starts a comment if the text before it still parses to
+    /// the key's current value, so `#` inside quoted strings is left alone.
+    fn trailing_comment(&self, key: &str) -> Result<Option<String>, SikilError> {
+        let Some(index) = self
+            .frontmatter
+            .iter()
+            .position(|l| key_line(key).is_match(l))
+        else {
+            return Ok(None);
+        };
+        if continuation_len(&self.frontmatter[index + 1..]) > 0 {
+            return Ok(None);
+        }
+        let current = self.get(key)?;
+        let line = &self.frontmatter[index];
+        for (at, _) in line.match_indices(" #") {
+            let parsed = serde_yaml::from_str::<serde_yaml::Mapping>(&line[..at]);
+            if parsed.ok().and_then(|m| m.get(key).cloned()) == current {
+                let start = line[..at].trim_end().len();
+                return Ok(Some(line[start..].to_string()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Checks that `key` can be edited and returns whether it is set
+    fn check_editable(&self, key: &str) -> Result<bool, SikilError> {
+        if !EDITABLE_KEY.is_match(key) {
+            return Err(SikilError::ValidationError {
+                reason: format!(
+                    "invalid key '{}': keys may contain letters, digits, '_', '-' and '.'",
+                    key
+                ),
+            });
+        }
+
+        let in_mapping = self.mapping()?.contains_key(key);
+        let on_line = self.frontmatter.iter().any(|l| key_line(key).is_match(l));
+        if in_mapping && !on_line {
+            return Err(SikilError::ValidationError {
+                reason: format!(
+                    "cannot edit '{}': it is not written as a plain top-level key",
+                    key
+                ),
+            });
+        }
+        Ok(in_mapping)
+    }
+}
+
+fn invalid(reason: &str) -> SikilError {
+    SikilError::InvalidSkillMd {
+        path: PathBuf::from("<content>"),
+        reason: reason.to_string(),
+        line: None,
+        column: None,
+    }
+}
+
+/// Returns the line indices of the opening and closing `---` delimiters
+pub(crate) fn locate_frontmatter(lines: &[String]) -> Option<(usize, usize)> {
+    let open = lines.iter().position(|l| !l.trim().is_empty())?;
+    if !is_delimiter(&lines[open]) {
+        return None;
+    }
+    let close = lines[open + 1..].iter().position(|l| is_delimiter(l))? + open + 1;
+    Some((open, close))
+}
+
+pub(crate) fn is_delimiter(line: &str) -> bool {
+    line.trim_end() == "---"
+}
+
+/// Matches a top-level `key:` line
+fn key_line(key: &str) -> Regex {
+    Regex::new(&format!(r"^{}\s*:", regex::escape(key))).unwrap()
+}
+
+/// Number of indented continuation lines at the start of `lines`
+///
+/// Trailing blank lines that separate the next key are not counted.
+fn continuation_len(lines: &[String]) -> usize {
+    let continuation = lines
+        .iter()
+        .take_while(|l| l.starts_with(' ') || l.starts_with('\t') || l.trim().is_empty())
+        .count();
+    lines[..continuation]
+        .iter()
+        .rposition(|l| !l.trim().is_empty())
+        .map_or(0, |i| i + 1)
+}
+
+/// Sets a top-level key to an already rendered YAML value
+///
+/// An existing key is replaced in place. A new key is inserted after the
+/// `after` key, or first if `after` is absent. Replacing a block value also
+/// removes its indented continuation lines.
+pub(crate) fn set_key(frontmatter: &mut Vec<String>, key: &str, value: &str, after: Option<&str>) {
+    let line = format!("{}: {}", key, value);
+
+    if let Some(index) = frontmatter.iter().position(|l| key_line(key).is_match(l)) {
+        let continuation = continuation_len(&frontmatter[index + 1..]);
+        frontmatter.splice(index..index + 1 + continuation, [line]);
+        return;
+    }
+
+    let insert_at = after
+        .and_then(|after| frontmatter.iter().position(|l| key_line(after).is_match(l)))
+        .map_or(0, |i| {
+            // Skip the continuation lines of the preceding key
+            i + 1
+                + frontmatter[i + 1..]
+                    .iter()
+                    .take_while(|l| l.starts_with(' ') || l.starts_with('\t'))
+                    .count()
+        });
+    frontmatter.insert(insert_at, line);
+}
+
+/// Values that YAML would not read back as a plain string
+static NEEDS_QUOTES: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)^(?:$|[\s\-?:,\[\]{}#&*!|>'"%@`~]|true$|false$|yes$|no$|on$|off$|null$|[-+.\d])|: |\s#|\s$"#)
+        .unwrap()
+});
+
+/// Formats a string as a YAML scalar, quoting only when necessary
+pub(crate) fn yaml_scalar(value: &str) -> String {
+    if NEEDS_QUOTES.is_match(value) || value.contains(['\n', '\t']) {
+        serde_json::to_string(value).unwrap_or_else(|_| format!("\"{}\"", value))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Formats a YAML value for a single `key: value` line
+///
+/// JSON is valid flow-style YAML, so non-string values are written as JSON.
+fn render_value(value: &serde_yaml::Value) -> Result<String, serde_json::Error> {
+    match value {
+        serde_yaml::Value::String(s) => Ok(yaml_scalar(s)),
+        other => serde_json::to_string(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn yaml(s: &str) -> serde_yaml::Value {
+        serde_yaml::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_set_replaces_in_place_and_keeps_comments() {
+        let content = "---\n# owner: docs\nname: x\nversion: 0.1.0 # bump me\ndescription: d\n---\n\n# Body\n";
+        let mut editor = FrontmatterEditor::parse(content).unwrap();
+        editor.set("version", &yaml("\"0.2.0\"")).unwrap();
+        assert_eq!(
+            editor.render(),
+            "---\n# owner: docs\nname: x\nversion: \"0.2.0\" # bump me\ndescription: d\n---\n\n# Body\n"
+        );
+    }
+
+    #[test]
+    fn test_set_ignores_hash_inside_quoted_value() {
+        let content = "---\nname: x\ndescription: \"Use #tags # here\" # note\n---\n";
+        let mut editor = FrontmatterEditor::parse(content).unwrap();
+        editor.set("description", &yaml("plain")).unwrap();
+        assert_eq!(
+            editor.render(),
+            "---\nname: x\ndescription: plain # note\n---\n"
+        );
+    }
+
+    #[test]
+    fn test_set_appends_new_key_before_trailing_blank_lines() {
+        let content = "---\nname: x\ndescription: d\n\n---\nBody";
+        let mut editor = FrontmatterEditor::parse(content).unwrap();
+        editor.set("tags", &yaml("[git, review]")).unwrap();
+        assert_eq!(
+            editor.render(),
+            "---\nname: x\ndescription: d\ntags: [\"git\",\"review\"]\n\n---\nBody"
+        );
+        assert_eq!(editor.get("tags").unwrap(), Some(yaml("[git, review]")));
+    }
+
+    #[test]
+    fn test_set_replaces_block_value() {
+        let content = "---\nname: x\ntags:\n  - a\n  - b\n\ndescription: d\n---\n";
+        let mut editor = FrontmatterEditor::parse(content).unwrap();
+        editor.set("tags", &yaml("c")).unwrap();
+        assert_eq!(
+            editor.render(),
+            "---\nname: x\ntags: c\n\ndescription: d\n---\n"
+        );
+    }
+
+    #[test]
+    fn test_unset_removes_key_and_continuation() {
+        let content =
+            "---\nname: x\nmetadata:\n  author: a\n# trailing comment\ndescription: d\n---\n";
+        let mut editor = FrontmatterEditor::parse(content).unwrap();
+        assert!(editor.unset("metadata").unwrap());
+        assert!(!editor.unset("homepage").unwrap());
+        assert_eq!(
+            editor.render(),
+            "---\nname: x\n# trailing comment\ndescription: d\n---\n"
+        );
+    }
+
+    #[test]
+    fn test_render_keeps_bom_and_crlf() {
+        let content = "\u{feff}---\r\nname: x\r\ndescription: d\r\n---\r\nBody\r\n";
+        let mut editor = FrontmatterEditor::parse(content).unwrap();
+        assert_eq!(editor.render(), content);
+        editor.set("license", &yaml("MIT")).unwrap();
+        assert_eq!(
+            editor.render(),
+            "\u{feff}---\r\nname: x\r\ndescription: d\r\nlicense: MIT\r\n---\r\nBody\r\n"
+        );
+    }
+
+    #[test]
+    fn test_rejects_keys_that_cannot_be_edited() {
+        let content = "---\n\"quoted key\": 1\n\"name\": x\ndescription: d\n---\n";
+        let mut editor = FrontmatterEditor::parse(content).unwrap();
+        assert!(editor.set("bad key", &yaml("1")).is_err());
+        let err = editor.set("name", &yaml("y")).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("not written as a plain top-level key"));
+    }
+
+    #[test]
+    fn test_invalid_frontmatter_is_not_edited() {
+        let mut editor = FrontmatterEditor::parse("---\nname: [broken\n---\n").unwrap();
+        assert!(matches!(
+            editor.set("version", &yaml("1")),
+            Err(SikilError::InvalidSkillMd { .. })
+        ));
+        assert!(FrontmatterEditor::parse("no frontmatter").is_err());
+    }
+
+    #[test]
+    fn test_yaml_scalar_quoting() {
+        assert_eq!(yaml_scalar("plain text"), "plain text");
+        assert_eq!(yaml_scalar("Use it: now"), "\"Use it: now\"");
+        assert_eq!(yaml_scalar("yes"), "\"yes\"");
+        assert_eq!(yaml_scalar("1.0"), "\"1.0\"");
+        assert_eq!(yaml_scalar("- item"), "\"- item\"");
+    }
+}
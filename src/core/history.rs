@@ -0,0 +1,355 @@
+//! History of completed operations, for `sikil undo`
+//!
+//! Once a plan has run, the steps it performed are saved to
+//! `~/.sikil/history/<id>/entry.json` together with the state it left each
+//! touched path in. Directories the plan deletes are moved into
+//! `~/.sikil/history/<id>/retained/` instead, so undoing the operation can
+//! bring them back. Only the last [`HISTORY_LIMIT`] operations are kept.
+//!
+//! An entry is only undone while every recorded path is still in the state
+//! the operation left it in; anything changed since makes undo unsafe.
+
+use crate::core::errors::SikilError;
+use crate::core::plan::{Plan, Step};
+use crate::utils::atomic::atomic_write;
+use crate::utils::paths::hash_dir;
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Number of operations kept in the history
+pub const HISTORY_LIMIT: usize = 10;
+
+/// What was found at a path
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PathState {
+    /// Nothing exists at the path
+    Absent,
+    /// A symlink pointing to `target`
+    Symlink { target: PathBuf },
+    /// A directory whose contents hash to `hash`
+    Dir { hash: String },
+    /// A regular file
+    File,
+}
+
+impl PathState {
+    /// Inspects a path without following symlinks
+    pub fn capture(path: &Path) -> Self {
+        match fs::symlink_metadata(path) {
+            Err(_) => PathState::Absent,
+            Ok(metadata) if metadata.file_type().is_symlink() => PathState::Symlink {
+                target: fs::read_link(path).unwrap_or_default(),
+            },
+            Ok(metadata) if metadata.is_dir() => PathState::Dir {
+                hash: hash_dir(path),
+            },
+            Ok(_) => PathState::File,
+        }
+    }
+}
+
+/// A path touched by an operation and the state it was left in
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpectedPath {
+    /// The touched path
+    pub path: PathBuf,
+    /// Its state right after the operation
+    #[serde(flatten)]
+    pub state: PathState,
+}
+
+/// A completed operation that can be undone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Command that ran the operation
+    pub command: String,
+    /// Skill the operation acted on
+    pub skill: String,
+    /// Unix timestamp (seconds) when the operation finished
+    pub finished_at: u64,
+    /// Steps as performed, with deletions turned into moves into `retained/`
+    pub steps: Vec<Step>,
+    /// Every touched path and the state the operation left it in
+    pub expected: Vec<ExpectedPath>,
+    #[serde(skip)]
+    dir: PathBuf,
+}
+
+impl HistoryEntry {
+    /// Rewrites `steps` for recording in the entry directory `dir`
+    ///
+    /// Each [`Step::RemoveDir`] becomes a move into `dir/retained/`, so the
+    /// directory can be restored.
+    pub fn retain_removed(steps: &[Step], dir: &Path) -> Vec<Step> {
+        steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| match step {
+                Step::RemoveDir { path } => Step::MoveDir {
+                    from: path.clone(),
+                    to: dir.join("retained").join(index.to_string()),
+                },
+                step => step.clone(),
+            })
+            .collect()
+    }
+
+    /// Saves a completed operation to the entry directory `dir`, capturing
+    /// the current state of every path its steps touched
+    pub fn record(
+        dir: &Path,
+        command: &str,
+        skill: &str,
+        steps: Vec<Step>,
+    ) -> Result<Self, SikilError> {
+        let mut paths: Vec<&Path> = Vec::new();
+        for step in &steps {
+            let touched: Vec<&Path> = match step {
+                Step::CopyDir { to, .. } => vec![to],
+                Step::MoveDir { from, to } => vec![from, to],
+                Step::CreateSymlink { link, .. } | Step::RemoveSymlink { link, .. } => vec![link],
+                Step::RemoveDir { path } => vec![path],
+            };
+            for path in touched {
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+        let expected = paths
+            .into_iter()
+            .map(|path| ExpectedPath {
+                path: path.to_path_buf(),
+                state: PathState::capture(path),
+            })
+            .collect();
+
+        let finished_at = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let entry = Self {
+            command: command.to_string(),
+            skill: skill.to_string(),
+            finished_at,
+            steps,
+            expected,
+            dir: dir.to_path_buf(),
+        };
+
+        let json = serde_json::to_vec_pretty(&entry).map_err(|e| SikilError::ValidationError {
+            reason: format!("failed to serialize history entry: {}", e),
+        })?;
+        fs::create_dir_all(dir).map_err(|_| SikilError::PermissionDenied {
+            operation: "create history directory".to_string(),
+            path: dir.to_path_buf(),
+        })?;
+        atomic_write(&dir.join("entry.json"), &json)?;
+        Ok(entry)
+    }
+
+    /// Loads the most recent operation recorded in `history_dir`
+    ///
+    /// Directories without a readable entry are skipped.
+    pub fn latest(history_dir: &Path) -> Option<Self> {
+        entry_dirs(history_dir).into_iter().rev().find_map(|dir| {
+            let content = fs::read_to_string(dir.join("entry.json")).ok()?;
+            let mut entry: Self = serde_json::from_str(&content).ok()?;
+            entry.dir = dir;
+            Some(entry)
+        })
+    }
+
+    /// Returns the recorded paths that are no longer in the state the
+    /// operation left them in
+    pub fn changed_paths(&self) -> Vec<&Path> {
+        self.expected
+            .iter()
+            .filter(|expected| PathState::capture(&expected.path) != expected.state)
+            .map(|expected| expected.path.as_path())
+            .collect()
+    }
+
+    /// Builds the plan that reverses the operation
+    ///
+    /// # Errors
+    ///
+    /// Returns `SikilError::ValidationError` if a step deleted data outright.
+    pub fn inverse(&self) -> Result<Plan, SikilError> {
+        let mut plan = Plan::new("undo", &self.skill);
+        for step in self.steps.iter().rev() {
+            plan.push(match step {
+                Step::CopyDir { to, .. } => Step::RemoveDir { path: to.clone() },
+                Step::MoveDir { from, to } => Step::MoveDir {
+                    from: to.clone(),
+                    to: from.clone(),
+                },
+                Step::CreateSymlink { target, link } => Step::RemoveSymlink {
+                    link: link.clone(),
+                    target: target.clone(),
+                },
+                Step::RemoveSymlink { link, target } => Step::CreateSymlink {
+                    target: target.clone(),
+                    link: link.clone(),
+                },
+                Step::RemoveDir { path } => {
+                    return Err(SikilError::ValidationError {
+                        reason: format!("{} was deleted and cannot be restored", path.display()),
+                    })
+                }
+            });
+        }
+        Ok(plan)
+    }
+
+    /// Deletes the entry and anything it retained
+    pub fn discard(self) -> Result<(), SikilError> {
+        fs::remove_dir_all(&self.dir).map_err(|_| SikilError::PermissionDenied {
+            operation: "remove history entry".to_string(),
+            path: self.dir.clone(),
+        })
+    }
+}
+
+/// Deletes all but the newest `keep` entries in `history_dir`
+pub fn prune(history_dir: &Path, keep: usize) {
+    let dirs = entry_dirs(history_dir);
+    let excess = dirs.len().saturating_sub(keep);
+    for dir in &dirs[..excess] {
+        let _ = fs::remove_dir_all(dir);
+    }
+}
+
+/// Removes an entry directory that was never recorded, if nothing was
+/// retained in it
+pub fn discard_unrecorded(dir: &Path) {
+    let _ = fs::remove_dir(dir.join("retained"));
+    let _ = fs::remove_dir(dir);
+}
+
+/// Lists entry directories, oldest first
+///
+/// Entry ids start with a zero-padded timestamp, so name order is time order.
+fn entry_dirs(history_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(history_dir) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::symlink::is_symlink;
+    use tempfile::TempDir;
+
+    fn make_skill(dir: &Path) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(dir.join("SKILL.md"), "---\nname: s\ndescription: d\n---\n").unwrap();
+    }
+
+    fn run_remove(root: &Path) -> (PathBuf, PathBuf) {
+        let repo = root.join("repo/s");
+        let link = root.join("agent/s");
+        make_skill(&repo);
+        std::fs::create_dir_all(root.join("agent")).unwrap();
+        std::os::unix::fs::symlink(&repo, &link).unwrap();
+
+        let mut plan = Plan::new("remove", "s");
+        plan.push(Step::RemoveSymlink {
+            link: link.clone(),
+            target: repo.clone(),
+        });
+        plan.push(Step::RemoveDir { path: repo.clone() });
+        plan.execute_in(&root.join("journal"), Some(&root.join("history")), |_| {})
+            .unwrap();
+        (repo, link)
+    }
+
+    #[test]
+    fn test_removed_directory_is_retained_and_restored() {
+        let temp_dir = TempDir::new().unwrap();
+        let (repo, link) = run_remove(temp_dir.path());
+        assert!(!repo.exists());
+
+        let entry = HistoryEntry::latest(&temp_dir.path().join("history")).unwrap();
+        assert_eq!(entry.command, "remove");
+        assert!(entry.changed_paths().is_empty());
+
+        entry
+            .inverse()
+            .unwrap()
+            .execute_in(&temp_dir.path().join("journal"), None, |_| {})
+            .unwrap();
+        entry.discard().unwrap();
+
+        assert!(repo.join("SKILL.md").is_file());
+        assert!(is_symlink(&link));
+        assert!(HistoryEntry::latest(&temp_dir.path().join("history")).is_none());
+    }
+
+    #[test]
+    fn test_changed_paths_detects_later_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let (repo, link) = run_remove(temp_dir.path());
+        make_skill(&link);
+
+        let entry = HistoryEntry::latest(&temp_dir.path().join("history")).unwrap();
+        assert_eq!(entry.changed_paths(), vec![link.as_path()]);
+
+        std::fs::remove_dir_all(&link).unwrap();
+        make_skill(&repo);
+        let entry = HistoryEntry::latest(&temp_dir.path().join("history")).unwrap();
+        assert_eq!(entry.changed_paths(), vec![repo.as_path()]);
+    }
+
+    #[test]
+    fn test_inverse_reverses_steps() {
+        let entry = HistoryEntry {
+            command: "adopt".to_string(),
+            skill: "s".to_string(),
+            finished_at: 0,
+            steps: vec![
+                Step::MoveDir {
+                    from: PathBuf::from("/agent/s"),
+                    to: PathBuf::from("/repo/s"),
+                },
+                Step::CreateSymlink {
+                    target: PathBuf::from("/repo/s"),
+                    link: PathBuf::from("/agent/s"),
+                },
+            ],
+            expected: Vec::new(),
+            dir: PathBuf::new(),
+        };
+
+        let plan = entry.inverse().unwrap();
+        assert_eq!(
+            plan.to_string(),
+            "1. remove symlink /agent/s\n2. move /repo/s to /agent/s\n"
+        );
+    }
+
+    #[test]
+    fn test_prune_keeps_newest_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        for id in ["0001", "0002", "0003"] {
+            std::fs::create_dir_all(temp_dir.path().join(id)).unwrap();
+        }
+
+        prune(temp_dir.path(), 2);
+
+        assert!(!temp_dir.path().join("0001").exists());
+        assert!(temp_dir.path().join("0002").exists());
+        assert!(temp_dir.path().join("0003").exists());
+    }
+}
@@ -0,0 +1,388 @@
+//! Write-ahead journal for multi-step filesystem operations
+//!
+//! Install, adopt, unmanage and remove change the repository and agent
+//! directories in several steps. Before the first step runs, the full list
+//! of planned steps is written to `~/.sikil/journal/<id>.json`, and the
+//! journal is rewritten after every completed step. It is deleted once the
+//! operation finishes or has been rolled back, so a journal found later
+//! belongs to a process that crashed or was interrupted, and records exactly
+//! how far it got.
+//!
+//! Steps are undone in reverse order on rollback.
+
+use crate::core::errors::SikilError;
+use crate::core::plan::Step;
+use crate::utils::atomic::atomic_write;
+use crate::utils::paths::{ensure_dir_exists, get_journal_dir};
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, SystemTime};
+
+/// Distinguishes journals started by the same process in the same millisecond
+static SEQUENCE: AtomicU32 = AtomicU32::new(0);
+
+/// Returns a unique, time-ordered id for an operation and the current time
+pub(crate) fn operation_id() -> (String, Duration) {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+    let id = format!(
+        "{:013}-{}-{}",
+        now.as_millis(),
+        std::process::id(),
+        SEQUENCE.fetch_add(1, Ordering::Relaxed)
+    );
+    (id, now)
+}
+
+/// How to settle an interrupted operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recovery {
+    /// Undo the completed steps
+    RollBack,
+    /// Run the remaining steps
+    RollForward,
+}
+
+/// On-disk contents of a journal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalRecord {
+    /// Command that started the operation
+    pub command: String,
+    /// Skill the operation acts on
+    pub skill: String,
+    /// PID of the process that wrote the journal
+    pub pid: u32,
+    /// Unix timestamp (seconds) when the operation started
+    pub started_at: u64,
+    /// Every planned step, in execution order
+    pub steps: Vec<Step>,
+    /// Number of steps that completed
+    pub completed: usize,
+}
+
+/// A journaled operation, executed one step at a time
+#[derive(Debug)]
+pub struct Journal {
+    path: PathBuf,
+    record: JournalRecord,
+}
+
+impl Journal {
+    /// Writes the planned steps to a new journal in `~/.sikil/journal/`
+    pub fn begin(command: &str, skill: &str, steps: Vec<Step>) -> Result<Self, SikilError> {
+        Self::begin_in(&get_journal_dir(), command, skill, steps)
+    }
+
+    /// Writes the planned steps to a new journal in `dir`
+    pub fn begin_in(
+        dir: &Path,
+        command: &str,
+        skill: &str,
+        steps: Vec<Step>,
+    ) -> Result<Self, SikilError> {
+        ensure_dir_exists(dir).map_err(|_| SikilError::PermissionDenied {
+            operation: "create journal directory".to_string(),
+            path: dir.to_path_buf(),
+        })?;
+
+        let (id, now) = operation_id();
+        let pid = std::process::id();
+
+        let journal = Self {
+            path: dir.join(format!("{}.json", id)),
+            record: JournalRecord {
+                command: command.to_string(),
+                skill: skill.to_string(),
+                pid,
+                started_at: now.as_secs(),
+                steps,
+                completed: 0,
+            },
+        };
+        journal.save()?;
+        Ok(journal)
+    }
+
+    /// Loads every journal left in `dir`, oldest first
+    ///
+    /// Files that cannot be read or parsed are skipped.
+    pub fn load_all(dir: &Path) -> Vec<Journal> {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+
+        let mut journals: Vec<Journal> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| {
+                let content = fs::read_to_string(&path).ok()?;
+                let record = serde_json::from_str(&content).ok()?;
+                Some(Journal { path, record })
+            })
+            .collect();
+        journals
+            .sort_by(|a, b| (a.record.started_at, &a.path).cmp(&(b.record.started_at, &b.path)));
+        journals
+    }
+
+    /// Returns the journal contents
+    pub fn record(&self) -> &JournalRecord {
+        &self.record
+    }
+
+    /// Returns the path of the journal file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the step that runs next, if any remain
+    pub fn next_step(&self) -> Option<&Step> {
+        self.record.steps.get(self.record.completed)
+    }
+
+    /// Performs the next step and records it as completed
+    ///
+    /// # Errors
+    ///
+    /// Returns the step's error, leaving the journal in place so the caller
+    /// can [`rollback`](Self::rollback).
+    pub fn apply_next(&mut self) -> Result<(), SikilError> {
+        let Some(step) = self.next_step() else {
+            return Ok(());
+        };
+        step.apply()?;
+        self.record.completed += 1;
+        self.save()
+    }
+
+    /// Runs every remaining step and deletes the journal
+    pub fn roll_forward(mut self) -> Result<(), SikilError> {
+        while let Some(step) = self.next_step() {
+            step.redo()?;
+            self.record.completed += 1;
+            self.save()?;
+        }
+        self.finish()
+    }
+
+    /// Undoes the completed steps, and any partial attempt at the next one,
+    /// then deletes the journal
+    ///
+    /// Steps that cannot be undone are skipped and the rest are still
+    /// reversed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SikilError::ValidationError` naming the steps that could not
+    /// be undone.
+    pub fn rollback(self) -> Result<(), SikilError> {
+        let attempted = (self.record.completed + 1).min(self.record.steps.len());
+        let mut failures = Vec::new();
+        for step in self.record.steps[..attempted].iter().rev() {
+            if let Err(e) = step.undo() {
+                failures.push(format!("{} ({})", step, e));
+            }
+        }
+        self.finish()?;
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(SikilError::ValidationError {
+                reason: format!("rollback incomplete: {}", failures.join("; ")),
+            })
+        }
+    }
+
+    /// Settles an interrupted operation as chosen by the user
+    pub fn recover(self, recovery: Recovery) -> Result<(), SikilError> {
+        match recovery {
+            Recovery::RollBack => self.rollback(),
+            Recovery::RollForward => self.roll_forward(),
+        }
+    }
+
+    /// Deletes the journal after the operation completed
+    pub fn finish(self) -> Result<(), SikilError> {
+        match fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(_) => Err(SikilError::PermissionDenied {
+                operation: "remove journal".to_string(),
+                path: self.path.clone(),
+            }),
+        }
+    }
+
+    fn save(&self) -> Result<(), SikilError> {
+        let json =
+            serde_json::to_vec_pretty(&self.record).map_err(|e| SikilError::ValidationError {
+                reason: format!("failed to serialize journal: {}", e),
+            })?;
+        atomic_write(&self.path, &json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::symlink::is_symlink;
+    use tempfile::TempDir;
+
+    fn make_skill(dir: &Path) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(dir.join("SKILL.md"), "---\nname: s\ndescription: d\n---\n").unwrap();
+    }
+
+    #[test]
+    fn test_completed_journal_is_removed() {
+        let temp_dir = TempDir::new().unwrap();
+        let journal_dir = temp_dir.path().join("journal");
+        let source = temp_dir.path().join("source");
+        make_skill(&source);
+
+        let mut journal = Journal::begin_in(
+            &journal_dir,
+            "install",
+            "s",
+            vec![
+                Step::CopyDir {
+                    from: source.clone(),
+                    to: temp_dir.path().join("repo/s"),
+                },
+                Step::CreateSymlink {
+                    target: temp_dir.path().join("repo/s"),
+                    link: temp_dir.path().join("agent/s"),
+                },
+            ],
+        )
+        .unwrap();
+        assert_eq!(Journal::load_all(&journal_dir).len(), 1);
+
+        journal.apply_next().unwrap();
+        assert_eq!(Journal::load_all(&journal_dir)[0].record().completed, 1);
+        journal.apply_next().unwrap();
+        journal.finish().unwrap();
+
+        assert!(Journal::load_all(&journal_dir).is_empty());
+        assert!(is_symlink(&temp_dir.path().join("agent/s")));
+    }
+
+    #[test]
+    fn test_interrupted_journal_rolls_back() {
+        let temp_dir = TempDir::new().unwrap();
+        let journal_dir = temp_dir.path().join("journal");
+        let original = temp_dir.path().join("agent/s");
+        let repo = temp_dir.path().join("repo/s");
+        make_skill(&original);
+
+        // Adopt interrupted after the move, before the symlink
+        let mut journal = Journal::begin_in(
+            &journal_dir,
+            "adopt",
+            "s",
+            vec![
+                Step::MoveDir {
+                    from: original.clone(),
+                    to: repo.clone(),
+                },
+                Step::CreateSymlink {
+                    target: repo.clone(),
+                    link: original.clone(),
+                },
+            ],
+        )
+        .unwrap();
+        journal.apply_next().unwrap();
+        drop(journal);
+
+        let mut found = Journal::load_all(&journal_dir);
+        assert_eq!(found.len(), 1);
+        found.remove(0).recover(Recovery::RollBack).unwrap();
+
+        assert!(original.join("SKILL.md").is_file());
+        assert!(!is_symlink(&original));
+        assert!(!repo.exists());
+        assert!(Journal::load_all(&journal_dir).is_empty());
+    }
+
+    #[test]
+    fn test_interrupted_journal_rolls_forward() {
+        let temp_dir = TempDir::new().unwrap();
+        let journal_dir = temp_dir.path().join("journal");
+        let repo = temp_dir.path().join("repo/s");
+        let link = temp_dir.path().join("agent/s");
+        make_skill(&repo);
+        std::fs::create_dir_all(temp_dir.path().join("agent")).unwrap();
+        std::os::unix::fs::symlink(&repo, &link).unwrap();
+
+        // Unmanage interrupted after removing the symlink, mid-copy
+        let mut journal = Journal::begin_in(
+            &journal_dir,
+            "unmanage",
+            "s",
+            vec![
+                Step::RemoveSymlink {
+                    link: link.clone(),
+                    target: repo.clone(),
+                },
+                Step::CopyDir {
+                    from: repo.clone(),
+                    to: link.clone(),
+                },
+                Step::RemoveDir { path: repo.clone() },
+            ],
+        )
+        .unwrap();
+        journal.apply_next().unwrap();
+        std::fs::create_dir_all(&link).unwrap();
+        drop(journal);
+
+        Journal::load_all(&journal_dir)
+            .remove(0)
+            .recover(Recovery::RollForward)
+            .unwrap();
+
+        assert!(!is_symlink(&link));
+        assert!(link.join("SKILL.md").is_file());
+        assert!(!repo.exists());
+        assert!(Journal::load_all(&journal_dir).is_empty());
+    }
+
+    #[test]
+    fn test_rollback_reports_deleted_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let journal_dir = temp_dir.path().join("journal");
+        let repo = temp_dir.path().join("repo/s");
+        let link = temp_dir.path().join("agent/s");
+        make_skill(&repo);
+        std::fs::create_dir_all(temp_dir.path().join("agent")).unwrap();
+        std::os::unix::fs::symlink(&repo, &link).unwrap();
+
+        let mut journal = Journal::begin_in(
+            &journal_dir,
+            "remove",
+            "s",
+            vec![
+                Step::RemoveSymlink {
+                    link: link.clone(),
+                    target: repo.clone(),
+                },
+                Step::RemoveDir { path: repo.clone() },
+            ],
+        )
+        .unwrap();
+        journal.apply_next().unwrap();
+        journal.apply_next().unwrap();
+
+        let err = journal.rollback().unwrap_err();
+        assert!(err.to_string().contains("cannot be restored"));
+        // The symlink is still restored
+        assert!(is_symlink(&link));
+        assert!(Journal::load_all(&journal_dir).is_empty());
+    }
+}
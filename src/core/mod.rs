@@ -1,19 +1,40 @@
 //! Core module containing fundamental data types and models
 
+pub mod audit;
 pub mod cache;
 pub mod config;
 pub mod conflicts;
 pub mod errors;
+pub mod fixer;
+pub mod frontmatter;
+pub mod history;
+pub mod journal;
 pub mod parser;
+pub mod plan;
+pub mod references;
+pub mod rules;
 pub mod scanner;
+pub mod scripts;
+pub mod search;
+pub mod secrets;
 pub mod skill;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_cache;
+pub mod tools;
+pub mod trash;
 
-pub use cache::{Cache, JsonCache, ScanEntry};
+pub use cache::{open_cache, Cache, CacheBackend, CacheConfig, CacheHealth, JsonCache, ScanEntry};
 pub use config::{AgentConfig, Config};
 pub use conflicts::{
     detect_conflicts, filter_error_conflicts, Conflict, ConflictLocation, ConflictType,
 };
 pub use errors::SikilError;
-pub use parser::{extract_frontmatter, parse_skill_md, validate_skill_name};
+pub use parser::{
+    extract_body, extract_frontmatter, parse_frontmatter, parse_skill_md, parse_skill_md_content,
+    validate_skill_name,
+};
+pub use rules::{Finding, LintConfig, Rule, RuleSet, Severity, RULES};
 pub use scanner::{ScanResult, Scanner, SkillEntry};
 pub use skill::{Agent, Installation, Scope, Skill, SkillMetadata};
+#[cfg(feature = "sqlite")]
+pub use sqlite_cache::SqliteCache;
@@ -1,18 +1,22 @@
 //! SKILL.md parser for extracting YAML frontmatter and metadata
 //!
 //! This module provides functions for parsing SKILL.md files, which use
-//! YAML frontmatter delimited by `---` markers.
+//! YAML frontmatter delimited by `---` lines.
 
 use crate::core::errors::SikilError;
 use crate::core::skill::SkillMetadata;
+use crate::core::tools::parse_allowed_tools;
 use fs_err as fs;
+use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 /// Extracts YAML frontmatter from SKILL.md content.
 ///
-/// SKILL.md files use YAML frontmatter delimited by `---` markers:
+/// SKILL.md files use YAML frontmatter delimited by `---` lines:
 ///
 /// ```text
 /// ---
@@ -25,6 +29,11 @@ use std::path::{Path, PathBuf};
 /// Documentation here...
 /// ```
 ///
+/// Delimiters must be whole lines (trailing whitespace is allowed), so `---`
+/// inside YAML values or a Markdown horizontal rule in the body is never
+/// mistaken for one. A leading byte order mark, blank lines before the opening
+/// delimiter and CRLF line endings are accepted.
+///
 /// # Arguments
 ///
 /// * `content` - The full content of a SKILL.md file
@@ -56,44 +65,198 @@ use std::path::{Path, PathBuf};
 ///
 /// # Errors
 ///
-/// * Returns `InvalidSkillMd` if no `---` markers are found
-/// * Returns `InvalidSkillMd` if only one `---` marker is found (malformed)
+/// * Returns `InvalidSkillMd` if no `---` delimiter line is found
+/// * Returns `InvalidSkillMd` if content other than blank lines precedes the opening delimiter
+/// * Returns `InvalidSkillMd` if the closing delimiter is missing
+///
+/// Errors carry the 1-based line of the problem; the path is `<content>`.
 pub fn extract_frontmatter(content: &str) -> Result<&str, SikilError> {
-    // Find the first occurrence of '---'
-    let first_marker = content
-        .find("---")
-        .ok_or_else(|| SikilError::InvalidSkillMd {
-            path: PathBuf::from("<content>"),
-            reason: "missing frontmatter delimiters (no '---' markers found)".to_string(),
-        })?;
-
-    // Skip past the first '---' marker
-    let after_first = &content[first_marker + 3..];
-
-    // Find the second occurrence of '---' after the first one
-    let second_marker = after_first
-        .find("---")
-        .ok_or_else(|| SikilError::InvalidSkillMd {
-            path: PathBuf::from("<content>"),
-            reason: "malformed frontmatter (only one '---' marker found, expected two)".to_string(),
-        })?;
+    split_frontmatter(content).map(|span| span.yaml)
+}
 
-    // Extract the content between the markers
-    let frontmatter = after_first[..second_marker].trim();
+/// Extracts the Markdown body that follows the frontmatter.
+///
+/// Returns the body text together with the 1-based line number in `content`
+/// on which the body starts, so that findings in the body can be reported
+/// against SKILL.md line numbers.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::core::parser::extract_body;
+///
+/// let content = "---\nname: x\ndescription: y\n---\n# Title\n";
+/// let (body, line) = extract_body(content).unwrap();
+/// assert_eq!(body, "# Title\n");
+/// assert_eq!(line, 5);
+/// ```
+///
+/// # Errors
+///
+/// Same as [`extract_frontmatter`].
+pub fn extract_body(content: &str) -> Result<(&str, usize), SikilError> {
+    let span = split_frontmatter(content)?;
+    Ok((&content[span.body_start..], span.body_line))
+}
 
-    // Ensure the first marker is at the start of the file (allowing only leading whitespace)
-    let before_first = &content[..first_marker];
-    if !before_first.trim().is_empty() {
-        return Err(SikilError::InvalidSkillMd {
+/// Parses the frontmatter of SKILL.md content as YAML.
+///
+/// YAML errors are reported with their line and column in `content` (not in
+/// the frontmatter), so they point at the right place in SKILL.md.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::core::errors::SikilError;
+/// use sikil::core::parser::parse_frontmatter;
+///
+/// let content = "---\nname: x\ndescription: [broken\n---\n";
+/// match parse_frontmatter::<serde_yaml::Value>(content) {
+///     Err(SikilError::InvalidSkillMd { line, .. }) => assert_eq!(line, Some(4)),
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Same as [`extract_frontmatter`], plus `InvalidSkillMd` if the YAML cannot
+/// be deserialized into `T`.
+pub fn parse_frontmatter<T: DeserializeOwned>(content: &str) -> Result<T, SikilError> {
+    let span = split_frontmatter(content)?;
+    serde_yaml::from_str(span.yaml).map_err(|e| {
+        let (line, column) = match e.location() {
+            Some(loc) => {
+                let column_offset = if loc.line() == 1 {
+                    span.column_offset
+                } else {
+                    0
+                };
+                (
+                    Some(span.yaml_line + loc.line() - 1),
+                    Some(loc.column() + column_offset),
+                )
+            }
+            None => (None, None),
+        };
+        SikilError::InvalidSkillMd {
             path: PathBuf::from("<content>"),
             reason: format!(
-                "frontmatter must be at the start of the file (found '{}' before first '---')",
-                before_first.trim()
+                "failed to parse YAML: {}",
+                strip_yaml_location(&e.to_string())
             ),
+            line,
+            column,
+        }
+    })
+}
+
+/// Location of the frontmatter within SKILL.md content
+struct FrontmatterSpan<'a> {
+    /// Frontmatter text between the delimiters, trimmed
+    yaml: &'a str,
+    /// 1-based line on which `yaml` starts
+    yaml_line: usize,
+    /// Leading whitespace trimmed from the first line of `yaml`
+    column_offset: usize,
+    /// Byte offset of the first body line
+    body_start: usize,
+    /// 1-based line number of the first body line
+    body_line: usize,
+}
+
+/// Returns true if a line (without its line ending) is a `---` delimiter
+fn is_delimiter(line: &str) -> bool {
+    line.trim_end() == "---"
+}
+
+/// Locates the frontmatter by scanning whole lines
+fn split_frontmatter(content: &str) -> Result<FrontmatterSpan<'_>, SikilError> {
+    let error = |reason: String, line: Option<usize>| SikilError::InvalidSkillMd {
+        path: PathBuf::from("<content>"),
+        reason,
+        line,
+        column: line.map(|_| 1),
+    };
+
+    // (byte offset, line text without line ending) for every line
+    let start = if content.starts_with('\u{feff}') {
+        3
+    } else {
+        0
+    };
+    let mut offset = start;
+    let lines: Vec<(usize, &str)> = content[start..]
+        .split_inclusive('\n')
+        .map(|raw| {
+            let line = (offset, raw.trim_end_matches('\n').trim_end_matches('\r'));
+            offset += raw.len();
+            line
+        })
+        .collect();
+
+    let Some(open) = lines.iter().position(|(_, l)| !l.trim().is_empty()) else {
+        return Err(error(
+            "missing frontmatter delimiters (no '---' markers found)".to_string(),
+            None,
+        ));
+    };
+    if !is_delimiter(lines[open].1) {
+        return Err(if lines.iter().any(|(_, l)| is_delimiter(l)) {
+            error(
+                format!(
+                    "frontmatter must be at the start of the file (found '{}' before first '---')",
+                    lines[open].1.trim()
+                ),
+                Some(open + 1),
+            )
+        } else {
+            error(
+                "missing frontmatter delimiters (no '---' markers found)".to_string(),
+                None,
+            )
         });
     }
 
-    Ok(frontmatter)
+    let close = lines[open + 1..]
+        .iter()
+        .position(|(_, l)| is_delimiter(l))
+        .map(|i| i + open + 1)
+        .ok_or_else(|| {
+            error(
+                "malformed frontmatter (only one '---' marker found, expected two)".to_string(),
+                Some(open + 1),
+            )
+        })?;
+
+    let yaml_start = lines.get(open + 1).map_or(content.len(), |(o, _)| *o);
+    let raw_yaml = &content[yaml_start..lines[close].0];
+    let yaml = raw_yaml.trim();
+
+    // Position of the trimmed text, for mapping YAML errors back to the file
+    let leading = &raw_yaml[..raw_yaml.len() - raw_yaml.trim_start().len()];
+    let yaml_line = open + 2 + leading.matches('\n').count();
+    let column_offset = leading.rsplit('\n').next().map_or(0, |l| l.chars().count());
+
+    let (body_start, body_line) = match lines.get(close + 1) {
+        Some((o, _)) => (*o, close + 2),
+        None => (content.len(), close + 1),
+    };
+
+    Ok(FrontmatterSpan {
+        yaml,
+        yaml_line,
+        column_offset,
+        body_start,
+        body_line,
+    })
+}
+
+/// Positions in serde_yaml messages, which are relative to the frontmatter
+static YAML_POSITION: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+at line \d+ column \d+").unwrap());
+
+/// Removes serde_yaml's frontmatter-relative positions from an error message
+fn strip_yaml_location(message: &str) -> String {
+    YAML_POSITION.replace_all(message, "").into_owned()
 }
 
 /// Validates a skill name according to the naming rules.
@@ -190,6 +353,22 @@ struct NestedMetadata {
     license: Option<String>,
 }
 
+/// Top-level frontmatter keys that map onto typed `SkillMetadata` fields
+const KNOWN_KEYS: &[&str] = &[
+    "name",
+    "description",
+    "version",
+    "author",
+    "license",
+    "homepage",
+    "repository",
+    "compatibility",
+    "metadata",
+];
+
+/// Keys of the nested `metadata:` block that are merged into typed fields
+const KNOWN_NESTED_KEYS: &[&str] = &["version", "author", "license"];
+
 /// Raw YAML structure for parsing SKILL.md frontmatter
 ///
 /// This struct uses `Option` for all fields to allow graceful handling
@@ -211,6 +390,15 @@ struct RawSkillMetadata {
     /// Optional license (top-level)
     license: Option<String>,
 
+    /// Optional homepage URL (any YAML value; see [`scalar_string`])
+    homepage: Option<serde_yaml::Value>,
+
+    /// Optional source repository URL (any YAML value)
+    repository: Option<serde_yaml::Value>,
+
+    /// Optional environment requirements (any YAML value)
+    compatibility: Option<serde_yaml::Value>,
+
     /// Optional nested metadata block
     metadata: Option<NestedMetadata>,
 }
@@ -252,33 +440,54 @@ pub fn parse_skill_md(path: &Path) -> Result<SkillMetadata, SikilError> {
     let content = fs::read_to_string(path).map_err(|e| SikilError::InvalidSkillMd {
         path: path.to_path_buf(),
         reason: format!("failed to read file: {}", e),
+        line: None,
+        column: None,
     })?;
 
-    // Extract the YAML frontmatter
-    let frontmatter = extract_frontmatter(&content).map_err(|e| match e {
-        SikilError::InvalidSkillMd { path: _, reason } => SikilError::InvalidSkillMd {
+    parse_skill_md_content(&content, path)
+}
+
+/// Parses SKILL.md content and extracts its metadata.
+///
+/// Same as [`parse_skill_md`] for content that is already in memory, such as
+/// an edit that has not been written yet. `path` is only used in errors.
+///
+/// # Errors
+///
+/// Same as [`parse_skill_md`], except for read errors.
+pub fn parse_skill_md_content(content: &str, path: &Path) -> Result<SkillMetadata, SikilError> {
+    // Extract the YAML frontmatter and parse it into our raw struct. The
+    // untyped parse collects the keys the struct does not model.
+    let with_path = |e| match e {
+        SikilError::InvalidSkillMd {
+            path: _,
+            reason,
+            line,
+            column,
+        } => SikilError::InvalidSkillMd {
             path: path.to_path_buf(),
             reason,
+            line,
+            column,
         },
         _ => e,
-    })?;
-
-    // Parse the YAML into our raw struct
-    let raw: RawSkillMetadata =
-        serde_yaml::from_str(frontmatter).map_err(|e| SikilError::InvalidSkillMd {
-            path: path.to_path_buf(),
-            reason: format!("failed to parse YAML: {}", e),
-        })?;
+    };
+    let raw: RawSkillMetadata = parse_frontmatter(content).map_err(with_path)?;
+    let value: serde_yaml::Value = parse_frontmatter(content).map_err(with_path)?;
 
     // Validate required fields
     let name = raw.name.ok_or_else(|| SikilError::InvalidSkillMd {
         path: path.to_path_buf(),
         reason: "missing required field 'name'".to_string(),
+        line: None,
+        column: None,
     })?;
 
     let description = raw.description.ok_or_else(|| SikilError::InvalidSkillMd {
         path: path.to_path_buf(),
         reason: "missing required field 'description'".to_string(),
+        line: None,
+        column: None,
     })?;
 
     // Merge top-level fields with nested metadata block (top-level takes precedence)
@@ -287,6 +496,39 @@ pub fn parse_skill_md(path: &Path) -> Result<SkillMetadata, SikilError> {
     let author = raw.author.or(nested.author);
     let license = raw.license.or(nested.license);
 
+    // An invalid `allowed-tools` is kept verbatim in `extra` (and reported by
+    // validate) rather than hiding the whole skill
+    let mut extra = collect_extra_fields(&value);
+    let allowed_tools = value
+        .get("allowed-tools")
+        .and_then(|v| parse_allowed_tools(v).ok());
+    if allowed_tools.is_some() {
+        extra.remove("allowed-tools");
+    }
+    // Likewise, a non-scalar `homepage`, `repository` or `compatibility` is
+    // kept in `extra` (validate warns about it under SK017)
+    let mut string_field = |key: &str, field: Option<serde_yaml::Value>| {
+        field.and_then(|v| {
+            scalar_string(&v).or_else(|| {
+                if !v.is_null() {
+                    extra.insert(key.to_string(), v);
+                }
+                None
+            })
+        })
+    };
+    let homepage = string_field("homepage", raw.homepage);
+    let repository = string_field("repository", raw.repository);
+    let compatibility = string_field("compatibility", raw.compatibility);
+
+    let tags = match value.get("tags").map(parse_tags) {
+        Some(Some(tags)) => {
+            extra.remove("tags");
+            tags
+        }
+        _ => Vec::new(),
+    };
+
     // Build and return the SkillMetadata
     Ok(SkillMetadata {
         name,
@@ -294,9 +536,88 @@ pub fn parse_skill_md(path: &Path) -> Result<SkillMetadata, SikilError> {
         version,
         author,
         license,
+        homepage,
+        repository,
+        compatibility,
+        allowed_tools,
+        tags,
+        extra,
     })
 }
 
+/// Renders a scalar YAML value (string, number or boolean) as a string
+///
+/// Returns `None` for null, lists and mappings.
+fn scalar_string(value: &serde_yaml::Value) -> Option<String> {
+    match value {
+        serde_yaml::Value::String(s) => Some(s.clone()),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        serde_yaml::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Parses a `tags` value: a list of strings or a comma-separated string
+///
+/// Returns `None` for any other shape, so the value stays in `extra`.
+fn parse_tags(value: &serde_yaml::Value) -> Option<Vec<String>> {
+    let tags: Vec<String> = match value {
+        serde_yaml::Value::String(s) => s.split(',').map(str::to_string).collect(),
+        serde_yaml::Value::Sequence(items) => items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string))
+            .collect::<Option<_>>()?,
+        _ => return None,
+    };
+    Some(
+        tags.iter()
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect(),
+    )
+}
+
+/// Collects frontmatter keys that have no typed `SkillMetadata` field
+///
+/// Unknown keys of the nested `metadata:` block are kept under `metadata`
+/// so that nothing in the frontmatter is lost.
+fn collect_extra_fields(value: &serde_yaml::Value) -> BTreeMap<String, serde_yaml::Value> {
+    let mut extra = BTreeMap::new();
+    let Some(mapping) = value.as_mapping() else {
+        return extra;
+    };
+
+    for (key, value) in mapping {
+        let key = yaml_key(key);
+        if key == "metadata" {
+            if let Some(nested) = value.as_mapping() {
+                let rest: serde_yaml::Mapping = nested
+                    .iter()
+                    .filter(|(k, _)| !KNOWN_NESTED_KEYS.contains(&yaml_key(k).as_str()))
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect();
+                if !rest.is_empty() {
+                    extra.insert(key, serde_yaml::Value::Mapping(rest));
+                }
+            }
+        } else if !KNOWN_KEYS.contains(&key.as_str()) {
+            extra.insert(key, value.clone());
+        }
+    }
+
+    extra
+}
+
+/// Renders a YAML mapping key as a string (non-string keys use their YAML form)
+fn yaml_key(key: &serde_yaml::Value) -> String {
+    match key {
+        serde_yaml::Value::String(s) => s.clone(),
+        other => serde_yaml::to_string(other)
+            .map(|s| s.trim_end().to_string())
+            .unwrap_or_default(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -316,6 +637,22 @@ description: A test skill
         assert_eq!(frontmatter, "name: test-skill\ndescription: A test skill");
     }
 
+    #[test]
+    fn test_extract_body_line_numbers() {
+        let content = "\n---\nname: test-skill\ndescription: A test skill\n---\n\n# Documentation";
+
+        let (body, line) = extract_body(content).unwrap();
+        assert_eq!(body, "\n# Documentation");
+        assert_eq!(line, 6);
+    }
+
+    #[test]
+    fn test_extract_body_empty() {
+        let (body, line) = extract_body("---\nname: x\n---").unwrap();
+        assert_eq!(body, "");
+        assert_eq!(line, 3);
+    }
+
     #[test]
     fn test_extract_frontmatter_valid_with_leading_newline() {
         let content = r#"
@@ -491,6 +828,71 @@ name: test-skill
         assert!(frontmatter.contains("name: test-skill"));
     }
 
+    #[test]
+    fn test_extract_frontmatter_ignores_dashes_inside_lines() {
+        let content = "---\nname: test-skill\ndescription: before --- after \u{2014}\u{2014}\u{2014} end\n---\n\n# Doc\n\n---\n\nMore\n";
+        let frontmatter = extract_frontmatter(content).unwrap();
+        assert_eq!(
+            frontmatter,
+            "name: test-skill\ndescription: before --- after \u{2014}\u{2014}\u{2014} end"
+        );
+
+        let (body, line) = extract_body(content).unwrap();
+        assert_eq!(body, "\n# Doc\n\n---\n\nMore\n");
+        assert_eq!(line, 5);
+    }
+
+    #[test]
+    fn test_extract_frontmatter_rejects_partial_delimiter_lines() {
+        let content = "--- \nname: x\n----\n--- not a delimiter\n";
+        let err = extract_frontmatter(content).unwrap_err();
+        assert!(err.to_string().contains("only one '---' marker found"));
+    }
+
+    #[test]
+    fn test_extract_frontmatter_bom_and_crlf() {
+        let content = "\u{feff}---\r\nname: test-skill\r\ndescription: d\r\n---\r\n# Body\r\n";
+        let frontmatter = extract_frontmatter(content).unwrap();
+        assert_eq!(frontmatter, "name: test-skill\r\ndescription: d");
+
+        let (body, line) = extract_body(content).unwrap();
+        assert_eq!(body, "# Body\r\n");
+        assert_eq!(line, 5);
+    }
+
+    #[test]
+    fn test_extract_frontmatter_error_lines() {
+        let not_at_start = "\n# Title\n---\nname: x\n---\n";
+        match extract_frontmatter(not_at_start).unwrap_err() {
+            SikilError::InvalidSkillMd { line, .. } => assert_eq!(line, Some(2)),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        match extract_frontmatter("\n\n---\nname: x\n").unwrap_err() {
+            SikilError::InvalidSkillMd { line, .. } => assert_eq!(line, Some(3)),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_frontmatter_maps_yaml_errors_to_file_position() {
+        let content = "\n---\n\nname: x\ndescription: d\nextra: a: b\n---\n";
+        match parse_frontmatter::<serde_yaml::Value>(content).unwrap_err() {
+            SikilError::InvalidSkillMd {
+                reason,
+                line,
+                column,
+                ..
+            } => {
+                assert_eq!(line, Some(6));
+                assert_eq!(column, Some(9));
+                assert!(reason.starts_with("failed to parse YAML"));
+                assert!(!reason.contains("at line"));
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
     #[test]
     fn test_extract_frontmatter_complex_yaml() {
         let content = r#"---
@@ -694,6 +1096,114 @@ description: [invalid
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(err.to_string().contains("failed to parse YAML"));
+        assert!(err.to_string().contains("SKILL.md:3:14:"));
+    }
+
+    #[test]
+    fn test_parse_skill_md_keeps_extra_fields() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let skill_path = temp_dir.path().join("SKILL.md");
+        let content = r#"---
+name: test-skill
+description: A test skill
+homepage: https://example.com/test-skill
+compatibility: Requires git
+allowed-tools: Read, Grep
+tags: [git, review]
+metadata:
+  author: Nested Author
+  internal-id: 42
+---
+
+# Documentation"#;
+        fs::write(&skill_path, content).unwrap();
+
+        let metadata = parse_skill_md(&skill_path).unwrap();
+        assert_eq!(
+            metadata.homepage,
+            Some("https://example.com/test-skill".to_string())
+        );
+        assert_eq!(metadata.compatibility, Some("Requires git".to_string()));
+        assert_eq!(metadata.repository, None);
+        assert_eq!(metadata.author, Some("Nested Author".to_string()));
+
+        let tools: Vec<String> = metadata
+            .allowed_tools
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(tools, vec!["Read", "Grep"]);
+
+        assert_eq!(metadata.tags, vec!["git", "review"]);
+
+        let keys: Vec<&str> = metadata.extra.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["metadata"]);
+        assert_eq!(
+            metadata.extra["metadata"],
+            serde_yaml::from_str::<serde_yaml::Value>("internal-id: 42").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_skill_md_keeps_invalid_allowed_tools_in_extra() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let skill_path = temp_dir.path().join("SKILL.md");
+        let content = "---\nname: test-skill\ndescription: d\nallowed-tools: Bash(git:*\n---\n";
+        fs::write(&skill_path, content).unwrap();
+
+        let metadata = parse_skill_md(&skill_path).unwrap();
+        assert!(metadata.allowed_tools.is_none());
+        assert_eq!(
+            metadata.extra["allowed-tools"],
+            serde_yaml::Value::String("Bash(git:*".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_skill_md_keeps_non_string_fields_in_extra() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let skill_path = temp_dir.path().join("SKILL.md");
+        let content = "---\nname: test-skill\ndescription: d\nhomepage: 42\nrepository:\ncompatibility:\n  - git\n  - node >= 18\n---\n";
+        fs::write(&skill_path, content).unwrap();
+
+        let metadata = parse_skill_md(&skill_path).unwrap();
+        assert_eq!(metadata.homepage, Some("42".to_string()));
+        assert_eq!(metadata.repository, None);
+        assert_eq!(metadata.compatibility, None);
+        let keys: Vec<&str> = metadata.extra.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["compatibility"]);
+        assert_eq!(
+            metadata.extra["compatibility"],
+            serde_yaml::from_str::<serde_yaml::Value>("[git, node >= 18]").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        let tags = |yaml: &str| parse_tags(&serde_yaml::from_str(yaml).unwrap());
+        assert_eq!(
+            tags("[git, review]"),
+            Some(vec!["git".into(), "review".into()])
+        );
+        assert_eq!(
+            tags("'git, code review,'"),
+            Some(vec!["git".into(), "code review".into()])
+        );
+        assert_eq!(tags("[git, 3]"), None);
+        assert_eq!(tags("{git: true}"), None);
+    }
+
+    #[test]
+    fn test_parse_skill_md_without_extra_fields() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let skill_path = temp_dir.path().join("SKILL.md");
+        let content = "---\nname: test-skill\ndescription: d\nmetadata:\n  version: 1.0.0\n---\n";
+        fs::write(&skill_path, content).unwrap();
+
+        let metadata = parse_skill_md(&skill_path).unwrap();
+        assert_eq!(metadata.version, Some("1.0.0".to_string()));
+        assert!(metadata.extra.is_empty());
     }
 
     #[test]
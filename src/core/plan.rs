@@ -0,0 +1,399 @@
+//! Operation plans and the shared executor
+//!
+//! Mutating commands first describe what they will do as a [`Plan`]: an
+//! ordered list of filesystem [`Step`]s such as "copy X to Y", "symlink A to
+//! B" or "delete C". A plan can be printed instead of run (`--dry-run`), or
+//! handed to [`Plan::execute`], which records it in the write-ahead journal,
+//! applies the steps in order and rolls back completed steps if one fails.
+//!
+//! Every step except [`Step::RemoveDir`] can be undone; re-running or undoing
+//! a step tolerates the step having been applied partially or not at all.
+//! Completed plans are recorded in the history for `sikil undo`, which keeps
+//! a copy of each deleted directory.
+
+use crate::core::errors::SikilError;
+use crate::core::history::{self, HistoryEntry, HISTORY_LIMIT};
+use crate::core::journal::{operation_id, Journal};
+use crate::utils::atomic::{atomic_move_dir, copy_skill_dir};
+use crate::utils::paths::{get_history_dir, get_journal_dir};
+use crate::utils::symlink::{create_symlink, is_symlink};
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A single filesystem step of an operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Step {
+    /// Copy a skill directory to a path that does not exist yet
+    CopyDir { from: PathBuf, to: PathBuf },
+    /// Move a skill directory to a path that does not exist yet
+    MoveDir { from: PathBuf, to: PathBuf },
+    /// Create a symlink at `link` pointing to `target`
+    CreateSymlink { target: PathBuf, link: PathBuf },
+    /// Remove the symlink at `link`, which points to `target`
+    RemoveSymlink { link: PathBuf, target: PathBuf },
+    /// Delete a directory and everything in it
+    RemoveDir { path: PathBuf },
+}
+
+impl Step {
+    /// Performs the step
+    pub fn apply(&self) -> Result<(), SikilError> {
+        match self {
+            Step::CopyDir { from, to } => copy_skill_dir(from, to),
+            Step::MoveDir { from, to } => atomic_move_dir(from, to),
+            Step::CreateSymlink { target, link } => create_symlink(target, link),
+            Step::RemoveSymlink { link, .. } => {
+                fs::remove_file(link).map_err(|e| SikilError::SymlinkError {
+                    reason: format!("failed to remove symlink at {}", link.display()),
+                    source: Some(e),
+                })
+            }
+            Step::RemoveDir { path } => remove_dir(path),
+        }
+    }
+
+    /// Performs the step again after an interruption
+    ///
+    /// Leftovers of a partial attempt are cleared first, and a step that
+    /// already completed is left alone.
+    pub fn redo(&self) -> Result<(), SikilError> {
+        match self {
+            Step::CopyDir { to, .. } => {
+                if to.exists() {
+                    remove_dir(to)?;
+                }
+                self.apply()
+            }
+            Step::MoveDir { from, to } => {
+                if !from.exists() && to.exists() {
+                    return Ok(());
+                }
+                // A cross-filesystem move copies before deleting the source,
+                // so a destination next to an intact source is a partial copy
+                if to.exists() {
+                    remove_dir(to)?;
+                }
+                self.apply()
+            }
+            Step::CreateSymlink { .. } => self.apply(),
+            Step::RemoveSymlink { link, .. } => {
+                if is_symlink(link) {
+                    self.apply()
+                } else {
+                    Ok(())
+                }
+            }
+            Step::RemoveDir { path } => {
+                if path.exists() {
+                    self.apply()
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Reverses the step, whether it completed, partially ran or never ran
+    ///
+    /// # Errors
+    ///
+    /// Returns `SikilError::ValidationError` for a directory that was already
+    /// deleted, which cannot be restored.
+    pub fn undo(&self) -> Result<(), SikilError> {
+        match self {
+            Step::CopyDir { to, .. } => {
+                if to.exists() {
+                    remove_dir(to)?;
+                }
+                Ok(())
+            }
+            Step::MoveDir { from, to } => {
+                if !to.exists() {
+                    return Ok(());
+                }
+                if is_symlink(from) {
+                    return Err(SikilError::ValidationError {
+                        reason: format!("{} is in the way of the move back", from.display()),
+                    });
+                }
+                if from.exists() {
+                    remove_dir(to)
+                } else {
+                    atomic_move_dir(to, from)
+                }
+            }
+            Step::CreateSymlink { link, .. } => {
+                if is_symlink(link) {
+                    fs::remove_file(link).map_err(|e| SikilError::SymlinkError {
+                        reason: format!("failed to remove symlink at {}", link.display()),
+                        source: Some(e),
+                    })?;
+                }
+                Ok(())
+            }
+            Step::RemoveSymlink { link, target } => {
+                if is_symlink(link) || link.exists() {
+                    return Ok(());
+                }
+                create_symlink(target, link)
+            }
+            Step::RemoveDir { path } => {
+                if path.exists() {
+                    Ok(())
+                } else {
+                    Err(SikilError::ValidationError {
+                        reason: format!("{} was deleted and cannot be restored", path.display()),
+                    })
+                }
+            }
+        }
+    }
+
+    /// Returns whether the step can be undone after it completed
+    pub fn is_reversible(&self) -> bool {
+        !matches!(self, Step::RemoveDir { .. })
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::CopyDir { from, to } => {
+                write!(f, "copy {} to {}", from.display(), to.display())
+            }
+            Step::MoveDir { from, to } => {
+                write!(f, "move {} to {}", from.display(), to.display())
+            }
+            Step::CreateSymlink { target, link } => {
+                write!(f, "symlink {} -> {}", link.display(), target.display())
+            }
+            Step::RemoveSymlink { link, .. } => write!(f, "remove symlink {}", link.display()),
+            Step::RemoveDir { path } => write!(f, "delete {}", path.display()),
+        }
+    }
+}
+
+/// The steps a command will perform, in order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Plan {
+    /// Command the plan belongs to
+    pub command: String,
+    /// Skill the plan acts on, or empty for a command acting on several
+    pub skill: String,
+    /// Steps to perform, in order
+    pub steps: Vec<Step>,
+}
+
+impl Plan {
+    /// Creates an empty plan
+    pub fn new(command: &str, skill: &str) -> Self {
+        Self {
+            command: command.to_string(),
+            skill: skill.to_string(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Appends a step
+    pub fn push(&mut self, step: Step) {
+        self.steps.push(step);
+    }
+
+    /// Returns whether the plan has no steps
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Runs the plan under a journal in `~/.sikil/journal/` and records it
+    /// in `~/.sikil/history/` so it can be undone
+    ///
+    /// `on_step` is called after each step completes, so commands can report
+    /// progress. If a step fails, the steps before it are rolled back and the
+    /// step's error is returned.
+    pub fn execute(self, on_step: impl FnMut(&Step)) -> Result<(), SikilError> {
+        self.execute_in(&get_journal_dir(), Some(&get_history_dir()), on_step)
+    }
+
+    /// Runs the plan under a journal without recording it in the history
+    pub fn execute_untracked(self, on_step: impl FnMut(&Step)) -> Result<(), SikilError> {
+        self.execute_in(&get_journal_dir(), None, on_step)
+    }
+
+    /// Runs the plan under a journal in `journal_dir`, recording it in
+    /// `history_dir` if one is given
+    ///
+    /// When recorded, deleted directories are moved into the history entry
+    /// rather than deleted. Failing to record a completed plan is not an
+    /// error; the operation just cannot be undone.
+    pub fn execute_in(
+        self,
+        journal_dir: &Path,
+        history_dir: Option<&Path>,
+        on_step: impl FnMut(&Step),
+    ) -> Result<(), SikilError> {
+        let Some(history_dir) = history_dir else {
+            let journal =
+                Journal::begin_in(journal_dir, &self.command, &self.skill, self.steps.clone())?;
+            return run(journal, &self.steps, on_step);
+        };
+
+        let (id, _) = operation_id();
+        let entry_dir = history_dir.join(id);
+        let performed = HistoryEntry::retain_removed(&self.steps, &entry_dir);
+        let journal =
+            Journal::begin_in(journal_dir, &self.command, &self.skill, performed.clone())?;
+        if let Err(e) = run(journal, &self.steps, on_step) {
+            history::discard_unrecorded(&entry_dir);
+            return Err(e);
+        }
+
+        if HistoryEntry::record(&entry_dir, &self.command, &self.skill, performed).is_ok() {
+            history::prune(history_dir, HISTORY_LIMIT);
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, step) in self.steps.iter().enumerate() {
+            writeln!(f, "{}. {}", index + 1, step)?;
+        }
+        Ok(())
+    }
+}
+
+/// Applies the journaled steps, reporting each as the matching step in
+/// `reported`
+fn run(
+    mut journal: Journal,
+    reported: &[Step],
+    mut on_step: impl FnMut(&Step),
+) -> Result<(), SikilError> {
+    for step in reported {
+        if let Err(e) = journal.apply_next() {
+            return Err(match journal.rollback() {
+                Ok(()) => e,
+                Err(rollback) => SikilError::ValidationError {
+                    reason: format!("{}; {}", e, rollback),
+                },
+            });
+        }
+        on_step(step);
+    }
+    journal.finish()
+}
+
+fn remove_dir(path: &Path) -> Result<(), SikilError> {
+    fs::remove_dir_all(path).map_err(|_| SikilError::PermissionDenied {
+        operation: "remove directory".to_string(),
+        path: path.to_path_buf(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn make_skill(dir: &Path) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(dir.join("SKILL.md"), "---\nname: s\ndescription: d\n---\n").unwrap();
+    }
+
+    #[test]
+    fn test_execute_applies_steps_in_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let journal_dir = temp_dir.path().join("journal");
+        let source = temp_dir.path().join("source");
+        let repo = temp_dir.path().join("repo/s");
+        let link = temp_dir.path().join("agent/s");
+        make_skill(&source);
+
+        let mut plan = Plan::new("install", "s");
+        plan.push(Step::CopyDir {
+            from: source,
+            to: repo.clone(),
+        });
+        plan.push(Step::CreateSymlink {
+            target: repo.clone(),
+            link: link.clone(),
+        });
+
+        let mut done = Vec::new();
+        plan.clone()
+            .execute_in(&journal_dir, None, |step| done.push(step.clone()))
+            .unwrap();
+
+        assert_eq!(done, plan.steps);
+        assert!(is_symlink(&link));
+        assert!(repo.join("SKILL.md").is_file());
+        assert!(Journal::load_all(&journal_dir).is_empty());
+    }
+
+    #[test]
+    fn test_execute_rolls_back_on_failure() {
+        let temp_dir = TempDir::new().unwrap();
+        let journal_dir = temp_dir.path().join("journal");
+        let source = temp_dir.path().join("source");
+        let repo = temp_dir.path().join("repo/s");
+        make_skill(&source);
+
+        let mut plan = Plan::new("install", "s");
+        plan.push(Step::CopyDir {
+            from: source.clone(),
+            to: repo.clone(),
+        });
+        plan.push(Step::CopyDir {
+            from: temp_dir.path().join("missing"),
+            to: temp_dir.path().join("elsewhere"),
+        });
+
+        let err = plan.execute_in(&journal_dir, None, |_| {}).unwrap_err();
+
+        assert!(matches!(err, SikilError::DirectoryNotFound { .. }));
+        assert!(!repo.exists());
+        assert!(source.join("SKILL.md").is_file());
+        assert!(Journal::load_all(&journal_dir).is_empty());
+    }
+
+    #[test]
+    fn test_plan_serializes_steps() {
+        let mut plan = Plan::new("remove", "s");
+        plan.push(Step::RemoveSymlink {
+            link: PathBuf::from("/agent/s"),
+            target: PathBuf::from("/repo/s"),
+        });
+        plan.push(Step::RemoveDir {
+            path: PathBuf::from("/repo/s"),
+        });
+
+        let json = serde_json::to_value(&plan).unwrap();
+        assert_eq!(json["steps"][0]["action"], "remove_symlink");
+        assert_eq!(json["steps"][1]["path"], "/repo/s");
+        assert_eq!(
+            plan.to_string(),
+            "1. remove symlink /agent/s\n2. delete /repo/s\n"
+        );
+    }
+
+    #[test]
+    fn test_step_display() {
+        let step = Step::CreateSymlink {
+            target: PathBuf::from("/repo/s"),
+            link: PathBuf::from("/agent/s"),
+        };
+        assert_eq!(step.to_string(), "symlink /agent/s -> /repo/s");
+        assert_eq!(
+            Step::CopyDir {
+                from: PathBuf::from("/src"),
+                to: PathBuf::from("/repo/s")
+            }
+            .to_string(),
+            "copy /src to /repo/s"
+        );
+    }
+}
@@ -0,0 +1,355 @@
+//! File references in SKILL.md bodies
+//!
+//! Skills point at their bundled files with Markdown links
+//! (`[setup](scripts/setup.sh)`), inline code (`` `references/api.md` ``) or
+//! plain prose ("see references/api.md"). This module extracts those
+//! references so `validate` can report missing targets and bundled files
+//! that nothing mentions.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Directories whose files are expected to be referenced from SKILL.md
+pub const REFERENCED_DIRS: &[&str] = &["scripts", "references"];
+
+/// How a reference was written in the Markdown source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceKind {
+    /// Inline link, image or link reference definition
+    Link,
+    /// Inline code span containing a path
+    Code,
+    /// Bare path in prose
+    Text,
+}
+
+/// A relative file reference found in a Markdown document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileReference {
+    /// Target as written, without any `#fragment` or `?query`
+    pub target: String,
+    /// 1-based line number in the source file
+    pub line: usize,
+    /// How the reference was written
+    pub kind: ReferenceKind,
+}
+
+/// `[text](target)` and `![alt](target)`, with an optional title
+static INLINE_LINK: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\]\(\s*(?:<([^>]+)>|([^)\s]+))(?:\s+"[^"]*")?\s*\)"#).unwrap());
+
+/// `[id]: target` link reference definitions
+static LINK_DEFINITION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s{0,3}\[[^\]]+\]:\s*<?([^\s>]+)>?").unwrap());
+
+/// Inline code spans
+static CODE_SPAN: Lazy<Regex> = Lazy::new(|| Regex::new(r"`([^`\n]+)`").unwrap());
+
+/// Extensions that mark a code span outside the skill directories as a file
+const FILE_EXTENSIONS: &[&str] = &[
+    "md", "txt", "json", "yaml", "yml", "toml", "csv", "xml", "html", "css", "sh", "bash", "py",
+    "js", "ts", "rb", "pl", "ps1", "sql", "png", "jpg", "jpeg", "gif", "svg", "pdf",
+];
+
+/// Bare paths into the conventional skill directories
+static BARE_PATH: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|[\s(\[])((?:\./)?(?:scripts|references|assets)/[\w./-]*[\w/])").unwrap()
+});
+
+/// Extracts relative file references from a Markdown document
+///
+/// Fenced code blocks are skipped, as are URLs, anchors and absolute paths.
+///
+/// # Arguments
+///
+/// * `markdown` - The Markdown text (for SKILL.md, the body after the frontmatter)
+/// * `first_line` - The line number of the first line of `markdown` in its file
+pub fn extract_references(markdown: &str, first_line: usize) -> Vec<FileReference> {
+    let mut refs = Vec::new();
+    let mut in_fence = false;
+
+    for (index, line) in markdown.lines().enumerate() {
+        let line_no = first_line + index;
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        let mut push = |target: &str, kind: ReferenceKind| {
+            if let Some(target) = clean_target(target) {
+                refs.push(FileReference {
+                    target,
+                    line: line_no,
+                    kind,
+                });
+            }
+        };
+
+        for cap in INLINE_LINK.captures_iter(line) {
+            if let Some(target) = cap.get(1).or_else(|| cap.get(2)) {
+                push(target.as_str(), ReferenceKind::Link);
+            }
+        }
+        if let Some(cap) = LINK_DEFINITION.captures(line) {
+            push(&cap[1], ReferenceKind::Link);
+            continue;
+        }
+
+        // Code spans are matched separately and removed before looking for bare paths
+        let mut prose = line.to_string();
+        for cap in CODE_SPAN.captures_iter(line) {
+            let code = cap[1].trim();
+            if looks_like_path(code) {
+                push(code, ReferenceKind::Code);
+            }
+            prose = prose.replace(&cap[0], " ");
+        }
+        let prose = INLINE_LINK.replace_all(&prose, " ");
+        for cap in BARE_PATH.captures_iter(&prose) {
+            push(cap[1].trim_end_matches('.'), ReferenceKind::Text);
+        }
+    }
+
+    refs
+}
+
+/// Returns the references whose target does not exist relative to `base_dir`
+pub fn find_broken_references(base_dir: &Path, refs: &[FileReference]) -> Vec<FileReference> {
+    refs.iter()
+        .filter(|r| !base_dir.join(&r.target).exists())
+        .cloned()
+        .collect()
+}
+
+/// Returns files under `scripts/` and `references/` that are not referenced
+///
+/// References are collected from SKILL.md (`skill_refs`) and from any Markdown
+/// file inside `references/`, so documents may link to each other. A reference
+/// to a directory covers every file below it. Returned paths are relative to
+/// the skill directory and sorted.
+pub fn find_unreferenced_files(skill_dir: &Path, skill_refs: &[FileReference]) -> Vec<PathBuf> {
+    let mut referenced: BTreeSet<PathBuf> = skill_refs
+        .iter()
+        .filter_map(|r| normalize(Path::new(&r.target)))
+        .collect();
+
+    let files: Vec<PathBuf> = REFERENCED_DIRS
+        .iter()
+        .flat_map(|dir| {
+            WalkDir::new(skill_dir.join(dir))
+                .follow_links(false)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file())
+                .filter_map(|e| e.path().strip_prefix(skill_dir).ok().map(Path::to_path_buf))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    // Markdown documents in references/ can reference further files
+    for file in files
+        .iter()
+        .filter(|f| f.starts_with("references") && f.extension().is_some_and(|e| e == "md"))
+    {
+        let Ok(content) = std::fs::read_to_string(skill_dir.join(file)) else {
+            continue;
+        };
+        let parent = file.parent().unwrap_or(Path::new(""));
+        referenced.extend(
+            extract_references(&content, 1)
+                .iter()
+                .filter_map(|r| normalize(&parent.join(&r.target))),
+        );
+    }
+
+    let mut unreferenced: Vec<PathBuf> = files
+        .into_iter()
+        .filter(|f| !referenced.iter().any(|r| f.starts_with(r)))
+        .collect();
+    unreferenced.sort();
+    unreferenced
+}
+
+/// Strips anchors and queries and rejects non-relative targets
+fn clean_target(target: &str) -> Option<String> {
+    let target = target.split(['#', '?']).next().unwrap_or("");
+    if target.is_empty()
+        || target.contains("://")
+        || target.starts_with("mailto:")
+        || target.starts_with('/')
+        || target.starts_with('~')
+        || target.starts_with('$')
+        || target.starts_with('<')
+    {
+        return None;
+    }
+    Some(target.replace("%20", " "))
+}
+
+/// Heuristic for inline code spans that name a file rather than a command
+///
+/// Slashes alone are not enough: `origin/main` and `application/json` are
+/// common in code spans. The span must start in a conventional skill
+/// directory or `./`, or end in a known file extension.
+fn looks_like_path(code: &str) -> bool {
+    let shaped = !code.contains(char::is_whitespace)
+        && code.contains('/')
+        && !code.contains("://")
+        && !code.starts_with('-')
+        && code
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '/' | '.' | '_' | '-'));
+    if !shaped {
+        return false;
+    }
+
+    let in_skill_dir = ["./", "scripts/", "references/", "assets/"]
+        .iter()
+        .any(|prefix| code.starts_with(prefix));
+    let has_file_extension = Path::new(code)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| FILE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()));
+    in_skill_dir || has_file_extension
+}
+
+/// Lexically normalizes a relative path, returning None if it escapes its root
+fn normalize(path: &Path) -> Option<PathBuf> {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    return None;
+                }
+            }
+            Component::Normal(c) => out.push(c),
+            _ => return None,
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn targets(markdown: &str) -> Vec<String> {
+        extract_references(markdown, 1)
+            .into_iter()
+            .map(|r| r.target)
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_markdown_links() {
+        let md = "See [the API](references/api.md#auth) and ![diagram](<assets/flow chart.png>).\n";
+        assert_eq!(
+            targets(md),
+            vec!["references/api.md", "assets/flow chart.png"]
+        );
+    }
+
+    #[test]
+    fn test_extract_link_definition() {
+        assert_eq!(
+            targets("[api]: ./references/api.md\n"),
+            vec!["./references/api.md"]
+        );
+    }
+
+    #[test]
+    fn test_extract_code_and_bare_paths() {
+        let md = "Run `scripts/setup.sh` first, then see references/usage.md.\n";
+        let refs = extract_references(md, 10);
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].target, "scripts/setup.sh");
+        assert_eq!(refs[0].kind, ReferenceKind::Code);
+        assert_eq!(refs[1].target, "references/usage.md");
+        assert_eq!(refs[1].kind, ReferenceKind::Text);
+        assert_eq!(refs[1].line, 10);
+    }
+
+    #[test]
+    fn test_extract_skips_urls_anchors_and_fences() {
+        let md = "[site](https://example.com) [top](#top) [abs](/etc/passwd)\n\
+                  `git commit -m x` `--flag/x`\n\
+                  ```\n[inside](scripts/missing.sh)\n```\n";
+        assert!(targets(md).is_empty());
+    }
+
+    #[test]
+    fn test_code_spans_that_are_not_files_are_skipped() {
+        let md = "Push to `origin/main`, send `application/json`, use `feature/login`.\n\
+                  Read `docs/guide.md` and `./notes` and `assets/logo`.\n";
+        assert_eq!(targets(md), vec!["docs/guide.md", "./notes", "assets/logo"]);
+    }
+
+    #[test]
+    fn test_link_not_double_counted_as_bare_path() {
+        assert_eq!(targets("[x](scripts/a.sh)\n"), vec!["scripts/a.sh"]);
+    }
+
+    #[test]
+    fn test_find_broken_references() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("scripts")).unwrap();
+        fs::write(temp_dir.path().join("scripts/ok.sh"), "").unwrap();
+
+        let refs = extract_references("[a](scripts/ok.sh)\n[b](scripts/gone.sh)\n", 5);
+        let broken = find_broken_references(temp_dir.path(), &refs);
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].target, "scripts/gone.sh");
+        assert_eq!(broken[0].line, 6);
+    }
+
+    #[test]
+    fn test_find_unreferenced_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::create_dir_all(dir.join("scripts/lib")).unwrap();
+        fs::create_dir_all(dir.join("references")).unwrap();
+        fs::write(dir.join("scripts/used.sh"), "").unwrap();
+        fs::write(dir.join("scripts/unused.sh"), "").unwrap();
+        fs::write(dir.join("scripts/lib/helper.sh"), "").unwrap();
+        fs::write(
+            dir.join("references/index.md"),
+            "See [details](details.md).",
+        )
+        .unwrap();
+        fs::write(dir.join("references/details.md"), "").unwrap();
+        fs::write(dir.join("references/orphan.md"), "").unwrap();
+
+        let refs = extract_references(
+            "Run `scripts/used.sh`. Helpers live in scripts/lib/. Read references/index.md.\n",
+            1,
+        );
+        let unreferenced = find_unreferenced_files(dir, &refs);
+        assert_eq!(
+            unreferenced,
+            vec![
+                PathBuf::from("references/orphan.md"),
+                PathBuf::from("scripts/unused.sh")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize_rejects_escape() {
+        assert_eq!(
+            normalize(Path::new("./scripts/../scripts/a.sh")),
+            Some(PathBuf::from("scripts/a.sh"))
+        );
+        assert_eq!(normalize(Path::new("../outside")), None);
+    }
+}
@@ -0,0 +1,484 @@
+//! Validation rule registry for SKILL.md linting
+//!
+//! Every check performed by `sikil validate` is identified by a stable rule ID
+//! (e.g. `SK004`) with a default severity. Severities can be overridden per rule
+//! in `~/.sikil/config.toml` (`[lint.rules]`) or in a `.sikil-lint.toml` file in
+//! the skill directory (`[rules]`), and individual findings can be suppressed
+//! inline with `sikil-disable` comments in SKILL.md.
+
+use crate::core::errors::SikilError;
+use crate::core::parser::extract_body;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+
+/// Name of the per-skill lint configuration file
+pub const SKILL_LINT_FILE: &str = ".sikil-lint.toml";
+
+/// Severity of a rule finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Rule is disabled
+    Off,
+    /// Informational finding, never fails validation
+    Info,
+    /// Non-fatal finding
+    Warning,
+    /// Finding that fails validation
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Severity::Off => "off",
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Static description of a validation rule
+#[derive(Debug, Clone, Copy)]
+pub struct Rule {
+    /// Stable rule identifier (e.g. "SK004")
+    pub id: &'static str,
+    /// Short kebab-case rule name
+    pub name: &'static str,
+    /// Severity used when no override is configured
+    pub default_severity: Severity,
+    /// Whether validation stops when this rule fails; fatal rules cannot be reconfigured
+    pub fatal: bool,
+    /// One-line description of what the rule checks
+    pub description: &'static str,
+}
+
+/// All known validation rules, ordered by ID
+pub const RULES: &[Rule] = &[
+    Rule {
+        id: "SK001",
+        name: "skill-md-exists",
+        default_severity: Severity::Error,
+        fatal: true,
+        description: "SKILL.md must exist in the skill directory",
+    },
+    Rule {
+        id: "SK002",
+        name: "frontmatter-valid",
+        default_severity: Severity::Error,
+        fatal: true,
+        description: "SKILL.md must start with valid YAML frontmatter",
+    },
+    Rule {
+        id: "SK003",
+        name: "required-fields",
+        default_severity: Severity::Error,
+        fatal: true,
+        description: "Frontmatter must contain 'name' and 'description'",
+    },
+    Rule {
+        id: "SK004",
+        name: "name-format",
+        default_severity: Severity::Error,
+        fatal: false,
+        description: "Name must be lowercase alphanumeric with single hyphens, max 64 chars",
+    },
+    Rule {
+        id: "SK005",
+        name: "description-length",
+        default_severity: Severity::Error,
+        fatal: false,
+        description: "Description must be 1-1024 characters",
+    },
+    Rule {
+        id: "SK006",
+        name: "version-present",
+        default_severity: Severity::Warning,
+        fatal: false,
+        description: "Frontmatter should declare a 'version'",
+    },
+    Rule {
+        id: "SK007",
+        name: "author-present",
+        default_severity: Severity::Warning,
+        fatal: false,
+        description: "Frontmatter should declare an 'author'",
+    },
+    Rule {
+        id: "SK008",
+        name: "license-present",
+        default_severity: Severity::Warning,
+        fatal: false,
+        description: "Frontmatter should declare a 'license'",
+    },
+    Rule {
+        id: "SK009",
+        name: "name-matches-directory",
+        default_severity: Severity::Off,
+        fatal: false,
+        description: "Skill name should match the name of its directory",
+    },
+    Rule {
+        id: "SK010",
+        name: "broken-reference",
+        default_severity: Severity::Error,
+        fatal: false,
+        description: "Relative links and file paths in the body must point to existing files",
+    },
+    Rule {
+        id: "SK011",
+        name: "unreferenced-file",
+        default_severity: Severity::Warning,
+        fatal: false,
+        description: "Files in scripts/ and references/ should be referenced from SKILL.md",
+    },
+    Rule {
+        id: "SK012",
+        name: "secret-detected",
+        default_severity: Severity::Error,
+        fatal: false,
+        description: "Skill files must not contain credentials or private keys",
+    },
+    Rule {
+        id: "SK013",
+        name: "script-risk-high",
+        default_severity: Severity::Error,
+        fatal: false,
+        description: "Scripts must not pipe downloads into a shell, wipe home or root, or run encoded payloads (validate --scripts)",
+    },
+    Rule {
+        id: "SK014",
+        name: "script-risk-medium",
+        default_severity: Severity::Warning,
+        fatal: false,
+        description: "Scripts should not use sudo, write outside the working directory or decode base64 (validate --scripts)",
+    },
+    Rule {
+        id: "SK015",
+        name: "script-risk-low",
+        default_severity: Severity::Info,
+        fatal: false,
+        description: "Scripts that access the network or eval dynamic code (validate --scripts)",
+    },
+    Rule {
+        id: "SK016",
+        name: "allowed-tools-syntax",
+        default_severity: Severity::Error,
+        fatal: false,
+        description: "'allowed-tools' must be a string or list of tool names with optional (pattern)",
+    },
+    Rule {
+        id: "SK017",
+        name: "field-type",
+        default_severity: Severity::Warning,
+        fatal: false,
+        description: "'homepage', 'repository' and 'compatibility' must be strings",
+    },
+];
+
+/// Looks up a rule by its ID (case-insensitive)
+pub fn find_rule(id: &str) -> Option<&'static Rule> {
+    RULES.iter().find(|r| r.id.eq_ignore_ascii_case(id))
+}
+
+/// Per-rule severity overrides
+///
+/// Used both for the `[lint]` section of the global config and for
+/// `.sikil-lint.toml` files in skill directories.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintConfig {
+    /// Severity overrides indexed by rule ID
+    #[serde(default)]
+    pub rules: BTreeMap<String, Severity>,
+}
+
+impl LintConfig {
+    /// Returns true if no overrides are configured
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Loads `.sikil-lint.toml` from a skill directory, if present
+    ///
+    /// # Errors
+    ///
+    /// Returns `SikilError::ConfigError` if the file cannot be read or parsed.
+    pub fn load_from_skill_dir(skill_dir: &Path) -> Result<Option<Self>, SikilError> {
+        let path = skill_dir.join(SKILL_LINT_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&path).map_err(|e| SikilError::ConfigError {
+            reason: format!("failed to read {}: {}", path.display(), e),
+        })?;
+        let config = toml::from_str(&content).map_err(|e| SikilError::ConfigError {
+            reason: format!("invalid {}: {}", path.display(), e),
+        })?;
+
+        Ok(Some(config))
+    }
+}
+
+/// Effective severities after applying all overrides
+#[derive(Debug, Clone)]
+pub struct RuleSet {
+    severities: BTreeMap<&'static str, Severity>,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self {
+            severities: RULES.iter().map(|r| (r.id, r.default_severity)).collect(),
+        }
+    }
+}
+
+impl RuleSet {
+    /// Builds a rule set from layered overrides; later layers win
+    ///
+    /// # Errors
+    ///
+    /// Returns `SikilError::ConfigError` if an override names an unknown
+    /// rule or tries to change the severity of a fatal rule.
+    pub fn from_overrides(layers: &[&LintConfig]) -> Result<Self, SikilError> {
+        let mut set = Self::default();
+
+        for layer in layers {
+            for (id, severity) in &layer.rules {
+                let rule = find_rule(id).ok_or_else(|| SikilError::ConfigError {
+                    reason: format!("unknown lint rule '{}'", id),
+                })?;
+                if rule.fatal && *severity != rule.default_severity {
+                    return Err(SikilError::ConfigError {
+                        reason: format!(
+                            "lint rule {} ({}) cannot be reconfigured",
+                            rule.id, rule.name
+                        ),
+                    });
+                }
+                set.severities.insert(rule.id, *severity);
+            }
+        }
+
+        Ok(set)
+    }
+
+    /// Returns the effective severity of a rule
+    pub fn severity(&self, id: &str) -> Severity {
+        find_rule(id)
+            .and_then(|r| self.severities.get(r.id).copied())
+            .unwrap_or(Severity::Off)
+    }
+}
+
+/// Inline suppressions parsed from SKILL.md comments
+///
+/// Supported forms (in YAML `#` comments or HTML `<!-- -->` comments):
+/// - `sikil-disable SK006 SK007` suppresses rules for the whole file
+/// - `sikil-disable-next-line SK004` suppresses rules for the following line
+///
+/// Rule IDs may be separated by spaces or commas.
+#[derive(Debug, Clone, Default)]
+pub struct Suppressions {
+    file: BTreeSet<String>,
+    lines: BTreeMap<usize, BTreeSet<String>>,
+}
+
+impl Suppressions {
+    /// Parses suppression comments from SKILL.md content
+    pub fn parse(content: &str) -> Self {
+        let mut suppressions = Self::default();
+
+        for (index, line) in content.lines().enumerate() {
+            // Line numbers are 1-based, so the next line is index + 2
+            if let Some(ids) = directive_ids(line, "sikil-disable-next-line") {
+                suppressions.lines.entry(index + 2).or_default().extend(ids);
+            } else if let Some(ids) = directive_ids(line, "sikil-disable") {
+                suppressions.file.extend(ids);
+            }
+        }
+
+        suppressions
+    }
+
+    /// Returns true if the rule is suppressed for the given line (or file-wide)
+    pub fn is_suppressed(&self, id: &str, line: Option<usize>) -> bool {
+        let id = id.to_ascii_uppercase();
+        if self.file.contains(&id) {
+            return true;
+        }
+        line.and_then(|l| self.lines.get(&l))
+            .is_some_and(|ids| ids.contains(&id))
+    }
+}
+
+/// Extracts rule IDs following `directive` in a comment line
+fn directive_ids(line: &str, directive: &str) -> Option<Vec<String>> {
+    let trimmed = line.trim();
+    let comment = if let Some(rest) = trimmed.strip_prefix("<!--") {
+        rest.trim_end().strip_suffix("-->").unwrap_or(rest)
+    } else {
+        trimmed.strip_prefix('#')?
+    };
+
+    let rest = comment.trim().strip_prefix(directive)?;
+    // Reject prefixes of longer directives (sikil-disable vs sikil-disable-next-line)
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    Some(
+        rest.split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_ascii_uppercase())
+            .collect(),
+    )
+}
+
+/// A single rule violation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Finding {
+    /// ID of the rule that produced this finding
+    pub rule: String,
+    /// Effective severity of the finding
+    pub severity: Severity,
+    /// Human-readable message
+    pub message: String,
+    /// File the finding refers to, relative to the skill directory (None for SKILL.md)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// 1-based line in the file, if the finding is tied to a line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+}
+
+/// Returns the 1-based line of a top-level frontmatter key, if present
+///
+/// Only the frontmatter block is searched, so a body line that happens to
+/// start with `key:` is never reported.
+pub fn frontmatter_key_line(content: &str, key: &str) -> Option<usize> {
+    let prefix = format!("{}:", key);
+    let (_, body_line) = extract_body(content).ok()?;
+    content
+        .lines()
+        .take(body_line - 1)
+        .position(|l| l.starts_with(&prefix))
+        .map(|i| i + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_ids_are_unique_and_sorted() {
+        let ids: Vec<&str> = RULES.iter().map(|r| r.id).collect();
+        let mut sorted = ids.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(ids, sorted);
+    }
+
+    #[test]
+    fn test_frontmatter_key_line_ignores_body() {
+        let content = "---\nname: s\ndescription: d\n---\nversion: 1.0\n";
+        assert_eq!(frontmatter_key_line(content, "name"), Some(2));
+        assert_eq!(frontmatter_key_line(content, "description"), Some(3));
+        assert_eq!(frontmatter_key_line(content, "version"), None);
+        assert_eq!(
+            frontmatter_key_line("\n---\nname: s\n---\n", "name"),
+            Some(3)
+        );
+        assert_eq!(frontmatter_key_line("name: s\n", "name"), None);
+    }
+
+    #[test]
+    fn test_find_rule_case_insensitive() {
+        assert_eq!(find_rule("sk004").unwrap().name, "name-format");
+        assert!(find_rule("SK999").is_none());
+    }
+
+    #[test]
+    fn test_rule_set_defaults() {
+        let set = RuleSet::default();
+        assert_eq!(set.severity("SK004"), Severity::Error);
+        assert_eq!(set.severity("SK006"), Severity::Warning);
+        assert_eq!(set.severity("SK009"), Severity::Off);
+        assert_eq!(set.severity("SK999"), Severity::Off);
+    }
+
+    #[test]
+    fn test_rule_set_layers_later_wins() {
+        let mut global = LintConfig::default();
+        global.rules.insert("SK006".to_string(), Severity::Error);
+        global.rules.insert("SK007".to_string(), Severity::Off);
+        let mut local = LintConfig::default();
+        local.rules.insert("sk006".to_string(), Severity::Info);
+
+        let set = RuleSet::from_overrides(&[&global, &local]).unwrap();
+        assert_eq!(set.severity("SK006"), Severity::Info);
+        assert_eq!(set.severity("SK007"), Severity::Off);
+    }
+
+    #[test]
+    fn test_rule_set_rejects_unknown_rule() {
+        let mut config = LintConfig::default();
+        config.rules.insert("SK999".to_string(), Severity::Off);
+        let err = RuleSet::from_overrides(&[&config]).unwrap_err();
+        assert!(err.to_string().contains("unknown lint rule 'SK999'"));
+    }
+
+    #[test]
+    fn test_rule_set_rejects_fatal_override() {
+        let mut config = LintConfig::default();
+        config.rules.insert("SK001".to_string(), Severity::Off);
+        assert!(RuleSet::from_overrides(&[&config]).is_err());
+    }
+
+    #[test]
+    fn test_lint_config_from_toml() {
+        let config: LintConfig = toml::from_str("[rules]\nSK006 = \"off\"\n").unwrap();
+        assert_eq!(config.rules.get("SK006"), Some(&Severity::Off));
+        assert!(toml::from_str::<LintConfig>("[rules]\nSK006 = \"fatal\"\n").is_err());
+    }
+
+    #[test]
+    fn test_suppressions_file_wide() {
+        let content =
+            "---\nname: x\n# sikil-disable SK006, sk007\n---\n<!-- sikil-disable SK008 -->\n";
+        let s = Suppressions::parse(content);
+        assert!(s.is_suppressed("SK006", None));
+        assert!(s.is_suppressed("SK007", Some(2)));
+        assert!(s.is_suppressed("SK008", None));
+        assert!(!s.is_suppressed("SK004", None));
+    }
+
+    #[test]
+    fn test_suppressions_next_line() {
+        let content = "---\n# sikil-disable-next-line SK004\nname: Bad_Name\ndescription: d\n---\n";
+        let s = Suppressions::parse(content);
+        assert!(s.is_suppressed("SK004", Some(3)));
+        assert!(!s.is_suppressed("SK004", Some(4)));
+        assert!(!s.is_suppressed("SK004", None));
+    }
+
+    #[test]
+    fn test_directive_requires_word_boundary() {
+        assert!(directive_ids("# sikil-disabled SK004", "sikil-disable").is_none());
+        assert!(directive_ids("plain sikil-disable SK004", "sikil-disable").is_none());
+    }
+
+    #[test]
+    fn test_frontmatter_key_line() {
+        let content = "---\nname: x\ndescription: y\n---\n";
+        assert_eq!(frontmatter_key_line(content, "name"), Some(2));
+        assert_eq!(frontmatter_key_line(content, "description"), Some(3));
+        assert_eq!(frontmatter_key_line(content, "version"), None);
+    }
+}
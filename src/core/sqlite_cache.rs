@@ -0,0 +1,529 @@
+//! SQLite backend for the scan cache.
+//!
+//! Entries live in a single `skill_cache` table keyed by directory path, so
+//! lookups use the primary key index and updates touch only the affected
+//! rows. The database runs in WAL mode with a busy timeout, which lets
+//! several sikil processes read and write the cache at the same time.
+
+use crate::core::cache::{Cache, CacheHealth, JsonCache, ScanEntry, MAX_HASH_SIZE};
+use crate::core::skill::SkillMetadata;
+use crate::core::SikilError;
+use crate::utils::paths::{ensure_dir_exists, get_sqlite_cache_path};
+use fs_err as fs;
+use rusqlite::{Connection, OpenFlags, OptionalExtension, Row, ToSql, TransactionBehavior};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+/// Schema version stored in `PRAGMA user_version`.
+/// Increment this when the schema changes to drop and recreate the table.
+const SCHEMA_VERSION: i64 = 1;
+
+/// How long to wait for another process to release the database
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Columns selected for a `ScanEntry`, in the order `entry_from_row` reads them
+const ENTRY_COLUMNS: &str =
+    "path, mtime, size, dir_mtime, content_hash, cached_at, skill_name, is_valid_skill, metadata";
+
+/// SQLite-based cache implementation.
+pub struct SqliteCache {
+    db_path: PathBuf,
+    conn: Mutex<Connection>,
+}
+
+impl SqliteCache {
+    /// Open or create the cache database at the default location.
+    pub fn open() -> Result<Self, SikilError> {
+        Self::open_at(&get_sqlite_cache_path())
+    }
+
+    /// Open or create the cache database at a specific path.
+    ///
+    /// A file that is not a usable database is deleted and recreated, since
+    /// the cache can always be rebuilt by rescanning.
+    pub fn open_at(path: &Path) -> Result<Self, SikilError> {
+        if let Some(parent) = path.parent() {
+            ensure_dir_exists(parent).map_err(|e| SikilError::ConfigError {
+                reason: format!("failed to create cache directory: {}", e),
+            })?;
+        }
+
+        let conn = match Self::connect(path) {
+            Ok(conn) => conn,
+            Err(_) => {
+                remove_database(path);
+                Self::connect(path).map_err(db_error)?
+            }
+        };
+
+        Ok(Self {
+            db_path: path.to_path_buf(),
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Inspects the database at `path` without creating or modifying it.
+    pub fn check_at(path: &Path) -> CacheHealth {
+        if !path.exists() {
+            return CacheHealth::Missing;
+        }
+
+        let inspect = || -> rusqlite::Result<CacheHealth> {
+            let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+            conn.busy_timeout(BUSY_TIMEOUT)?;
+
+            let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+            if version != SCHEMA_VERSION {
+                return Ok(CacheHealth::VersionMismatch {
+                    found: version as u32,
+                });
+            }
+
+            let integrity: String = conn.query_row("PRAGMA quick_check", [], |row| row.get(0))?;
+            if integrity != "ok" {
+                return Ok(CacheHealth::Corrupt { reason: integrity });
+            }
+
+            let entries: i64 =
+                conn.query_row("SELECT COUNT(*) FROM skill_cache", [], |row| row.get(0))?;
+            Ok(CacheHealth::Healthy {
+                entries: entries as usize,
+            })
+        };
+
+        inspect().unwrap_or_else(|e| CacheHealth::Corrupt {
+            reason: e.to_string(),
+        })
+    }
+
+    /// Imports the entries of a JSON cache file and deletes the file.
+    ///
+    /// Entries already in the database win over imported ones. Returns the
+    /// number of entries imported; a missing file imports nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entries cannot be written or the JSON file
+    /// cannot be removed afterwards.
+    pub fn migrate_from_json(&self, json_path: &Path) -> Result<usize, SikilError> {
+        if !json_path.exists() {
+            return Ok(0);
+        }
+
+        let entries = JsonCache::open_at(json_path)?.entries()?;
+
+        let mut conn = self.lock();
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(db_error)?;
+        let mut imported = 0;
+        {
+            let mut insert = tx
+                .prepare(&format!(
+                    "INSERT OR IGNORE INTO skill_cache ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                    ENTRY_COLUMNS
+                ))
+                .map_err(db_error)?;
+            for entry in &entries {
+                let row = EntryRow::new(entry)?;
+                imported += insert.execute(row.params()).map_err(db_error)?;
+            }
+        }
+        tx.commit().map_err(db_error)?;
+
+        fs::remove_file(json_path).map_err(|e| SikilError::ConfigError {
+            reason: format!("failed to remove migrated cache file: {}", e),
+        })?;
+
+        Ok(imported)
+    }
+
+    /// Opens a connection and brings the schema up to date.
+    fn connect(path: &Path) -> rusqlite::Result<Connection> {
+        let mut conn = Connection::open(path)?;
+        conn.busy_timeout(BUSY_TIMEOUT)?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+
+        // Check and migrate in one write transaction so that concurrent
+        // processes opening a fresh database do not both recreate it
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let version: i64 = tx.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        if version != SCHEMA_VERSION {
+            tx.execute_batch(
+                "DROP TABLE IF EXISTS skill_cache;
+                CREATE TABLE skill_cache (
+                    path TEXT PRIMARY KEY NOT NULL,
+                    mtime INTEGER NOT NULL,
+                    size INTEGER NOT NULL,
+                    dir_mtime INTEGER NOT NULL,
+                    content_hash TEXT NOT NULL,
+                    cached_at INTEGER NOT NULL,
+                    skill_name TEXT,
+                    is_valid_skill INTEGER NOT NULL,
+                    metadata TEXT
+                );
+                CREATE INDEX idx_skill_name ON skill_cache(skill_name);",
+            )?;
+            tx.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+        }
+        tx.commit()?;
+
+        Ok(conn)
+    }
+
+    /// Locks the connection, recovering it if another thread panicked.
+    fn lock(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Cache for SqliteCache {
+    fn path(&self) -> &Path {
+        &self.db_path
+    }
+
+    fn check(&self) -> CacheHealth {
+        Self::check_at(&self.db_path)
+    }
+
+    fn get(&self, path: &Path) -> Result<Option<ScanEntry>, SikilError> {
+        let path_str = path_to_str(path)?;
+
+        let entry = self
+            .lock()
+            .query_row(
+                &format!("SELECT {} FROM skill_cache WHERE path = ?1", ENTRY_COLUMNS),
+                [path_str],
+                entry_from_row,
+            )
+            .optional()
+            .map_err(db_error)?;
+
+        // Modified or gone, return None to trigger rescan
+        Ok(entry.filter(ScanEntry::is_fresh))
+    }
+
+    fn put(&self, entry: &ScanEntry) -> Result<(), SikilError> {
+        self.put_all(std::slice::from_ref(entry))
+    }
+
+    fn put_all(&self, entries: &[ScanEntry]) -> Result<(), SikilError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        // Validate everything before writing so a bad entry leaves no partial update
+        let rows = entries
+            .iter()
+            .map(EntryRow::new)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut conn = self.lock();
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(db_error)?;
+        {
+            let mut upsert = tx
+                .prepare(&format!(
+                    "INSERT OR REPLACE INTO skill_cache ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                    ENTRY_COLUMNS
+                ))
+                .map_err(db_error)?;
+            for row in &rows {
+                upsert.execute(row.params()).map_err(db_error)?;
+            }
+        }
+        tx.commit().map_err(db_error)
+    }
+
+    fn entries(&self) -> Result<Vec<ScanEntry>, SikilError> {
+        let conn = self.lock();
+        let mut select = conn
+            .prepare(&format!(
+                "SELECT {} FROM skill_cache ORDER BY path",
+                ENTRY_COLUMNS
+            ))
+            .map_err(db_error)?;
+        let entries = select
+            .query_map([], entry_from_row)
+            .map_err(db_error)?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .map_err(db_error)?;
+        Ok(entries)
+    }
+
+    fn invalidate(&self, path: &Path) -> Result<(), SikilError> {
+        let path_str = path_to_str(path)?;
+        self.lock()
+            .execute("DELETE FROM skill_cache WHERE path = ?1", [path_str])
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    fn clean_stale(&self) -> Result<usize, SikilError> {
+        let stale_paths = self.stale_paths();
+        if stale_paths.is_empty() {
+            return Ok(0);
+        }
+
+        let mut conn = self.lock();
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(db_error)?;
+        let mut removed = 0;
+        {
+            let mut delete = tx
+                .prepare("DELETE FROM skill_cache WHERE path = ?1")
+                .map_err(db_error)?;
+            for path in &stale_paths {
+                removed += delete.execute([path_to_str(path)?]).map_err(db_error)?;
+            }
+        }
+        tx.commit().map_err(db_error)?;
+
+        Ok(removed)
+    }
+
+    fn clear(&self) -> Result<(), SikilError> {
+        self.lock()
+            .execute("DELETE FROM skill_cache", [])
+            .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+/// A `ScanEntry` converted to column values
+struct EntryRow<'a> {
+    path: &'a str,
+    mtime: i64,
+    size: i64,
+    dir_mtime: i64,
+    cached_at: i64,
+    entry: &'a ScanEntry,
+    metadata: Option<String>,
+}
+
+impl<'a> EntryRow<'a> {
+    fn new(entry: &'a ScanEntry) -> Result<Self, SikilError> {
+        // Validate content hash length
+        if entry.content_hash.len() > MAX_HASH_SIZE {
+            return Err(SikilError::ValidationError {
+                reason: format!("content hash too long: {}", entry.content_hash.len()),
+            });
+        }
+
+        let metadata = entry
+            .metadata
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| SikilError::ConfigError {
+                reason: format!("failed to serialize cached metadata: {}", e),
+            })?;
+
+        Ok(Self {
+            path: path_to_str(&entry.path)?,
+            mtime: entry.mtime as i64,
+            size: entry.size as i64,
+            dir_mtime: entry.dir_mtime as i64,
+            cached_at: entry.cached_at as i64,
+            entry,
+            metadata,
+        })
+    }
+
+    fn params(&self) -> [&dyn ToSql; 9] {
+        [
+            &self.path,
+            &self.mtime,
+            &self.size,
+            &self.dir_mtime,
+            &self.entry.content_hash,
+            &self.cached_at,
+            &self.entry.skill_name,
+            &self.entry.is_valid_skill,
+            &self.metadata,
+        ]
+    }
+}
+
+/// Builds a `ScanEntry` from a row selected with `ENTRY_COLUMNS`.
+fn entry_from_row(row: &Row<'_>) -> rusqlite::Result<ScanEntry> {
+    let path: String = row.get(0)?;
+    let metadata: Option<String> = row.get(8)?;
+
+    Ok(ScanEntry {
+        path: PathBuf::from(path),
+        mtime: row.get::<_, i64>(1)? as u64,
+        size: row.get::<_, i64>(2)? as u64,
+        dir_mtime: row.get::<_, i64>(3)? as u64,
+        content_hash: row.get(4)?,
+        cached_at: row.get::<_, i64>(5)? as u64,
+        skill_name: row.get(6)?,
+        is_valid_skill: row.get(7)?,
+        // Unreadable metadata just means the entry is treated as a miss
+        metadata: metadata.and_then(|json| serde_json::from_str::<SkillMetadata>(&json).ok()),
+    })
+}
+
+fn path_to_str(path: &Path) -> Result<&str, SikilError> {
+    path.to_str().ok_or_else(|| SikilError::ConfigError {
+        reason: format!("invalid path: {}", path.display()),
+    })
+}
+
+fn db_error(e: rusqlite::Error) -> SikilError {
+    SikilError::ConfigError {
+        reason: format!("cache database error: {}", e),
+    }
+}
+
+/// Deletes a database file together with its WAL and shared-memory files.
+fn remove_database(path: &Path) {
+    let _ = fs::remove_file(path);
+    for suffix in ["-wal", "-shm"] {
+        let mut sidecar = path.as_os_str().to_owned();
+        sidecar.push(suffix);
+        let _ = fs::remove_file(PathBuf::from(sidecar));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cache::SkillStamp;
+    use tempfile::TempDir;
+
+    /// Creates a skill directory and a matching, fresh cache entry for it
+    fn create_skill(root: &Path, name: &str) -> ScanEntry {
+        let dir = root.join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("SKILL.md"), format!("# {}", name)).unwrap();
+        let stamp = SkillStamp::read(&dir).unwrap();
+
+        ScanEntry {
+            path: dir,
+            mtime: stamp.mtime,
+            size: stamp.size,
+            dir_mtime: stamp.dir_mtime,
+            content_hash: "abc123".to_string(),
+            cached_at: 1,
+            skill_name: Some(name.to_string()),
+            is_valid_skill: true,
+            metadata: Some(
+                SkillMetadata::new(name.to_string(), "A cached skill".to_string()).with_extra(
+                    "x-owner".to_string(),
+                    serde_yaml::Value::String("team-a".to_string()),
+                ),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_sqlite_cache_put_and_get() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = SqliteCache::open_at(&temp_dir.path().join("cache.db")).unwrap();
+        let entry = create_skill(temp_dir.path(), "db-skill");
+
+        cache.put(&entry).unwrap();
+
+        let cached = cache.get(&entry.path).unwrap().unwrap();
+        assert_eq!(cached.stamp(), entry.stamp());
+        assert_eq!(cached.skill_name.as_deref(), Some("db-skill"));
+        let metadata = cached.metadata.unwrap();
+        assert_eq!(metadata.description, "A cached skill");
+        assert!(metadata.extra.contains_key("x-owner"));
+    }
+
+    #[test]
+    fn test_sqlite_cache_get_rejects_changed_skill() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = SqliteCache::open_at(&temp_dir.path().join("cache.db")).unwrap();
+        let mut entry = create_skill(temp_dir.path(), "db-skill");
+        entry.size += 1;
+
+        cache.put(&entry).unwrap();
+
+        assert!(cache.get(&entry.path).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_sqlite_cache_invalidate_clean_stale_and_clear() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = SqliteCache::open_at(&temp_dir.path().join("cache.db")).unwrap();
+        let kept = create_skill(temp_dir.path(), "kept");
+        let gone = create_skill(temp_dir.path(), "gone");
+        let dropped = create_skill(temp_dir.path(), "dropped");
+        cache
+            .put_all(&[kept.clone(), gone.clone(), dropped.clone()])
+            .unwrap();
+
+        cache.invalidate(&dropped.path).unwrap();
+        fs::remove_dir_all(&gone.path).unwrap();
+        assert_eq!(cache.clean_stale().unwrap(), 1);
+
+        let paths: Vec<PathBuf> = cache
+            .entries()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.path)
+            .collect();
+        assert_eq!(paths, vec![kept.path]);
+
+        cache.clear().unwrap();
+        assert!(cache.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sqlite_cache_shared_between_handles() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("cache.db");
+        let first = SqliteCache::open_at(&db_path).unwrap();
+        let second = SqliteCache::open_at(&db_path).unwrap();
+        let entry = create_skill(temp_dir.path(), "shared");
+
+        first.put(&entry).unwrap();
+
+        assert!(second.get(&entry.path).unwrap().is_some());
+        assert_eq!(
+            SqliteCache::check_at(&db_path),
+            CacheHealth::Healthy { entries: 1 }
+        );
+    }
+
+    #[test]
+    fn test_sqlite_cache_rebuilds_corrupt_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("cache.db");
+        fs::write(&db_path, "not a database").unwrap();
+
+        assert!(matches!(
+            SqliteCache::check_at(&db_path),
+            CacheHealth::Corrupt { .. }
+        ));
+
+        let cache = SqliteCache::open_at(&db_path).unwrap();
+        assert!(cache.entries().unwrap().is_empty());
+        assert_eq!(cache.check(), CacheHealth::Healthy { entries: 0 });
+    }
+
+    #[test]
+    fn test_sqlite_cache_migrates_json_cache() {
+        let temp_dir = TempDir::new().unwrap();
+        let json_path = temp_dir.path().join("cache.json");
+        let entry = create_skill(temp_dir.path(), "migrated");
+        JsonCache::open_at(&json_path).unwrap().put(&entry).unwrap();
+
+        let cache = SqliteCache::open_at(&temp_dir.path().join("cache.db")).unwrap();
+        assert_eq!(cache.migrate_from_json(&json_path).unwrap(), 1);
+
+        assert!(!json_path.exists());
+        let cached = cache.get(&entry.path).unwrap().unwrap();
+        assert_eq!(cached.metadata.unwrap().name, "migrated");
+
+        // Nothing left to migrate
+        assert_eq!(cache.migrate_from_json(&json_path).unwrap(), 0);
+    }
+}
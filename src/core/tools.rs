@@ -0,0 +1,287 @@
+//! Tool permissions declared with `allowed-tools`
+//!
+//! Claude Code skills can pre-approve tools in their frontmatter, either as a
+//! comma- or space-separated string (`Read, Grep, Bash(git:*)`) or as a YAML
+//! list. Each entry is a tool name with an optional argument pattern in
+//! parentheses. Grants of shell or network access are security relevant, so
+//! they are classified here and flagged by `sikil permissions`.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Tools that run arbitrary shell commands
+const SHELL_TOOLS: &[&str] = &["Bash"];
+
+/// Tools that fetch content from the network
+const NETWORK_TOOLS: &[&str] = &["WebFetch", "WebSearch"];
+
+/// Commands that access the network when allowed through a shell pattern
+const NETWORK_COMMANDS: &[&str] = &[
+    "curl", "wget", "ssh", "scp", "sftp", "rsync", "nc", "ncat", "netcat", "telnet", "ftp", "http",
+    "https", "aria2c",
+];
+
+/// A single `allowed-tools` entry, such as `Read` or `Bash(git:*)`
+///
+/// Serialized as the string it was written as.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::core::tools::{ToolCapability, ToolGrant};
+///
+/// let grant: ToolGrant = "Bash(curl:*)".parse().unwrap();
+/// assert_eq!(grant.tool, "Bash");
+/// assert_eq!(grant.pattern.as_deref(), Some("curl:*"));
+/// assert_eq!(
+///     grant.capabilities(),
+///     vec![ToolCapability::Shell, ToolCapability::Network]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct ToolGrant {
+    /// Tool name (e.g. `Bash`, `Read`, `mcp__github__create_issue`)
+    pub tool: String,
+    /// Argument pattern the grant is restricted to (e.g. `git:*`)
+    pub pattern: Option<String>,
+}
+
+/// Security-relevant capability granted by a tool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToolCapability {
+    /// Runs shell commands
+    Shell,
+    /// Accesses the network
+    Network,
+}
+
+impl fmt::Display for ToolCapability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ToolCapability::Shell => write!(f, "shell"),
+            ToolCapability::Network => write!(f, "network"),
+        }
+    }
+}
+
+impl ToolGrant {
+    /// Returns the shell and network capabilities this grant gives, in order
+    ///
+    /// An unrestricted shell (`Bash` or `Bash(*)`) can reach the network too.
+    pub fn capabilities(&self) -> Vec<ToolCapability> {
+        let mut capabilities = Vec::new();
+        if SHELL_TOOLS.contains(&self.tool.as_str()) {
+            capabilities.push(ToolCapability::Shell);
+            let command = self
+                .pattern
+                .as_deref()
+                .filter(|p| p.trim() != "*")
+                .map(|p| p.split([' ', ':']).next().unwrap_or_default());
+            if command.map_or(true, |c| NETWORK_COMMANDS.contains(&c)) {
+                capabilities.push(ToolCapability::Network);
+            }
+        } else if NETWORK_TOOLS.contains(&self.tool.as_str()) {
+            capabilities.push(ToolCapability::Network);
+        }
+        capabilities
+    }
+}
+
+impl fmt::Display for ToolGrant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.pattern {
+            Some(ref pattern) => write!(f, "{}({})", self.tool, pattern),
+            None => write!(f, "{}", self.tool),
+        }
+    }
+}
+
+impl FromStr for ToolGrant {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (tool, pattern) = match s.find('(') {
+            Some(open) => {
+                let pattern = s[open + 1..]
+                    .strip_suffix(')')
+                    .ok_or_else(|| format!("'{}' is missing a closing ')'", s))?;
+                if pattern.trim().is_empty() {
+                    return Err(format!("'{}' has an empty pattern", s));
+                }
+                (&s[..open], Some(pattern.to_string()))
+            }
+            None if s.contains(')') => return Err(format!("'{}' has an unmatched ')'", s)),
+            None => (s, None),
+        };
+
+        let valid_name = tool.starts_with(|c: char| c.is_ascii_alphabetic())
+            && tool
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid_name {
+            return Err(format!("'{}' is not a valid tool name", tool));
+        }
+
+        Ok(Self {
+            tool: tool.to_string(),
+            pattern,
+        })
+    }
+}
+
+impl From<ToolGrant> for String {
+    fn from(grant: ToolGrant) -> Self {
+        grant.to_string()
+    }
+}
+
+impl TryFrom<String> for ToolGrant {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Parses the value of an `allowed-tools` frontmatter field
+///
+/// Accepts a string of entries separated by commas or whitespace (outside
+/// parentheses), or a list of strings, each holding one or more entries.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::core::tools::parse_allowed_tools;
+///
+/// let value = serde_yaml::Value::from("Read, Grep Bash(npm run test:*)");
+/// let grants = parse_allowed_tools(&value).unwrap();
+/// assert_eq!(grants.len(), 3);
+/// assert_eq!(grants[2].to_string(), "Bash(npm run test:*)");
+/// ```
+///
+/// # Errors
+///
+/// Returns a description of the problem if the value is not a string or a
+/// list of strings, or an entry is malformed.
+pub fn parse_allowed_tools(value: &serde_yaml::Value) -> Result<Vec<ToolGrant>, String> {
+    let parts: Vec<&str> = match value {
+        serde_yaml::Value::String(s) => vec![s.as_str()],
+        serde_yaml::Value::Sequence(items) => items
+            .iter()
+            .map(|item| {
+                item.as_str()
+                    .ok_or_else(|| "list entries must be strings".to_string())
+            })
+            .collect::<Result<_, _>>()?,
+        _ => return Err("expected a string or a list of strings".to_string()),
+    };
+
+    let mut grants = Vec::new();
+    for part in parts {
+        for entry in split_entries(part)? {
+            grants.push(entry.parse()?);
+        }
+    }
+    Ok(grants)
+}
+
+/// Splits a string at commas and whitespace that are not inside parentheses
+fn split_entries(s: &str) -> Result<Vec<&str>, String> {
+    let mut entries = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| format!("unmatched ')' in '{}'", s.trim()))?;
+            }
+            c if depth == 0 && (c == ',' || c.is_whitespace()) => {
+                if !s[start..i].trim().is_empty() {
+                    entries.push(&s[start..i]);
+                }
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    if depth > 0 {
+        return Err(format!("unclosed '(' in '{}'", s.trim()));
+    }
+    if !s[start..].trim().is_empty() {
+        entries.push(&s[start..]);
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grants(yaml: &str) -> Result<Vec<String>, String> {
+        let value: serde_yaml::Value = serde_yaml::from_str(yaml).unwrap();
+        parse_allowed_tools(&value).map(|g| g.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn test_parse_string_and_list_forms() {
+        assert_eq!(
+            grants("Read, Grep,Glob").unwrap(),
+            vec!["Read", "Grep", "Glob"]
+        );
+        assert_eq!(
+            grants("Bash(git add:*) Bash(git commit:*) Read").unwrap(),
+            vec!["Bash(git add:*)", "Bash(git commit:*)", "Read"]
+        );
+        assert_eq!(
+            grants("[Read, 'Bash(npm run test:*)', mcp__github__create_issue]").unwrap(),
+            vec!["Read", "Bash(npm run test:*)", "mcp__github__create_issue"]
+        );
+        assert!(grants("''").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(grants("Bash(git:*").unwrap_err().contains("unclosed '('"));
+        assert!(grants("Read)").unwrap_err().contains("unmatched ')'"));
+        assert!(grants("Bash()").unwrap_err().contains("empty pattern"));
+        assert!(grants("Bash(git)x").unwrap_err().contains("closing ')'"));
+        assert!(grants("1Read")
+            .unwrap_err()
+            .contains("not a valid tool name"));
+        assert!(grants("[Read, 3]").unwrap_err().contains("must be strings"));
+        assert!(grants("{Read: true}")
+            .unwrap_err()
+            .contains("expected a string"));
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let caps = |s: &str| s.parse::<ToolGrant>().unwrap().capabilities();
+        use ToolCapability::{Network, Shell};
+
+        assert_eq!(caps("Read"), vec![]);
+        assert_eq!(caps("Bash"), vec![Shell, Network]);
+        assert_eq!(caps("Bash(*)"), vec![Shell, Network]);
+        assert_eq!(caps("Bash(git status:*)"), vec![Shell]);
+        assert_eq!(caps("Bash(wget -q:*)"), vec![Shell, Network]);
+        assert_eq!(caps("WebFetch"), vec![Network]);
+        assert_eq!(caps("WebSearch(domain:example.com)"), vec![Network]);
+    }
+
+    #[test]
+    fn test_serializes_as_string() {
+        let grant: ToolGrant = "Bash(git:*)".parse().unwrap();
+        assert_eq!(serde_json::to_string(&grant).unwrap(), "\"Bash(git:*)\"");
+        let parsed: ToolGrant = serde_json::from_str("\"Bash(git:*)\"").unwrap();
+        assert_eq!(parsed, grant);
+        assert!(serde_json::from_str::<ToolGrant>("\"Bash(\"").is_err());
+    }
+}
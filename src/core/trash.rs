@@ -0,0 +1,432 @@
+//! Trash for removed skills
+//!
+//! `sikil remove` moves what it deletes into
+//! `~/.sikil/trash/<timestamp>-<name>/` instead: the repository copy goes to
+//! `repo/`, unmanaged installation directories to `installations/<n>/`, and
+//! `trash.json` records where each installation lived so the skill can be
+//! put back. Entries older than `[trash] retention_days` are deleted for
+//! good.
+
+use crate::core::errors::SikilError;
+use crate::core::plan::{Plan, Step};
+use crate::utils::atomic::atomic_write;
+use crate::utils::paths::{dir_size, ensure_dir_exists};
+use crate::utils::time::format_timestamp;
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Days a trashed skill is kept when `[trash] retention_days` is not set
+pub const DEFAULT_RETENTION_DAYS: u64 = 30;
+
+/// Trash settings (`[trash]` section of the config)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TrashConfig {
+    /// Days to keep removed skills before deleting them; 0 keeps them forever
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u64,
+}
+
+impl Default for TrashConfig {
+    fn default() -> Self {
+        Self {
+            retention_days: DEFAULT_RETENTION_DAYS,
+        }
+    }
+}
+
+impl TrashConfig {
+    /// Returns true if every setting has its default value
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+fn default_retention_days() -> u64 {
+    DEFAULT_RETENTION_DAYS
+}
+
+/// Where a removed installation lived
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashedInstallation {
+    /// Agent the installation belonged to
+    pub agent: String,
+    /// Path of the installation
+    pub path: PathBuf,
+    /// Target of the symlink, or None for a directory kept in the trash
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symlink_target: Option<PathBuf>,
+}
+
+/// Contents of `trash.json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashRecord {
+    /// Name of the removed skill
+    pub name: String,
+    /// Unix timestamp (seconds) when the skill was removed
+    pub removed_at: u64,
+    /// Where the repository copy lived, if it was removed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo_path: Option<PathBuf>,
+    /// Every installation that was removed
+    pub installations: Vec<TrashedInstallation>,
+}
+
+/// A removed skill in the trash
+#[derive(Debug, Clone)]
+pub struct TrashEntry {
+    dir: PathBuf,
+    record: TrashRecord,
+}
+
+impl TrashEntry {
+    /// Prepares an entry in `trash_dir` for a skill removed at `removed_at`
+    ///
+    /// Nothing is written until [`save`](Self::save).
+    pub fn new(trash_dir: &Path, name: &str, removed_at: u64) -> Self {
+        let stamp = format_timestamp(removed_at).replace(['-', ':'], "");
+        let mut dir = trash_dir.join(format!("{}-{}", stamp, name));
+        let mut suffix = 1;
+        while dir.exists() {
+            suffix += 1;
+            dir = trash_dir.join(format!("{}-{}-{}", stamp, name, suffix));
+        }
+        Self {
+            dir,
+            record: TrashRecord {
+                name: name.to_string(),
+                removed_at,
+                repo_path: None,
+                installations: Vec::new(),
+            },
+        }
+    }
+
+    /// Loads every entry in `trash_dir`, oldest first
+    ///
+    /// Directories without a readable `trash.json` are skipped.
+    pub fn load_all(trash_dir: &Path) -> Vec<Self> {
+        let Ok(entries) = fs::read_dir(trash_dir) else {
+            return Vec::new();
+        };
+        let mut trashed: Vec<Self> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter_map(|dir| {
+                let content = fs::read_to_string(dir.join("trash.json")).ok()?;
+                let record = serde_json::from_str(&content).ok()?;
+                Some(Self { dir, record })
+            })
+            .collect();
+        trashed.sort_by(|a, b| (a.record.removed_at, &a.dir).cmp(&(b.record.removed_at, &b.dir)));
+        trashed
+    }
+
+    /// Finds an entry by id, or the most recently removed skill of that name
+    pub fn find(trash_dir: &Path, query: &str) -> Result<Self, SikilError> {
+        let mut trashed = Self::load_all(trash_dir);
+        if let Some(index) = trashed.iter().position(|entry| entry.id() == query) {
+            return Ok(trashed.swap_remove(index));
+        }
+        trashed
+            .into_iter()
+            .rev()
+            .find(|entry| entry.record.name == query)
+            .ok_or_else(|| SikilError::SkillNotFound {
+                name: format!("{} (in trash)", query),
+            })
+    }
+
+    /// Returns the entry's directory name, which identifies it
+    pub fn id(&self) -> String {
+        self.dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    /// Returns the entry's directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the recorded metadata
+    pub fn record(&self) -> &TrashRecord {
+        &self.record
+    }
+
+    /// Returns the disk space the entry uses, in bytes
+    pub fn size(&self) -> u64 {
+        dir_size(&self.dir)
+    }
+
+    /// Adds the step moving the repository copy at `repo` into the entry
+    pub fn trash_repo(&mut self, repo: &Path, plan: &mut Plan) {
+        self.record.repo_path = Some(repo.to_path_buf());
+        plan.push(Step::MoveDir {
+            from: repo.to_path_buf(),
+            to: self.dir.join("repo"),
+        });
+    }
+
+    /// Records a removed installation, adding the step that moves it into
+    /// the entry if it is a directory
+    ///
+    /// Symlinks are only recorded; the caller removes them.
+    pub fn trash_installation(
+        &mut self,
+        agent: &str,
+        path: &Path,
+        symlink_target: Option<&Path>,
+        plan: &mut Plan,
+    ) {
+        if symlink_target.is_none() {
+            plan.push(Step::MoveDir {
+                from: path.to_path_buf(),
+                to: self.installation_store(self.record.installations.len()),
+            });
+        }
+        self.record.installations.push(TrashedInstallation {
+            agent: agent.to_string(),
+            path: path.to_path_buf(),
+            symlink_target: symlink_target.map(Path::to_path_buf),
+        });
+    }
+
+    /// Returns whether anything of the skill is kept in the entry
+    pub fn holds_data(&self) -> bool {
+        self.record.repo_path.is_some()
+            || self
+                .record
+                .installations
+                .iter()
+                .any(|installation| installation.symlink_target.is_none())
+    }
+
+    /// Returns whether everything the entry should hold is still there
+    ///
+    /// An entry stops being intact when `sikil undo` moves its data back.
+    pub fn is_intact(&self) -> bool {
+        let repo_kept = self.record.repo_path.is_none() || self.dir.join("repo").exists();
+        repo_kept
+            && self
+                .record
+                .installations
+                .iter()
+                .enumerate()
+                .filter(|(_, installation)| installation.symlink_target.is_none())
+                .all(|(index, _)| self.installation_store(index).exists())
+    }
+
+    /// Deletes the entry after the moves into it were rolled back, unless
+    /// data was left behind in it
+    pub fn discard_unused(&self) {
+        let left_behind = self.dir.join("repo").exists()
+            || fs::read_dir(self.dir.join("installations"))
+                .is_ok_and(|mut entries| entries.next().is_some());
+        if !left_behind {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    /// Writes `trash.json`
+    pub fn save(&self) -> Result<(), SikilError> {
+        ensure_dir_exists(&self.dir).map_err(|_| SikilError::PermissionDenied {
+            operation: "create trash entry".to_string(),
+            path: self.dir.clone(),
+        })?;
+        let json =
+            serde_json::to_vec_pretty(&self.record).map_err(|e| SikilError::ValidationError {
+                reason: format!("failed to serialize trash entry: {}", e),
+            })?;
+        atomic_write(&self.dir.join("trash.json"), &json)
+    }
+
+    /// Builds the plan that puts the skill back where it was
+    ///
+    /// # Errors
+    ///
+    /// Returns `SikilError::AlreadyExists` if something now occupies a path
+    /// the skill would be restored to, and `SikilError::ValidationError` if
+    /// the entry no longer holds the data (e.g. it was restored by
+    /// `sikil undo`).
+    pub fn restore_plan(&self) -> Result<Plan, SikilError> {
+        let mut plan = Plan::new("restore", &self.record.name);
+        if let Some(repo) = &self.record.repo_path {
+            plan.push(Step::MoveDir {
+                from: self.dir.join("repo"),
+                to: repo.clone(),
+            });
+        }
+        for (index, installation) in self.record.installations.iter().enumerate() {
+            plan.push(match &installation.symlink_target {
+                Some(target) => Step::CreateSymlink {
+                    target: target.clone(),
+                    link: installation.path.clone(),
+                },
+                None => Step::MoveDir {
+                    from: self.installation_store(index),
+                    to: installation.path.clone(),
+                },
+            });
+        }
+
+        let mut missing = Vec::new();
+        let mut occupied = Vec::new();
+        for step in &plan.steps {
+            let (from, to) = match step {
+                Step::MoveDir { from, to } => (Some(from), to),
+                Step::CreateSymlink { link, .. } => (None, link),
+                _ => continue,
+            };
+            if let Some(from) = from.filter(|from| !from.exists()) {
+                missing.push(from.display().to_string());
+            }
+            if to.exists() || to.is_symlink() {
+                occupied.push(to.display().to_string());
+            }
+        }
+
+        if !missing.is_empty() {
+            return Err(SikilError::ValidationError {
+                reason: format!("trash entry is missing {}", missing.join(", ")),
+            });
+        }
+        if !occupied.is_empty() {
+            return Err(SikilError::AlreadyExists {
+                resource: occupied.join(", "),
+            });
+        }
+        Ok(plan)
+    }
+
+    /// Returns whether the entry is older than `retention_days` at `now`
+    ///
+    /// With a retention of 0 days nothing expires.
+    pub fn is_expired(&self, now: u64, retention_days: u64) -> bool {
+        retention_days > 0 && now.saturating_sub(self.record.removed_at) >= retention_days * 86_400
+    }
+
+    /// Deletes the entry and everything kept in it
+    pub fn delete(self) -> Result<(), SikilError> {
+        fs::remove_dir_all(&self.dir).map_err(|_| SikilError::PermissionDenied {
+            operation: "delete trash entry".to_string(),
+            path: self.dir.clone(),
+        })
+    }
+
+    fn installation_store(&self, index: usize) -> PathBuf {
+        self.dir.join("installations").join(index.to_string())
+    }
+}
+
+/// Deletes entries in `trash_dir` older than `retention_days` at `now`,
+/// returning how many were deleted
+pub fn purge_expired(trash_dir: &Path, retention_days: u64, now: u64) -> usize {
+    TrashEntry::load_all(trash_dir)
+        .into_iter()
+        .filter(|entry| entry.is_expired(now, retention_days))
+        .map(TrashEntry::delete)
+        .filter(Result::is_ok)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::symlink::is_symlink;
+    use tempfile::TempDir;
+
+    fn make_skill(dir: &Path) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(dir.join("SKILL.md"), "---\nname: s\ndescription: d\n---\n").unwrap();
+    }
+
+    /// Trashes a managed skill linked from one agent and copied into another
+    fn trash_skill(root: &Path) -> (PathBuf, PathBuf, PathBuf) {
+        let repo = root.join("repo/s");
+        let link = root.join("claude/s");
+        let copy = root.join("windsurf/s");
+        make_skill(&repo);
+        make_skill(&copy);
+        std::fs::create_dir_all(root.join("claude")).unwrap();
+        std::os::unix::fs::symlink(&repo, &link).unwrap();
+
+        let mut entry = TrashEntry::new(&root.join("trash"), "s", 1_714_566_600);
+        let mut plan = Plan::new("remove", "s");
+        plan.push(Step::RemoveSymlink {
+            link: link.clone(),
+            target: repo.clone(),
+        });
+        entry.trash_installation("claude-code", &link, Some(&repo), &mut plan);
+        entry.trash_installation("windsurf", &copy, None, &mut plan);
+        entry.trash_repo(&repo, &mut plan);
+        entry.save().unwrap();
+        plan.execute_in(&root.join("journal"), None, |_| {})
+            .unwrap();
+        (repo, link, copy)
+    }
+
+    #[test]
+    fn test_trash_and_restore() {
+        let temp_dir = TempDir::new().unwrap();
+        let trash_dir = temp_dir.path().join("trash");
+        let (repo, link, copy) = trash_skill(temp_dir.path());
+        assert!(!repo.exists() && !copy.exists() && !is_symlink(&link));
+
+        let entry = TrashEntry::find(&trash_dir, "s").unwrap();
+        assert_eq!(entry.id(), "20240501T123000Z-s");
+        assert!(entry.dir().join("repo/SKILL.md").is_file());
+        assert_eq!(entry.record().installations.len(), 2);
+
+        entry
+            .restore_plan()
+            .unwrap()
+            .execute_in(&temp_dir.path().join("journal"), None, |_| {})
+            .unwrap();
+        entry.delete().unwrap();
+
+        assert!(repo.join("SKILL.md").is_file());
+        assert!(is_symlink(&link));
+        assert!(copy.join("SKILL.md").is_file());
+        assert!(TrashEntry::load_all(&trash_dir).is_empty());
+    }
+
+    #[test]
+    fn test_restore_refuses_occupied_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let (repo, _, _) = trash_skill(temp_dir.path());
+        make_skill(&repo);
+
+        let entry = TrashEntry::find(&temp_dir.path().join("trash"), "s").unwrap();
+        let err = entry.restore_plan().unwrap_err();
+        assert!(matches!(err, SikilError::AlreadyExists { .. }));
+        assert!(err.to_string().contains(&repo.display().to_string()));
+    }
+
+    #[test]
+    fn test_purge_expired() {
+        let temp_dir = TempDir::new().unwrap();
+        let trash_dir = temp_dir.path().join("trash");
+        TrashEntry::new(&trash_dir, "old", 0).save().unwrap();
+        TrashEntry::new(&trash_dir, "new", 10 * 86_400)
+            .save()
+            .unwrap();
+
+        assert_eq!(purge_expired(&trash_dir, 0, 20 * 86_400), 0);
+        assert_eq!(purge_expired(&trash_dir, 15, 20 * 86_400), 1);
+
+        let remaining = TrashEntry::load_all(&trash_dir);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].record().name, "new");
+    }
+
+    #[test]
+    fn test_trash_config_defaults() {
+        let config: TrashConfig = toml::from_str("").unwrap();
+        assert_eq!(config.retention_days, DEFAULT_RETENTION_DAYS);
+        assert!(config.is_default());
+        assert!(toml::from_str::<TrashConfig>("keep = 1").is_err());
+    }
+}
@@ -1,15 +1,24 @@
 use clap::Parser;
-use sikil::cli::Cli;
+use sikil::cli::{Cli, TrashAction};
 use sikil::commands::{
-    execute_adopt, execute_completions, execute_config, execute_install_git, execute_install_local,
-    execute_list, execute_remove, execute_show, execute_sync, execute_unmanage, execute_validate,
-    AdoptArgs, CompletionsArgs, ConfigArgs, InstallArgs, ListArgs, RemoveArgs, ShowArgs, SyncArgs,
-    UnmanageArgs, ValidateArgs,
+    check_interrupted, execute_adopt, execute_cache, execute_completions, execute_config,
+    execute_doctor, execute_gc, execute_install_git, execute_install_local, execute_list,
+    execute_log, execute_meta, execute_permissions, execute_recover, execute_remove,
+    execute_repair, execute_search, execute_show, execute_sync, execute_trash, execute_undo,
+    execute_unmanage, execute_validate, AdoptArgs, CacheArgs, CacheOperation, CompletionsArgs,
+    ConfigArgs, DoctorArgs, GcArgs, InstallArgs, ListArgs, LogArgs, MetaArgs, MetaOperation,
+    PermissionsArgs, RecoverArgs, RemoveArgs, RepairArgs, SearchArgs, ShowArgs, SyncArgs,
+    TrashArgs, TrashOperation, UndoArgs, UnmanageArgs, ValidateArgs,
 };
+use sikil::core::audit::{append_entry, AuditEntry};
 use sikil::core::config::Config;
 use sikil::core::errors::SikilError;
-use sikil::core::skill::Agent;
-use sikil::utils::paths::get_config_path;
+use sikil::core::journal::Recovery;
+use sikil::core::skill::{Agent, Scope};
+use sikil::core::trash::purge_expired;
+use sikil::utils::lock::{lock_timeout, ProcessLock};
+use sikil::utils::paths::{get_config_path, get_lock_path, get_log_path, get_trash_dir};
+use sikil::utils::time::now_secs;
 
 /// Gets the appropriate exit code for an error.
 /// If the error is a `SikilError`, returns the exit code defined by that error type.
@@ -91,14 +100,70 @@ fn main() {
     };
     config.expand_paths();
 
+    if cli.dry_run && !cli.command.supports_dry_run() {
+        let e = SikilError::ValidationError {
+            reason: "--dry-run is not supported by this command".to_string(),
+        };
+        eprintln!("Error: {}", e);
+        std::process::exit(e.exit_code());
+    }
+
+    // Serialize mutating commands across processes; read-only commands and
+    // dry runs never wait
+    let lock = if cli.command.is_mutating() && !cli.dry_run {
+        let lock_path = get_lock_path();
+        let json_mode = cli.json;
+        let acquired = ProcessLock::acquire(&lock_path, lock_timeout(), |pid| {
+            if !json_mode {
+                match pid {
+                    Some(pid) => eprintln!("Waiting for sikil process {} to finish...", pid),
+                    None => eprintln!("Waiting for another sikil process to finish..."),
+                }
+            }
+        });
+        match acquired {
+            Ok(lock) => Some(lock),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(e.exit_code());
+            }
+        }
+    } else {
+        None
+    };
+
+    // Offer to settle operations a crashed or interrupted run left half-done
+    if lock.is_some() && !matches!(cli.command, sikil::cli::Commands::Recover { .. }) {
+        if let Err(e) = check_interrupted(cli.json) {
+            eprintln!("Error: {}", e);
+            std::process::exit(get_exit_code(&e));
+        }
+    }
+
+    // Skills removed longer ago than `[trash] retention_days` are deleted
+    if lock.is_some() {
+        purge_expired(&get_trash_dir(), config.trash.retention_days, now_secs());
+    }
+
+    // Mutating commands are recorded in the audit log; dry runs change nothing
+    let audit = lock
+        .is_some()
+        .then(|| AuditEntry::for_command(&cli.command));
+
     // Dispatch to command handlers
-    match cli.command {
+    let result = match cli.command {
         sikil::cli::Commands::List {
             agent,
             managed,
             unmanaged,
             conflicts,
             duplicates,
+            orphans,
+            tag,
+            author,
+            scope,
+            name,
+            sort,
         } => {
             // Parse agent filter if provided
             let agent_filter = match agent {
@@ -128,12 +193,27 @@ fn main() {
                 unmanaged_only: unmanaged,
                 conflicts_only: conflicts,
                 duplicates_only: duplicates,
+                orphans_only: orphans,
+                tags: tag,
+                author,
+                scope: scope.map(|s| match s.as_str() {
+                    "workspace" => Scope::Workspace,
+                    _ => Scope::Global,
+                }),
+                name_pattern: name,
+                sort: sort.parse().unwrap_or_default(),
                 verbose: cli.verbose,
             };
-            if let Err(e) = execute_list(args, &config) {
-                eprintln!("Error: {}", e);
-                std::process::exit(get_exit_code(&e));
-            }
+            execute_list(args, &config)
+        }
+        sikil::cli::Commands::Search { query, limit } => {
+            let args = SearchArgs {
+                json_mode: cli.json,
+                no_cache: cli.no_cache,
+                query: query.join(" "),
+                limit,
+            };
+            execute_search(args, &config)
         }
         sikil::cli::Commands::Show { name } => {
             let args = ShowArgs {
@@ -141,12 +221,13 @@ fn main() {
                 no_cache: cli.no_cache,
                 name,
             };
-            if let Err(e) = execute_show(args, &config) {
-                eprintln!("Error: {}", e);
-                std::process::exit(get_exit_code(&e));
-            }
+            execute_show(args, &config)
         }
-        sikil::cli::Commands::Install { source, r#to } => {
+        sikil::cli::Commands::Install {
+            source,
+            r#to,
+            allow_secrets,
+        } => {
             // M3-E01-T04: Wire Install Command to CLI
             // M3-E02-T06: Wire Git URL detection to install command
             // Detect if source is a Git URL or local path and dispatch accordingly
@@ -160,55 +241,96 @@ fn main() {
                     Vec::new()
                 };
 
-                if let Err(e) = execute_install_git(&source, agents, &config, cli.json) {
-                    eprintln!("Error: {}", e);
-                    std::process::exit(get_exit_code(&e));
-                }
+                execute_install_git(
+                    &source,
+                    agents,
+                    &config,
+                    cli.json,
+                    allow_secrets,
+                    cli.dry_run,
+                )
             } else {
                 // Local path install
                 let args = InstallArgs {
                     json_mode: cli.json,
+                    dry_run: cli.dry_run,
                     path: source,
                     to,
+                    allow_secrets,
                 };
-                if let Err(e) = execute_install_local(args, &config) {
-                    eprintln!("Error: {}", e);
-                    std::process::exit(get_exit_code(&e));
-                }
+                execute_install_local(args, &config)
             }
         }
-        sikil::cli::Commands::Validate { path } => {
+        sikil::cli::Commands::Validate {
+            path,
+            all,
+            format,
+            scripts,
+            fix,
+            yes,
+        } => {
             let args = ValidateArgs {
                 json_mode: cli.json,
                 path_or_name: path,
+                all,
+                format: format.parse().unwrap_or_default(),
+                scripts,
+                fix,
+                yes,
             };
-            if let Err(e) = execute_validate(args, &config) {
-                eprintln!("Error: {}", e);
-                std::process::exit(get_exit_code(&e));
-            }
+            execute_validate(args, &config)
         }
-        sikil::cli::Commands::Adopt { name, from } => {
+        sikil::cli::Commands::Meta { action } => {
+            let (skill, operation) = match action {
+                sikil::cli::MetaAction::Get { skill, key } => (skill, MetaOperation::Get { key }),
+                sikil::cli::MetaAction::Set {
+                    skill,
+                    key,
+                    value,
+                    yaml,
+                } => (skill, MetaOperation::Set { key, value, yaml }),
+                sikil::cli::MetaAction::Unset { skill, key } => {
+                    (skill, MetaOperation::Unset { key })
+                }
+            };
+            let args = MetaArgs {
+                json_mode: cli.json,
+                skill,
+                operation,
+            };
+            execute_meta(args, &config)
+        }
+        sikil::cli::Commands::Permissions { flagged } => {
+            let args = PermissionsArgs {
+                json_mode: cli.json,
+                no_cache: cli.no_cache,
+                flagged_only: flagged,
+            };
+            execute_permissions(args, &config)
+        }
+        sikil::cli::Commands::Adopt {
+            name,
+            from,
+            allow_secrets,
+        } => {
             let args = AdoptArgs {
                 json_mode: cli.json,
+                dry_run: cli.dry_run,
                 name,
                 from,
+                allow_secrets,
             };
-            if let Err(e) = execute_adopt(args, &config) {
-                eprintln!("Error: {}", e);
-                std::process::exit(get_exit_code(&e));
-            }
+            execute_adopt(args, &config)
         }
         sikil::cli::Commands::Unmanage { name, agent, yes } => {
             let args = UnmanageArgs {
                 json_mode: cli.json,
+                dry_run: cli.dry_run,
                 name,
                 agent,
                 yes,
             };
-            if let Err(e) = execute_unmanage(args, &config) {
-                eprintln!("Error: {}", e);
-                std::process::exit(get_exit_code(&e));
-            }
+            execute_unmanage(args, &config)
         }
         sikil::cli::Commands::Remove {
             name,
@@ -219,28 +341,24 @@ fn main() {
             // M3-E05-T01-S02: Execute remove command
             let args = RemoveArgs {
                 json_mode: cli.json,
+                dry_run: cli.dry_run,
                 name,
                 agent,
                 all,
                 yes,
             };
-            if let Err(e) = execute_remove(args, &config) {
-                eprintln!("Error: {}", e);
-                std::process::exit(get_exit_code(&e));
-            }
+            execute_remove(args, &config)
         }
         sikil::cli::Commands::Sync { name, all, r#to } => {
             // M4-E01-T04: Wire Sync Command to CLI
             let args = SyncArgs {
                 json_mode: cli.json,
+                dry_run: cli.dry_run,
                 name,
                 all,
                 to,
             };
-            if let Err(e) = execute_sync(args, &config, None) {
-                eprintln!("Error: {}", e);
-                std::process::exit(get_exit_code(&e));
-            }
+            execute_sync(args, &config, None)
         }
         sikil::cli::Commands::Config { edit, set } => {
             let (set_key, set_value) = if set.is_empty() {
@@ -256,18 +374,101 @@ fn main() {
                 set_value,
                 json_mode: cli.json,
             };
-            if let Err(e) = execute_config(args) {
-                eprintln!("Error: {}", e);
-                std::process::exit(get_exit_code(&e));
-            }
+            execute_config(args)
+        }
+        sikil::cli::Commands::Doctor => {
+            let args = DoctorArgs {
+                json_mode: cli.json,
+            };
+            execute_doctor(args, &config)
+        }
+        sikil::cli::Commands::Repair => {
+            let args = RepairArgs {
+                json_mode: cli.json,
+                dry_run: cli.dry_run,
+            };
+            execute_repair(args, &config, None)
+        }
+        sikil::cli::Commands::Gc { yes } => {
+            let args = GcArgs {
+                json_mode: cli.json,
+                dry_run: cli.dry_run,
+                yes,
+            };
+            execute_gc(args, &config)
+        }
+        sikil::cli::Commands::Recover { rollback, forward } => {
+            let recovery = if cli.dry_run {
+                None
+            } else if rollback {
+                Some(Recovery::RollBack)
+            } else if forward {
+                Some(Recovery::RollForward)
+            } else {
+                None
+            };
+            let args = RecoverArgs {
+                json_mode: cli.json,
+                recovery,
+            };
+            execute_recover(args)
+        }
+        sikil::cli::Commands::Undo => {
+            let args = UndoArgs {
+                json_mode: cli.json,
+                dry_run: cli.dry_run,
+            };
+            execute_undo(args)
+        }
+        sikil::cli::Commands::Cache { action } => {
+            let operation = match action {
+                sikil::cli::CacheAction::Stats => CacheOperation::Stats,
+                sikil::cli::CacheAction::Clear => CacheOperation::Clear,
+                sikil::cli::CacheAction::Prune => CacheOperation::Prune,
+                sikil::cli::CacheAction::Verify => CacheOperation::Verify,
+            };
+            let args = CacheArgs {
+                json_mode: cli.json,
+                operation,
+            };
+            execute_cache(args, &config)
+        }
+        sikil::cli::Commands::Trash { action } => {
+            let operation = match action {
+                TrashAction::List => TrashOperation::List,
+                TrashAction::Restore { name } => TrashOperation::Restore { name },
+                TrashAction::Empty { yes } => TrashOperation::Empty { yes },
+            };
+            let args = TrashArgs {
+                json_mode: cli.json,
+                dry_run: cli.dry_run,
+                operation,
+            };
+            execute_trash(args, &config)
+        }
+        sikil::cli::Commands::Log { skill, since } => {
+            let args = LogArgs {
+                json_mode: cli.json,
+                skill,
+                since,
+            };
+            execute_log(args)
         }
         sikil::cli::Commands::Completions { shell, output } => {
             let args = CompletionsArgs { shell, output };
-            if let Err(e) = execute_completions(args) {
-                eprintln!("Error: {}", e);
-                std::process::exit(get_exit_code(&e));
-            }
+            execute_completions(args)
         }
+    };
+
+    if let Some(entry) = audit {
+        let entry = entry.finish(&result, &config);
+        if let Err(e) = append_entry(&get_log_path(), &entry) {
+            eprintln!("Warning: failed to write audit log: {}", e);
+        }
+    }
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(get_exit_code(&e));
     }
 }
 
@@ -306,6 +306,50 @@ pub fn safe_remove_dir(path: &Path, confirmed: bool) -> Result<(), SikilError> {
     Ok(())
 }
 
+/// Atomically replaces the contents of a file.
+///
+/// The new contents are written to a temporary file in the same directory,
+/// flushed to disk and renamed over `path`, so readers see either the old or
+/// the new file but never a partial write. Permissions of an existing file
+/// are preserved.
+///
+/// # Errors
+///
+/// Returns `SikilError::PermissionDenied` if the temporary file cannot be
+/// created, written or renamed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sikil::utils::atomic::atomic_write;
+/// use std::path::Path;
+///
+/// atomic_write(Path::new("/path/to/SKILL.md"), b"---\nname: x\n---\n").unwrap();
+/// ```
+pub fn atomic_write(path: &Path, contents: &[u8]) -> Result<(), SikilError> {
+    use std::io::Write;
+
+    let write_error = || SikilError::PermissionDenied {
+        operation: "write file".to_string(),
+        path: path.to_path_buf(),
+    };
+
+    let parent = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+    let mut temp = tempfile::NamedTempFile::new_in(parent).map_err(|_| write_error())?;
+    temp.write_all(contents).map_err(|_| write_error())?;
+    temp.as_file().sync_all().map_err(|_| write_error())?;
+
+    if let Ok(metadata) = std::fs::metadata(path) {
+        std::fs::set_permissions(temp.path(), metadata.permissions()).map_err(|_| write_error())?;
+    }
+
+    temp.persist(path).map_err(|_| write_error())?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -553,4 +597,32 @@ mod tests {
         assert!(result.is_ok());
         assert!(dest_path.join("empty").exists());
     }
+
+    #[test]
+    fn test_atomic_write_replaces_contents() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("SKILL.md");
+        fs::write(&path, "old contents that are longer").unwrap();
+
+        atomic_write(&path, b"new").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+
+        // No temporary files are left behind
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_atomic_write_preserves_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("run.sh");
+        fs::write(&path, "echo old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o750)).unwrap();
+
+        atomic_write(&path, b"echo new").unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o750);
+    }
 }
@@ -0,0 +1,188 @@
+//! Line-based unified diffs
+//!
+//! Used to preview rewrites of user files (such as `validate --fix`) before
+//! they are written.
+
+/// Number of unchanged context lines around each hunk
+const CONTEXT: usize = 3;
+
+/// Above this many line pairs the diff falls back to a single replacement hunk
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Renders a unified diff between two texts
+///
+/// Returns an empty string if the texts have identical lines. `label` is
+/// used for the `---`/`+++` header lines.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::utils::diff::unified_diff;
+///
+/// let diff = unified_diff("a\nb\n", "a\nc\n", "SKILL.md");
+/// assert!(diff.contains("-b\n+c\n"));
+/// ```
+pub fn unified_diff(old: &str, new: &str, label: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let ops = diff_ops(&old_lines, &new_lines);
+
+    if ops.iter().all(|op| *op == Op::Equal) {
+        return String::new();
+    }
+
+    // Position of each op in the old and new files
+    let mut positions = Vec::with_capacity(ops.len());
+    let (mut i, mut j) = (0, 0);
+    for op in &ops {
+        positions.push((i, j));
+        match op {
+            Op::Equal => {
+                i += 1;
+                j += 1;
+            }
+            Op::Delete => i += 1,
+            Op::Insert => j += 1,
+        }
+    }
+
+    let mut out = format!("--- a/{}\n+++ b/{}\n", label, label);
+    let mut k = 0;
+    while k < ops.len() {
+        if ops[k] == Op::Equal {
+            k += 1;
+            continue;
+        }
+
+        // Extend the hunk while changes are separated by at most 2 * CONTEXT lines
+        let start = k.saturating_sub(CONTEXT);
+        let mut last = k;
+        for (idx, op) in ops.iter().enumerate().skip(k + 1) {
+            if *op != Op::Equal {
+                if idx - last - 1 > 2 * CONTEXT {
+                    break;
+                }
+                last = idx;
+            }
+        }
+        let end = (last + 1 + CONTEXT).min(ops.len());
+
+        let (old_start, new_start) = positions[start];
+        let old_count = ops[start..end].iter().filter(|o| **o != Op::Insert).count();
+        let new_count = ops[start..end].iter().filter(|o| **o != Op::Delete).count();
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_start, old_count),
+            hunk_range(new_start, new_count)
+        ));
+
+        for (op, (i, j)) in ops[start..end].iter().zip(&positions[start..end]) {
+            match op {
+                Op::Equal => out.push_str(&format!(" {}\n", old_lines[*i])),
+                Op::Delete => out.push_str(&format!("-{}\n", old_lines[*i])),
+                Op::Insert => out.push_str(&format!("+{}\n", new_lines[*j])),
+            }
+        }
+        k = end;
+    }
+
+    out
+}
+
+/// Formats a hunk range as `start,count` (1-based; 0-based start when empty)
+fn hunk_range(start: usize, count: usize) -> String {
+    if count == 0 {
+        format!("{},0", start)
+    } else {
+        format!("{},{}", start + 1, count)
+    }
+}
+
+/// Computes an edit script with a longest-common-subsequence table
+fn diff_ops(old: &[&str], new: &[&str]) -> Vec<Op> {
+    let (n, m) = (old.len(), new.len());
+    if n.saturating_mul(m) > MAX_LCS_CELLS {
+        let mut ops = vec![Op::Delete; n];
+        ops.extend(std::iter::repeat(Op::Insert).take(m));
+        return ops;
+    }
+
+    // lcs[i][j] = length of the LCS of old[i..] and new[j..]
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            ops.push(Op::Equal);
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push(Op::Delete);
+            i += 1;
+        } else {
+            ops.push(Op::Insert);
+            j += 1;
+        }
+    }
+    ops.extend(std::iter::repeat(Op::Delete).take(n - i));
+    ops.extend(std::iter::repeat(Op::Insert).take(m - j));
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_texts_have_no_diff() {
+        assert_eq!(unified_diff("a\nb\n", "a\nb\n", "f"), "");
+    }
+
+    #[test]
+    fn test_single_change_with_context() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let new = "1\n2\n3\n4\nfive\n6\n7\n8\n9\n";
+        let diff = unified_diff(old, new, "SKILL.md");
+        assert_eq!(
+            diff,
+            "--- a/SKILL.md\n+++ b/SKILL.md\n@@ -2,7 +2,7 @@\n 2\n 3\n 4\n-5\n+five\n 6\n 7\n 8\n"
+        );
+    }
+
+    #[test]
+    fn test_distant_changes_make_separate_hunks() {
+        let old: String = (1..=20).map(|i| format!("{}\n", i)).collect();
+        let new: String = (1..=20)
+            .map(|i| match i {
+                2 => "two\n".to_string(),
+                19 => "nineteen\n".to_string(),
+                _ => format!("{}\n", i),
+            })
+            .collect();
+        let diff = unified_diff(&old, &new, "f");
+        assert_eq!(diff.matches("@@ -").count(), 2);
+    }
+
+    #[test]
+    fn test_insertion_at_start() {
+        let diff = unified_diff("b\n", "a\nb\n", "f");
+        assert!(diff.contains("@@ -1,1 +1,2 @@\n+a\n b\n"));
+    }
+}
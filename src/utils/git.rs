@@ -17,6 +17,7 @@
 use crate::core::errors::SikilError;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::Duration;
 
 /// A parsed Git URL with all components extracted
 #[derive(Debug, Clone, PartialEq)]
@@ -43,6 +44,80 @@ impl ParsedGitUrl {
     }
 }
 
+/// Prefix used for temporary directories holding Git clones
+///
+/// Clones are made under the system temp directory with this prefix so that
+/// leftovers from an interrupted install can be recognised later.
+pub const TEMP_CLONE_PREFIX: &str = "sikil-clone-";
+
+/// Create a temporary directory for cloning or extracting a skill
+///
+/// The directory is removed when the returned `TempDir` is dropped.
+///
+/// # Errors
+///
+/// Returns `SikilError::GitError` if the directory cannot be created.
+pub fn create_temp_clone_dir() -> Result<tempfile::TempDir, SikilError> {
+    tempfile::Builder::new()
+        .prefix(TEMP_CLONE_PREFIX)
+        .tempdir()
+        .map_err(|e| SikilError::GitError {
+            reason: format!("failed to create temporary directory: {}", e),
+        })
+}
+
+/// How old a temporary clone must be before it counts as left behind
+///
+/// Younger clones may belong to an install that is still running.
+pub const STALE_CLONE_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Find temporary clone directories left behind in `temp_root`
+///
+/// Returns the directories whose name starts with [`TEMP_CLONE_PREFIX`] and
+/// that were last modified at least `min_age` ago, sorted by path. A missing
+/// or unreadable `temp_root` yields an empty list.
+pub fn find_leftover_clones(temp_root: &Path, min_age: Duration) -> Vec<PathBuf> {
+    let entries = match std::fs::read_dir(temp_root) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut leftovers: Vec<PathBuf> = entries
+        .flatten()
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(TEMP_CLONE_PREFIX)
+        })
+        .filter(|entry| entry.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .filter(|entry| {
+            // A clone whose age cannot be determined is left alone
+            entry
+                .metadata()
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|age| age >= min_age)
+        })
+        .map(|entry| entry.path())
+        .collect();
+
+    leftovers.sort();
+    leftovers
+}
+
+/// Check whether the `git` executable is installed and runnable
+pub fn is_git_available() -> bool {
+    matches!(
+        Command::new("git")
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .arg("--version")
+            .output(),
+        Ok(output) if output.status.success()
+    )
+}
+
 /// Parse a Git URL string into a structured ParsedGitUrl
 ///
 /// # Supported Formats
@@ -280,20 +355,10 @@ fn parse_short_form(input: &str) -> Result<ParsedGitUrl, SikilError> {
 /// ```
 pub fn clone_repo(url: &ParsedGitUrl, dest: &Path) -> Result<(), SikilError> {
     // M3-E02-T02-S06: Check if git is installed
-    let git_check = Command::new("git")
-        .env("GIT_TERMINAL_PROMPT", "0")
-        .arg("--version")
-        .output();
-
-    match git_check {
-        Ok(output) if output.status.success() => {
-            // Git is installed, continue
-        }
-        Ok(_) | Err(_) => {
-            return Err(SikilError::GitError {
-                reason: "git is not installed or not accessible".to_string(),
-            });
-        }
+    if !is_git_available() {
+        return Err(SikilError::GitError {
+            reason: "git is not installed or not accessible".to_string(),
+        });
     }
 
     // M3-E02-T02-S02: Use std::process::Command with array args (no shell)
@@ -345,8 +410,9 @@ pub fn clone_repo(url: &ParsedGitUrl, dest: &Path) -> Result<(), SikilError> {
 ///
 /// # Returns
 ///
-/// A `PathBuf` pointing to the extracted skill directory in a temporary location.
-/// The caller is responsible for cleaning up this temporary directory.
+/// The temporary directory holding the extracted skill. It is deleted when
+/// the returned `TempDir` is dropped, so callers keep it alive only until
+/// the skill has been copied into the repository.
 ///
 /// # Errors
 ///
@@ -371,12 +437,16 @@ pub fn clone_repo(url: &ParsedGitUrl, dest: &Path) -> Result<(), SikilError> {
 /// let temp_clone = TempDir::new().unwrap();
 /// clone_repo(&url, temp_clone.path()).unwrap();
 ///
-/// let skill_path = extract_subdirectory(
+/// let extracted = extract_subdirectory(
 ///     temp_clone.path(),
 ///     url.subdirectory.as_ref().unwrap()
 /// ).unwrap();
+/// let skill_path = extracted.path();
 /// ```
-pub fn extract_subdirectory(clone_path: &Path, subdirectory: &str) -> Result<PathBuf, SikilError> {
+pub fn extract_subdirectory(
+    clone_path: &Path,
+    subdirectory: &str,
+) -> Result<tempfile::TempDir, SikilError> {
     // M3-E02-T03-S04: Validate extracted path is within clone root (no traversal)
 
     // Reject paths containing .. to prevent path traversal
@@ -420,23 +490,18 @@ pub fn extract_subdirectory(clone_path: &Path, subdirectory: &str) -> Result<Pat
     }
 
     // M3-E02-T03-S01: Clone to temp directory using tempfile::tempdir()
-    // Create a temporary directory for the extracted skill
-    let temp_dir = tempfile::tempdir().map_err(|e| SikilError::GitError {
-        reason: format!("failed to create temporary directory: {}", e),
-    })?;
-
-    // Use keep() to prevent the temp directory from being automatically deleted
-    // so the caller can manage its lifetime
-    let extracted_path = temp_dir.keep();
+    // Create a temporary directory for the extracted skill; the caller owns
+    // it and it is deleted when dropped
+    let temp_dir = create_temp_clone_dir()?;
 
     // M3-E02-T03-S03: Extract subdirectory to separate temp location
     // Copy the subdirectory contents to the temporary location
     // We use a recursive copy to preserve all contents
-    copy_dir_recursive(&canonical_subdir, &extracted_path).map_err(|e| SikilError::GitError {
+    copy_dir_recursive(&canonical_subdir, temp_dir.path()).map_err(|e| SikilError::GitError {
         reason: format!("failed to copy subdirectory: {}", e),
     })?;
 
-    Ok(extracted_path)
+    Ok(temp_dir)
 }
 
 /// Helper function to recursively copy a directory
@@ -923,7 +988,8 @@ mod tests {
         let result = extract_subdirectory(clone_path, "subdir");
 
         assert!(result.is_ok());
-        let extracted_path = result.unwrap();
+        let extracted = result.unwrap();
+        let extracted_path = extracted.path().to_path_buf();
 
         // Verify the extraction worked
         assert!(extracted_path.exists());
@@ -931,8 +997,9 @@ mod tests {
         assert!(extracted_path.join("nested").exists());
         assert!(extracted_path.join("nested/another.txt").exists());
 
-        // Clean up
-        let _ = std::fs::remove_dir_all(extracted_path);
+        // Dropping the guard deletes the extracted copy
+        drop(extracted);
+        assert!(!extracted_path.exists());
     }
 
     #[test]
@@ -949,12 +1016,9 @@ mod tests {
         let result = extract_subdirectory(clone_path, "skills/production/my-skill");
 
         assert!(result.is_ok());
-        let extracted_path = result.unwrap();
-
-        assert!(extracted_path.join("SKILL.md").exists());
+        let extracted = result.unwrap();
 
-        // Clean up
-        let _ = std::fs::remove_dir_all(extracted_path);
+        assert!(extracted.path().join("SKILL.md").exists());
     }
 
     // M3-E02-T03-S05: Clean up clone (remove .git/, temp files)
@@ -1039,4 +1103,57 @@ mod tests {
         assert!(temp_dest.path().join("subdir").exists());
         assert!(temp_dest.path().join("subdir/file2.txt").exists());
     }
+
+    #[test]
+    fn test_create_temp_clone_dir_uses_prefix() {
+        let temp = create_temp_clone_dir().unwrap();
+        let name = temp.path().file_name().unwrap().to_string_lossy();
+        assert!(name.starts_with(TEMP_CLONE_PREFIX));
+    }
+
+    #[test]
+    fn test_find_leftover_clones() {
+        let temp_root = tempfile::tempdir().unwrap();
+        std::fs::create_dir(temp_root.path().join("sikil-clone-b")).unwrap();
+        std::fs::create_dir(temp_root.path().join("sikil-clone-a")).unwrap();
+        std::fs::create_dir(temp_root.path().join("other-dir")).unwrap();
+        std::fs::write(temp_root.path().join("sikil-clone-file"), b"x").unwrap();
+
+        let leftovers = find_leftover_clones(temp_root.path(), Duration::ZERO);
+
+        assert_eq!(
+            leftovers,
+            vec![
+                temp_root.path().join("sikil-clone-a"),
+                temp_root.path().join("sikil-clone-b"),
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_leftover_clones_skips_recent_clones() {
+        let temp_root = tempfile::tempdir().unwrap();
+        let fresh = temp_root.path().join("sikil-clone-fresh");
+        let stale = temp_root.path().join("sikil-clone-stale");
+        std::fs::create_dir(&fresh).unwrap();
+        std::fs::create_dir(&stale).unwrap();
+        let two_hours_ago = std::time::SystemTime::now() - Duration::from_secs(2 * 60 * 60);
+        std::fs::File::open(&stale)
+            .unwrap()
+            .set_modified(two_hours_ago)
+            .unwrap();
+
+        assert_eq!(
+            find_leftover_clones(temp_root.path(), STALE_CLONE_AGE),
+            vec![stale]
+        );
+    }
+
+    #[test]
+    fn test_find_leftover_clones_missing_root() {
+        assert!(
+            find_leftover_clones(Path::new("/nonexistent/temp/root"), Duration::ZERO).is_empty()
+        );
+    }
 }
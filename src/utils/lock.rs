@@ -0,0 +1,195 @@
+//! Advisory process lock for mutating commands
+//!
+//! Commands that change the repository, agent directories or the cache hold
+//! an exclusive lock on `~/.sikil/sikil.lock` for their whole run, so two
+//! sikil processes never interleave their filesystem steps. The lock file
+//! holds the PID of the current holder, which is reported to processes that
+//! give up waiting. The operating system releases the lock when the holder
+//! exits, including on a crash.
+
+use crate::core::errors::SikilError;
+use fs2::FileExt;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// How long to wait for another process by default
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Environment variable overriding the wait, in whole seconds
+pub const LOCK_TIMEOUT_ENV: &str = "SIKIL_LOCK_TIMEOUT";
+
+/// Delay between attempts while the lock is held elsewhere
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// An exclusive lock held until dropped
+#[derive(Debug)]
+pub struct ProcessLock {
+    file: File,
+    path: PathBuf,
+}
+
+impl ProcessLock {
+    /// Acquires the lock at `path`, waiting up to `timeout` for another
+    /// holder to release it
+    ///
+    /// `on_wait` is called once, with the holder's PID if known, when the
+    /// lock is found to be held, so callers can tell the user why they are
+    /// waiting.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SikilError::LockTimeout` if the lock is still held after
+    /// `timeout`, or `SikilError::PermissionDenied` if the lock file cannot
+    /// be opened or written.
+    pub fn acquire(
+        path: &Path,
+        timeout: Duration,
+        on_wait: impl FnOnce(Option<u32>),
+    ) -> Result<Self, SikilError> {
+        if let Some(parent) = path.parent() {
+            crate::utils::paths::ensure_dir_exists(parent).map_err(|_| {
+                SikilError::PermissionDenied {
+                    operation: "create lock directory".to_string(),
+                    path: parent.to_path_buf(),
+                }
+            })?;
+        }
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map_err(|_| SikilError::PermissionDenied {
+                operation: "open lock file".to_string(),
+                path: path.to_path_buf(),
+            })?;
+
+        let deadline = Instant::now() + timeout;
+        let mut on_wait = Some(on_wait);
+        loop {
+            match file.try_lock_exclusive() {
+                Ok(()) => break,
+                Err(e) if e.kind() == fs2::lock_contended_error().kind() => {
+                    let holder = read_pid(&mut file);
+                    if Instant::now() >= deadline {
+                        return Err(SikilError::LockTimeout {
+                            path: path.to_path_buf(),
+                            pid: holder,
+                        });
+                    }
+                    if let Some(notify) = on_wait.take() {
+                        notify(holder);
+                    }
+                    std::thread::sleep(POLL_INTERVAL.min(deadline - Instant::now()));
+                }
+                Err(_) => {
+                    return Err(SikilError::PermissionDenied {
+                        operation: "lock".to_string(),
+                        path: path.to_path_buf(),
+                    })
+                }
+            }
+        }
+
+        write_pid(&mut file).map_err(|_| SikilError::PermissionDenied {
+            operation: "write lock file".to_string(),
+            path: path.to_path_buf(),
+        })?;
+
+        Ok(Self {
+            file,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Returns the path of the lock file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ProcessLock {
+    fn drop(&mut self) {
+        // Clear the PID so a stale value is never reported; the lock itself
+        // is released when the file is closed
+        let _ = self.file.set_len(0);
+    }
+}
+
+/// Reads the timeout from `SIKIL_LOCK_TIMEOUT`, falling back to the default
+pub fn lock_timeout() -> Duration {
+    std::env::var(LOCK_TIMEOUT_ENV)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_LOCK_TIMEOUT)
+}
+
+/// Reads the PID recorded in the lock file, if any
+fn read_pid(file: &mut File) -> Option<u32> {
+    let mut content = String::new();
+    file.seek(SeekFrom::Start(0)).ok()?;
+    file.read_to_string(&mut content).ok()?;
+    content.trim().parse().ok()
+}
+
+/// Replaces the lock file contents with this process's PID
+fn write_pid(file: &mut File) -> std::io::Result<()> {
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    writeln!(file, "{}", std::process::id())?;
+    file.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_lock_records_pid_and_clears_it_on_drop() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("nested").join("sikil.lock");
+
+        let lock = ProcessLock::acquire(&path, Duration::ZERO, |_| {}).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(lock.path()).unwrap().trim(),
+            std::process::id().to_string()
+        );
+
+        drop(lock);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
+    }
+
+    #[test]
+    fn test_lock_times_out_naming_holder() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("sikil.lock");
+        let _held = ProcessLock::acquire(&path, Duration::ZERO, |_| {}).unwrap();
+
+        let mut waited_for = None;
+        let err = ProcessLock::acquire(&path, Duration::from_millis(200), |pid| {
+            waited_for = pid;
+        })
+        .unwrap_err();
+
+        assert_eq!(waited_for, Some(std::process::id()));
+        match err {
+            SikilError::LockTimeout { pid, .. } => assert_eq!(pid, Some(std::process::id())),
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[test]
+    fn test_lock_is_reacquired_after_release() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("sikil.lock");
+
+        drop(ProcessLock::acquire(&path, Duration::ZERO, |_| {}).unwrap());
+        assert!(ProcessLock::acquire(&path, Duration::ZERO, |_| {}).is_ok());
+    }
+}
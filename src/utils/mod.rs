@@ -1,8 +1,12 @@
 //! Utilities module for helper functions and shared utilities
 
 pub mod atomic;
+pub mod diff;
 pub mod git;
+pub mod lock;
+pub mod parallel;
 pub mod paths;
 pub mod symlink;
+pub mod time;
 
 pub use paths::*;
@@ -0,0 +1,92 @@
+//! Parallel helpers for Sikil
+//!
+//! Scanning is dominated by filesystem latency (especially on network home
+//! directories), so independent directory reads and hashes are spread over a
+//! small pool of scoped threads.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Upper bound on worker threads, since the work is I/O bound
+const MAX_WORKERS: usize = 16;
+
+/// Applies `f` to every item on a pool of scoped threads.
+///
+/// Items are handed out one at a time, so slow items do not hold up a whole
+/// chunk. The results are returned in the order of `items`, regardless of the
+/// order in which they finished.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::utils::parallel::parallel_map;
+///
+/// let squares = parallel_map(&[1, 2, 3], |n| n * n);
+/// assert_eq!(squares, vec![1, 4, 9]);
+/// ```
+pub fn parallel_map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(MAX_WORKERS)
+        .min(items.len());
+    if workers <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut indexed: Vec<(usize, R)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break;
+                        };
+                        done.push((index, f(item)));
+                    }
+                    done
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| match handle.join() {
+                Ok(done) => done,
+                Err(panic) => std::panic::resume_unwind(panic),
+            })
+            .collect()
+    });
+
+    indexed.sort_unstable_by_key(|(index, _)| *index);
+    indexed.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parallel_map_keeps_order() {
+        let items: Vec<u64> = (0..500).collect();
+        let doubled = parallel_map(&items, |n| {
+            // Make later items finish first
+            std::thread::sleep(std::time::Duration::from_micros(500 - n));
+            n * 2
+        });
+        assert_eq!(doubled, items.iter().map(|n| n * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_parallel_map_empty() {
+        let items: Vec<u32> = Vec::new();
+        assert!(parallel_map(&items, |n| *n).is_empty());
+    }
+}
@@ -5,6 +5,7 @@
 //! directories exist.
 
 use fs_err as fs;
+use sha2::{Digest, Sha256};
 use std::path::{Path, PathBuf};
 
 /// Expands a path string, handling shell expansions like `~` and `$HOME`.
@@ -108,6 +109,135 @@ pub fn get_cache_path() -> PathBuf {
     home.join(".sikil").join("cache.json")
 }
 
+/// Returns the path to the SQLite cache database.
+///
+/// The SQLite cache is typically at `~/.sikil/cache.db` and is only used
+/// when `[cache] backend = "sqlite"` is configured.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::utils::paths::get_sqlite_cache_path;
+///
+/// let cache_path = get_sqlite_cache_path();
+/// assert!(cache_path.ends_with(".sikil/cache.db"));
+/// ```
+pub fn get_sqlite_cache_path() -> PathBuf {
+    let user_dirs = directories::UserDirs::new().expect("Unable to determine home directory");
+    let home = user_dirs.home_dir();
+    home.join(".sikil").join("cache.db")
+}
+
+/// Returns the path to the lock file held by mutating commands.
+///
+/// The lock file is typically at `~/.sikil/sikil.lock`.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::utils::paths::get_lock_path;
+///
+/// let lock_path = get_lock_path();
+/// assert!(lock_path.ends_with(".sikil/sikil.lock"));
+/// ```
+pub fn get_lock_path() -> PathBuf {
+    let user_dirs = directories::UserDirs::new().expect("Unable to determine home directory");
+    let home = user_dirs.home_dir();
+    home.join(".sikil").join("sikil.lock")
+}
+
+/// Returns the path to the operation journal directory.
+///
+/// The journal directory is typically at `~/.sikil/journal/`.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::utils::paths::get_journal_dir;
+///
+/// let journal_dir = get_journal_dir();
+/// assert!(journal_dir.ends_with(".sikil/journal"));
+/// ```
+pub fn get_journal_dir() -> PathBuf {
+    let user_dirs = directories::UserDirs::new().expect("Unable to determine home directory");
+    let home = user_dirs.home_dir();
+    home.join(".sikil").join("journal")
+}
+
+/// Returns the path to the operation history directory.
+///
+/// The history directory is typically at `~/.sikil/history/` and holds what
+/// recent operations did, for `sikil undo`.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::utils::paths::get_history_dir;
+///
+/// let history_dir = get_history_dir();
+/// assert!(history_dir.ends_with(".sikil/history"));
+/// ```
+pub fn get_history_dir() -> PathBuf {
+    let user_dirs = directories::UserDirs::new().expect("Unable to determine home directory");
+    let home = user_dirs.home_dir();
+    home.join(".sikil").join("history")
+}
+
+/// Returns the path to the audit log.
+///
+/// The audit log is typically at `~/.sikil/log.jsonl`.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::utils::paths::get_log_path;
+///
+/// let log_path = get_log_path();
+/// assert!(log_path.ends_with(".sikil/log.jsonl"));
+/// ```
+pub fn get_log_path() -> PathBuf {
+    let user_dirs = directories::UserDirs::new().expect("Unable to determine home directory");
+    let home = user_dirs.home_dir();
+    home.join(".sikil").join("log.jsonl")
+}
+
+/// Returns the path to the trash directory.
+///
+/// The trash is typically at `~/.sikil/trash/` and holds skills removed by
+/// `sikil remove` until they expire.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::utils::paths::get_trash_dir;
+///
+/// let trash_dir = get_trash_dir();
+/// assert!(trash_dir.ends_with(".sikil/trash"));
+/// ```
+pub fn get_trash_dir() -> PathBuf {
+    let user_dirs = directories::UserDirs::new().expect("Unable to determine home directory");
+    let home = user_dirs.home_dir();
+    home.join(".sikil").join("trash")
+}
+
+/// Returns the path to the search index.
+///
+/// The search index is typically at `~/.sikil/search-index.json`.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::utils::paths::get_search_index_path;
+///
+/// let index_path = get_search_index_path();
+/// assert!(index_path.ends_with(".sikil/search-index.json"));
+/// ```
+pub fn get_search_index_path() -> PathBuf {
+    let user_dirs = directories::UserDirs::new().expect("Unable to determine home directory");
+    let home = user_dirs.home_dir();
+    home.join(".sikil").join("search-index.json")
+}
+
 /// Ensures a directory exists, creating it and any parent directories if necessary.
 ///
 /// # Arguments
@@ -140,6 +270,75 @@ pub fn ensure_dir_exists(path: &Path) -> Result<(), std::io::Error> {
     Ok(())
 }
 
+/// Returns the total size in bytes of the files under a path.
+///
+/// Symlinks are counted by their own size and never followed. Entries that
+/// cannot be read are skipped, so the result is a lower bound.
+///
+/// # Arguments
+///
+/// * `path` - A file or directory
+///
+/// # Examples
+///
+/// ```no_run
+/// use sikil::utils::paths::{dir_size, get_repo_path};
+///
+/// println!("Repository uses {} bytes", dir_size(&get_repo_path()));
+/// ```
+pub fn dir_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(|metadata| !metadata.is_dir())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// Returns a SHA-256 fingerprint of everything under a directory.
+///
+/// The hash covers the relative path of every entry, the contents of every
+/// file and the target of every symlink, so renaming, editing, adding or
+/// removing anything changes it. Timestamps and permissions are ignored.
+/// Entries that cannot be read hash as empty.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sikil::utils::paths::{get_repo_path, hash_dir};
+///
+/// println!("{}", hash_dir(&get_repo_path().join("my-skill")));
+/// ```
+pub fn hash_dir(path: &Path) -> String {
+    let mut hasher = Sha256::new();
+    let entries = walkdir::WalkDir::new(path)
+        .follow_links(false)
+        .sort_by_file_name()
+        .min_depth(1)
+        .into_iter()
+        .flatten();
+    for entry in entries {
+        let relative = entry.path().strip_prefix(path).unwrap_or(entry.path());
+        hasher.update(relative.to_string_lossy().as_bytes());
+        let file_type = entry.file_type();
+        if file_type.is_symlink() {
+            hasher.update(b"\0l");
+            if let Ok(target) = fs::read_link(entry.path()) {
+                hasher.update(target.to_string_lossy().as_bytes());
+            }
+        } else if file_type.is_dir() {
+            hasher.update(b"\0d");
+        } else {
+            hasher.update(b"\0f");
+            hasher.update(fs::read(entry.path()).unwrap_or_default());
+        }
+        hasher.update(b"\0");
+    }
+    format!("{:x}", hasher.finalize())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,4 +441,30 @@ mod tests {
         // Just ensure it doesn't panic
         let _ = result;
     }
+
+    #[test]
+    fn test_dir_size() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("a.txt"), "12345").unwrap();
+        fs::create_dir(temp_dir.path().join("sub")).unwrap();
+        fs::write(temp_dir.path().join("sub/b.txt"), "123").unwrap();
+
+        assert_eq!(dir_size(temp_dir.path()), 8);
+        assert_eq!(dir_size(&temp_dir.path().join("missing")), 0);
+    }
+
+    #[test]
+    fn test_hash_dir_tracks_content() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("a.txt"), "one").unwrap();
+        let first = hash_dir(temp_dir.path());
+        assert_eq!(first, hash_dir(temp_dir.path()));
+
+        fs::write(temp_dir.path().join("a.txt"), "two").unwrap();
+        let edited = hash_dir(temp_dir.path());
+        assert_ne!(first, edited);
+
+        fs::rename(temp_dir.path().join("a.txt"), temp_dir.path().join("b.txt")).unwrap();
+        assert_ne!(edited, hash_dir(temp_dir.path()));
+    }
 }
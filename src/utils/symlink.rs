@@ -218,6 +218,42 @@ pub fn is_managed_symlink(path: &Path) -> bool {
     target_real.starts_with(&repo_path)
 }
 
+/// Lists the symbolic links directly inside a directory.
+///
+/// Symlinks are not followed, so dangling links are included. Returns an
+/// empty list if the directory cannot be read.
+///
+/// # Arguments
+///
+/// * `dir` - The directory to list
+///
+/// # Returns
+///
+/// Paths of the symlinks in `dir`, sorted
+///
+/// # Examples
+///
+/// ```no_run
+/// use sikil::utils::symlink::list_symlinks;
+/// use std::path::Path;
+///
+/// for link in list_symlinks(Path::new("/home/user/.claude/skills")) {
+///     println!("{}", link.display());
+/// }
+/// ```
+pub fn list_symlinks(dir: &Path) -> Vec<PathBuf> {
+    let mut links: Vec<PathBuf> = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .flatten()
+            .filter(|e| e.file_type().map(|t| t.is_symlink()).unwrap_or(false))
+            .map(|e| e.path())
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    links.sort();
+    links
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -443,4 +479,29 @@ mod tests {
         let realpath = resolve_realpath(&link).unwrap();
         assert!(realpath.starts_with(&repo));
     }
+
+    #[test]
+    fn test_list_symlinks_includes_dangling() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("target");
+        fs::create_dir(&target).unwrap();
+        fs::create_dir(temp_dir.path().join("plain")).unwrap();
+        std::os::unix::fs::symlink(&target, temp_dir.path().join("b-link")).unwrap();
+        std::os::unix::fs::symlink("/nonexistent/target", temp_dir.path().join("a-dangling"))
+            .unwrap();
+
+        let links = list_symlinks(temp_dir.path());
+        assert_eq!(
+            links,
+            vec![
+                temp_dir.path().join("a-dangling"),
+                temp_dir.path().join("b-link")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_list_symlinks_missing_dir() {
+        assert!(list_symlinks(Path::new("/nonexistent/dir")).is_empty());
+    }
 }
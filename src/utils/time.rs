@@ -0,0 +1,138 @@
+//! Timestamp formatting and date parsing
+//!
+//! Timestamps are written as RFC 3339 in UTC (`2024-05-01T12:30:00Z`) so
+//! they sort as strings and read the same on every machine.
+
+use std::time::SystemTime;
+
+/// Returns the current time as seconds since the Unix epoch
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Formats seconds since the Unix epoch as an RFC 3339 UTC timestamp
+///
+/// # Examples
+///
+/// ```
+/// use sikil::utils::time::format_timestamp;
+///
+/// assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+/// assert_eq!(format_timestamp(1_714_566_600), "2024-05-01T12:30:00Z");
+/// ```
+pub fn format_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+/// Parses a date (`YYYY-MM-DD`, midnight UTC) or an RFC 3339 UTC timestamp
+/// (`YYYY-MM-DDTHH:MM:SSZ`) into seconds since the Unix epoch
+///
+/// Returns None for anything else, including dates before 1970.
+///
+/// # Examples
+///
+/// ```
+/// use sikil::utils::time::parse_date;
+///
+/// assert_eq!(parse_date("1970-01-02"), Some(86_400));
+/// assert_eq!(parse_date("2024-05-01T12:30:00Z"), Some(1_714_566_600));
+/// assert_eq!(parse_date("yesterday"), None);
+/// ```
+pub fn parse_date(input: &str) -> Option<u64> {
+    let (date, time) = match input.split_once('T') {
+        Some((date, time)) => (date, Some(time.strip_suffix('Z')?)),
+        None => (input, None),
+    };
+
+    let mut parts = date.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || !(1..=12).contains(&month) || day == 0 {
+        return None;
+    }
+    let days = days_from_civil(year, month, day);
+    if civil_from_days(days) != (year, month, day) {
+        return None;
+    }
+
+    let seconds = match time {
+        None => 0,
+        Some(time) => {
+            let fields: Vec<u64> = time
+                .split(':')
+                .map(|field| field.parse().ok())
+                .collect::<Option<_>>()?;
+            match fields[..] {
+                [hours, minutes, seconds] if hours < 24 && minutes < 60 && seconds < 60 => {
+                    hours * 3600 + minutes * 60 + seconds
+                }
+                _ => return None,
+            }
+        }
+    };
+
+    u64::try_from(days).ok().map(|days| days * 86_400 + seconds)
+}
+
+/// Converts days since the epoch to a (year, month, day) date
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Converts a (year, month, day) date to days since the epoch
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_and_parse_round_trip() {
+        for secs in [0, 951_782_400, 1_709_210_096, 4_102_444_799] {
+            assert_eq!(parse_date(&format_timestamp(secs)), Some(secs));
+        }
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn test_parse_date_rejects_invalid_input() {
+        assert_eq!(parse_date("2023-02-29"), None);
+        assert_eq!(parse_date("2024-13-01"), None);
+        assert_eq!(parse_date("2024-05-01T25:00:00Z"), None);
+        assert_eq!(parse_date("2024-05-01T12:00:00"), None);
+        assert_eq!(parse_date("1969-12-31"), None);
+        assert_eq!(parse_date(""), None);
+    }
+}
@@ -125,6 +125,41 @@ pub fn create_complete_skill_md(skill_dir: &Path, name: &str, description: &str)
     );
 }
 
+/// Sets up a home with an agent directory and a skill source.
+///
+/// Writes `~/.sikil/config.toml` with a `claude-code` agent whose global path
+/// is `<home>/agent`, followed by `extra_config`, and creates `<home>/source`
+/// holding a SKILL.md for `skill_name`.
+///
+/// # Returns
+/// The path to the skill source directory
+pub fn setup_home(home: &Path, skill_name: &str, extra_config: &str) -> PathBuf {
+    let agent_dir = home.join("agent");
+    fs::create_dir_all(&agent_dir).expect("Failed to create agent dir");
+    fs::create_dir_all(home.join(".sikil")).expect("Failed to create .sikil");
+    let config_content = format!(
+        r#"[agents.claude-code]
+enabled = true
+global_path = "{}"
+workspace_path = ".claude/skills"
+{}"#,
+        agent_dir.display(),
+        extra_config
+    );
+    fs::write(home.join(".sikil/config.toml"), config_content).expect("Failed to write config");
+
+    let source = home.join("source");
+    fs::create_dir_all(&source).expect("Failed to create source");
+    create_skill_md(
+        &source,
+        &format!(
+            "---\nname: {}\ndescription: A test skill\n---\n",
+            skill_name
+        ),
+    );
+    source
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,4 +219,21 @@ mod tests {
         assert!(content.contains("author: \"Test Author\""));
         assert!(content.contains("license: \"MIT\""));
     }
+
+    #[test]
+    fn test_setup_home() {
+        let temp_dir = setup_temp_skill_dir();
+        let source = setup_home(
+            temp_dir.path(),
+            "home-skill",
+            "\n[trash]\nretention_days = 1\n",
+        );
+
+        assert!(temp_dir.path().join("agent").is_dir());
+        let config = fs::read_to_string(temp_dir.path().join(".sikil/config.toml")).unwrap();
+        assert!(config.contains("[agents.claude-code]"));
+        assert!(config.ends_with("retention_days = 1\n"));
+        let content = fs::read_to_string(source.join("SKILL.md")).unwrap();
+        assert!(content.contains("name: home-skill"));
+    }
 }
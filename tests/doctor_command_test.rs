@@ -0,0 +1,142 @@
+//! Integration tests for Doctor Command
+//!
+//! These tests validate the doctor command behavior including:
+//! - A healthy environment passes (exit 0)
+//! - JSON output lists every check with a status
+//! - Failing checks (corrupt cache, repo that is a file) exit non-zero
+
+mod common;
+
+use predicates::str::contains;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tempfile::TempDir;
+
+/// Helper to write a config with a single agent pointing at `agent_dir`
+fn write_config(home: &Path, agent_dir: &Path) {
+    let config_dir = home.join(".sikil");
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+    let config_content = format!(
+        r#"[agents.claude-code]
+enabled = true
+global_path = "{}"
+workspace_path = ".claude/skills"
+"#,
+        agent_dir.display()
+    );
+    fs::write(config_dir.join("config.toml"), config_content).expect("Failed to write config");
+}
+
+/// Sets the modification time of a directory to two hours ago
+fn backdate(path: &Path) {
+    fs::File::open(path)
+        .and_then(|dir| dir.set_modified(SystemTime::now() - Duration::from_secs(2 * 60 * 60)))
+        .expect("Failed to backdate directory");
+}
+
+#[test]
+fn test_doctor_healthy_environment() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let agent_dir = temp_dir.path().join("agent");
+    let tmp = temp_dir.path().join("tmp");
+    fs::create_dir_all(&agent_dir).expect("Failed to create agent dir");
+    fs::create_dir_all(&tmp).expect("Failed to create tmp dir");
+    write_config(temp_dir.path(), &agent_dir);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .env("TMPDIR", &tmp)
+        .current_dir(temp_dir.path())
+        .arg("doctor")
+        .assert()
+        .success()
+        .stdout(contains("Agent directories"))
+        .stdout(contains("0 failure(s)"));
+}
+
+#[test]
+fn test_doctor_json_output() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let agent_dir = temp_dir.path().join("agent");
+    let tmp = temp_dir.path().join("tmp");
+    fs::create_dir_all(&agent_dir).expect("Failed to create agent dir");
+    fs::create_dir_all(tmp.join("sikil-clone-leftover")).expect("Failed to create clone dir");
+    backdate(&tmp.join("sikil-clone-leftover"));
+    write_config(temp_dir.path(), &agent_dir);
+
+    let mut cmd = sikil_cmd!();
+    let output = cmd
+        .env("HOME", temp_dir.path())
+        .env("TMPDIR", &tmp)
+        .current_dir(temp_dir.path())
+        .arg("--json")
+        .arg("doctor")
+        .output()
+        .expect("Failed to run doctor");
+
+    assert!(output.status.success());
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+
+    let checks = json["checks"]
+        .as_array()
+        .expect("checks should be an array");
+    assert!(!checks.is_empty());
+    assert!(checks.iter().all(|c| c["status"].is_string()));
+
+    let clones = checks
+        .iter()
+        .find(|c| c["name"] == "Temporary clones")
+        .expect("Temporary clones check should be present");
+    assert_eq!(clones["status"], "warn");
+    assert_eq!(json["summary"]["failures"], 0);
+}
+
+#[test]
+fn test_doctor_corrupt_cache_fails() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let agent_dir = temp_dir.path().join("agent");
+    fs::create_dir_all(&agent_dir).expect("Failed to create agent dir");
+    write_config(temp_dir.path(), &agent_dir);
+    fs::write(temp_dir.path().join(".sikil/cache.json"), "{ not json")
+        .expect("Failed to write cache");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .current_dir(temp_dir.path())
+        .arg("doctor")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(contains("health check(s) failed"));
+}
+
+#[test]
+fn test_doctor_repo_not_a_directory_fails() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let agent_dir = temp_dir.path().join("agent");
+    fs::create_dir_all(&agent_dir).expect("Failed to create agent dir");
+    write_config(temp_dir.path(), &agent_dir);
+    fs::write(temp_dir.path().join(".sikil/repo"), "oops").expect("Failed to write repo file");
+
+    let mut cmd = sikil_cmd!();
+    let output = cmd
+        .env("HOME", temp_dir.path())
+        .current_dir(temp_dir.path())
+        .arg("--json")
+        .arg("doctor")
+        .output()
+        .expect("Failed to run doctor");
+
+    assert!(!output.status.success());
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    let repo = json["checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["name"] == "Repository")
+        .unwrap();
+    assert_eq!(repo["status"], "fail");
+}
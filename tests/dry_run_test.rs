@@ -0,0 +1,154 @@
+//! Integration tests for the global `--dry-run` flag
+//!
+//! These tests validate:
+//! - Install, adopt, remove and trash empty print their plan and change nothing
+//! - The plan is emitted as JSON with `--json`
+//! - Commands that cannot describe their changes reject the flag
+
+mod common;
+
+use common::setup_home;
+use predicates::str::contains;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn install(home: &Path, source: &Path) {
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", home)
+        .arg("install")
+        .arg(source)
+        .arg("--to")
+        .arg("claude-code")
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_install_dry_run_prints_plan_as_json() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "planned", "");
+
+    let mut cmd = sikil_cmd!();
+    let output = cmd
+        .env("HOME", temp_dir.path())
+        .arg("install")
+        .arg(&source)
+        .arg("--to")
+        .arg("claude-code")
+        .arg("--dry-run")
+        .arg("--json")
+        .output()
+        .expect("Failed to run install");
+    assert!(output.status.success());
+
+    let plan: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(plan["command"], "install");
+    assert_eq!(plan["skill"], "planned");
+    assert_eq!(plan["steps"][0]["action"], "copy_dir");
+    assert_eq!(plan["steps"][1]["action"], "create_symlink");
+    assert_eq!(
+        plan["steps"][1]["link"],
+        temp_dir.path().join("agent/planned").display().to_string()
+    );
+
+    assert!(!temp_dir.path().join(".sikil/repo").exists());
+    assert!(!temp_dir.path().join("agent/planned").exists());
+}
+
+#[test]
+fn test_adopt_dry_run_changes_nothing() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "planned", "");
+    let unmanaged = temp_dir.path().join("agent/planned");
+    fs::rename(&source, &unmanaged).expect("Failed to move skill");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("adopt")
+        .arg("planned")
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stdout(contains("would perform 2 step(s)"))
+        .stdout(contains("1. move"))
+        .stdout(contains("2. symlink"));
+
+    assert!(unmanaged.is_dir());
+    assert!(!unmanaged.is_symlink());
+    assert!(!temp_dir.path().join(".sikil/repo/planned").exists());
+}
+
+#[test]
+fn test_remove_dry_run_keeps_skill() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "planned", "");
+    install(temp_dir.path(), &source);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("remove")
+        .arg("planned")
+        .arg("--all")
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stdout(contains("remove symlink"))
+        .stdout(contains(format!(
+            "move {} to {}",
+            temp_dir.path().join(".sikil/repo/planned").display(),
+            temp_dir.path().join(".sikil/trash").display()
+        )));
+
+    assert!(temp_dir.path().join("agent/planned").is_symlink());
+    assert!(temp_dir.path().join(".sikil/repo/planned").is_dir());
+}
+
+#[test]
+fn test_trash_empty_dry_run_keeps_entries() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "planned", "");
+    install(temp_dir.path(), &source);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("remove")
+        .arg("planned")
+        .arg("--all")
+        .arg("--yes")
+        .assert()
+        .success();
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("trash")
+        .arg("empty")
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stdout(contains("Dry run: `trash empty` would perform 1 step(s):"))
+        .stdout(contains(format!(
+            "delete {}",
+            temp_dir.path().join(".sikil/trash").display()
+        )));
+
+    let entries = fs::read_dir(temp_dir.path().join(".sikil/trash"))
+        .expect("Failed to read trash")
+        .count();
+    assert_eq!(entries, 1);
+}
+
+#[test]
+fn test_dry_run_rejected_by_unsupported_command() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path(), "planned", "");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("cache")
+        .arg("clear")
+        .arg("--dry-run")
+        .assert()
+        .failure()
+        .stderr(contains("--dry-run is not supported"));
+}
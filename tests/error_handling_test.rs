@@ -58,8 +58,9 @@ description: "Test skill with invalid name"
 
     cmd.assert()
         .failure()
-        .stdout(predicate::str::contains("Validation failed"))
-        .stdout(predicate::str::contains("name"));
+        .stdout(predicate::str::contains("invalid skill name"))
+        .stdout(predicate::str::contains("name"))
+        .stderr(predicate::str::contains("Validation failed"));
 }
 
 #[test]
@@ -0,0 +1,233 @@
+//! Integration tests for Gc Command and `list --orphans`
+//!
+//! These tests validate:
+//! - `list --orphans` shows only managed skills with no agent links
+//! - `gc --yes` moves orphans to the trash, deletes leftover clones and
+//!   reports reclaimed space
+//! - `gc --dry-run` prints the plan and changes nothing
+//! - `gc --yes` keeps orphans when other projects' workspaces could link to them
+//! - Recent clones, which may belong to a running install, are kept
+//! - `gc` without --yes aborts when the prompt is declined
+//! - JSON output includes every item and the reclaimed byte count
+
+mod common;
+
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tempfile::TempDir;
+
+/// Helper to create a valid skill in the repository
+fn create_skill_in_repo(repo_dir: &Path, skill_name: &str) {
+    let skill_dir = repo_dir.join(skill_name);
+    fs::create_dir_all(&skill_dir).expect("Failed to create skill dir in repo");
+    let content = format!(
+        r#"---
+name: "{}"
+description: "A test skill for gc testing"
+---
+
+# Test Skill
+"#,
+        skill_name
+    );
+    fs::write(skill_dir.join("SKILL.md"), content).expect("Failed to write SKILL.md");
+}
+
+/// Writes the config for a claude-code agent with the given workspace path
+fn write_config(home: &Path, workspace_path: &Path) {
+    let config_content = format!(
+        r#"[agents.claude-code]
+enabled = true
+global_path = "{}"
+workspace_path = "{}"
+"#,
+        home.join("agent").display(),
+        workspace_path.display()
+    );
+    fs::write(home.join(".sikil/config.toml"), config_content).expect("Failed to write config");
+}
+
+/// Sets the modification time of a directory to two hours ago
+fn backdate(path: &Path) {
+    fs::File::open(path)
+        .and_then(|dir| dir.set_modified(SystemTime::now() - Duration::from_secs(2 * 60 * 60)))
+        .expect("Failed to backdate directory");
+}
+
+/// Sets up a home with one linked and one orphaned managed skill
+///
+/// The workspace path is absolute, so gc can see every possible link.
+fn setup_home(home: &Path) -> std::path::PathBuf {
+    let agent_dir = home.join("agent");
+    let repo_dir = home.join(".sikil").join("repo");
+    fs::create_dir_all(&agent_dir).expect("Failed to create agent dir");
+    fs::create_dir_all(home.join("tmp")).expect("Failed to create tmp dir");
+    create_skill_in_repo(&repo_dir, "linked-skill");
+    create_skill_in_repo(&repo_dir, "orphan-skill");
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(
+        repo_dir.join("linked-skill"),
+        agent_dir.join("linked-skill"),
+    )
+    .expect("Failed to create symlink");
+
+    write_config(home, &home.join("workspace"));
+    repo_dir
+}
+
+#[test]
+#[cfg(unix)]
+fn test_list_orphans() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path());
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .current_dir(temp_dir.path())
+        .arg("list")
+        .arg("--orphans")
+        .arg("--no-cache")
+        .assert()
+        .success()
+        .stdout(contains("orphan-skill"))
+        .stdout(contains("linked-skill").not());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_gc_yes_removes_orphans_and_clones() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let repo_dir = setup_home(temp_dir.path());
+    let clone = temp_dir.path().join("tmp/sikil-clone-leftover");
+    fs::create_dir_all(&clone).expect("Failed to create clone dir");
+    fs::write(clone.join("README"), "leftover").expect("Failed to write file");
+    backdate(&clone);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .env("TMPDIR", temp_dir.path().join("tmp"))
+        .current_dir(temp_dir.path())
+        .arg("gc")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stdout(contains("orphaned skill"))
+        .stdout(contains("temporary clone"))
+        .stdout(contains("Removed 2 item(s), reclaimed"))
+        .stdout(contains("Moved 1 orphaned skill(s) to the trash"));
+
+    assert!(!repo_dir.join("orphan-skill").exists());
+    assert!(repo_dir.join("linked-skill").exists());
+    assert!(!clone.exists());
+
+    let trashed: Vec<_> = fs::read_dir(temp_dir.path().join(".sikil/trash"))
+        .expect("Failed to read trash")
+        .flatten()
+        .map(|entry| entry.path())
+        .collect();
+    assert_eq!(trashed.len(), 1);
+    assert!(trashed[0].join("repo/SKILL.md").is_file());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_gc_dry_run_prints_plan() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let repo_dir = setup_home(temp_dir.path());
+    let clone = temp_dir.path().join("tmp/sikil-clone-leftover");
+    fs::create_dir_all(&clone).expect("Failed to create clone dir");
+    backdate(&clone);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .env("TMPDIR", temp_dir.path().join("tmp"))
+        .current_dir(temp_dir.path())
+        .arg("gc")
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stdout(contains("Dry run: `gc` would perform 2 step(s):"))
+        .stdout(contains(format!(
+            "move {}",
+            repo_dir.join("orphan-skill").display()
+        )))
+        .stdout(contains(format!("delete {}", clone.display())));
+
+    assert!(repo_dir.join("orphan-skill").exists());
+    assert!(clone.exists());
+    assert!(!temp_dir.path().join(".sikil/trash").exists());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_gc_yes_keeps_orphans_when_workspaces_are_unscanned() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let repo_dir = setup_home(temp_dir.path());
+    write_config(temp_dir.path(), Path::new(".claude/skills"));
+    let running = temp_dir.path().join("tmp/sikil-clone-running");
+    fs::create_dir_all(&running).expect("Failed to create clone dir");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .env("TMPDIR", temp_dir.path().join("tmp"))
+        .current_dir(temp_dir.path())
+        .arg("gc")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stdout(contains("orphaned skill"))
+        .stdout(contains("temporary clone").not())
+        .stdout(contains("Kept 1 orphaned skill(s)"))
+        .stdout(contains("Removed 0 item(s)"));
+
+    assert!(repo_dir.join("orphan-skill").exists());
+    assert!(running.exists());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_gc_declined_keeps_everything() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let repo_dir = setup_home(temp_dir.path());
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .env("TMPDIR", temp_dir.path().join("tmp"))
+        .current_dir(temp_dir.path())
+        .arg("gc")
+        .write_stdin("n\n")
+        .assert()
+        .failure();
+
+    assert!(repo_dir.join("orphan-skill").exists());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_gc_json_output() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path());
+
+    let mut cmd = sikil_cmd!();
+    let output = cmd
+        .env("HOME", temp_dir.path())
+        .env("TMPDIR", temp_dir.path().join("tmp"))
+        .current_dir(temp_dir.path())
+        .arg("--json")
+        .arg("gc")
+        .output()
+        .expect("Failed to run gc");
+
+    assert!(output.status.success());
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    let items = json["items"].as_array().expect("items should be an array");
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["kind"], "orphan-skill");
+    assert_eq!(items[0]["removed"], true);
+    // The orphan is kept in the trash, so no space is reclaimed yet
+    assert_eq!(json["reclaimed_bytes"], 0);
+}
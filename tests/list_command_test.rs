@@ -7,6 +7,7 @@
 //! - Filtering by managed/unmanaged
 //! - JSON output
 //! - Snapshot testing
+//! - Filtering by tag, author and name glob, and sorting
 
 mod common;
 
@@ -453,3 +454,90 @@ workspace_path = ".claude/skills"
         .stdout(contains("my-skill")) // Should show metadata name
         .stdout(contains("(directory: my-skill-v2)")); // Should show actual directory name
 }
+
+#[test]
+fn test_list_filter_by_tag_author_and_name() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let skills_base = temp_dir.path().join("skills");
+    let skills = [
+        ("review-code", "alice", "[git, review]", 2000),
+        ("review-docs", "Alice Smith", "[docs]", 10),
+        ("commit-helper", "alice", "git", 500),
+        ("review-prs", "bob", "[git]", 100),
+    ];
+    for (name, author, tags, padding) in skills {
+        let skill_dir = skills_base.join(name);
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            format!(
+                "---\nname: {}\ndescription: d\nauthor: {}\ntags: {}\n---\n{}",
+                name,
+                author,
+                tags,
+                "x".repeat(padding)
+            ),
+        )
+        .expect("Failed to write SKILL.md");
+    }
+
+    let config_dir = temp_dir.path().join(".sikil");
+    fs::create_dir(&config_dir).expect("Failed to create .sikil");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!(
+            "[agents.claude-code]\nenabled = true\nglobal_path = \"{}\"\nworkspace_path = \".claude/skills\"\n",
+            skills_base.display()
+        ),
+    )
+    .expect("Failed to write config");
+
+    let list = |args: &[&str]| -> Vec<String> {
+        let output = sikil_cmd!()
+            .env("HOME", temp_dir.path())
+            .args(["list", "--json", "--no-cache"])
+            .args(args)
+            .output()
+            .expect("Failed to run list");
+        assert!(output.status.success());
+        let parsed: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+        parsed
+            .as_array()
+            .expect("Output should be an array")
+            .iter()
+            .map(|s| s["name"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    assert_eq!(
+        list(&["--tag", "GIT", "--author", "alice", "--name", "review-*"]),
+        vec!["review-code"]
+    );
+    assert_eq!(
+        list(&["--tag", "git"]),
+        vec!["commit-helper", "review-code", "review-prs"]
+    );
+    assert_eq!(
+        list(&["--tag", "git", "--tag", "review"]),
+        vec!["review-code"]
+    );
+    assert_eq!(
+        list(&["--author", "alice", "--name", "review-?o*"]),
+        vec!["review-code", "review-docs"]
+    );
+    assert!(list(&["--scope", "workspace"]).is_empty());
+    assert_eq!(
+        list(&["--sort", "size"]),
+        vec!["review-code", "commit-helper", "review-prs", "review-docs"]
+    );
+
+    let output = sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["list", "--json", "--no-cache", "--name", "commit-*"])
+        .output()
+        .expect("Failed to run list");
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(parsed[0]["tags"], serde_json::json!(["git"]));
+}
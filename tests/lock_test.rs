@@ -0,0 +1,83 @@
+//! Integration tests for the process lock
+//!
+//! These tests validate:
+//! - Mutating commands fail with the holder's PID when the lock is held
+//! - Read-only commands run while the lock is held
+//! - The lock is released when a mutating command finishes
+
+mod common;
+
+use common::setup_home;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use sikil::utils::lock::ProcessLock;
+use std::time::Duration;
+use tempfile::TempDir;
+
+#[test]
+fn test_mutating_command_reports_lock_holder() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path(), "locked", "");
+    let _held = ProcessLock::acquire(
+        &temp_dir.path().join(".sikil/sikil.lock"),
+        Duration::ZERO,
+        |_| {},
+    )
+    .expect("Failed to take lock");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .env("SIKIL_LOCK_TIMEOUT", "0")
+        .current_dir(temp_dir.path())
+        .arg("gc")
+        .arg("--yes")
+        .assert()
+        .failure()
+        .stderr(contains(format!("PID {}", std::process::id())))
+        .stderr(contains("sikil.lock"));
+}
+
+#[test]
+fn test_read_only_command_does_not_wait_for_lock() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path(), "locked", "");
+    let _held = ProcessLock::acquire(
+        &temp_dir.path().join(".sikil/sikil.lock"),
+        Duration::ZERO,
+        |_| {},
+    )
+    .expect("Failed to take lock");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .env("SIKIL_LOCK_TIMEOUT", "0")
+        .current_dir(temp_dir.path())
+        .arg("list")
+        .assert()
+        .success()
+        .stderr(contains("Timed out").not());
+}
+
+#[test]
+fn test_lock_released_after_mutating_command() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path(), "locked", "");
+
+    for _ in 0..2 {
+        let mut cmd = sikil_cmd!();
+        cmd.env("HOME", temp_dir.path())
+            .env("SIKIL_LOCK_TIMEOUT", "0")
+            .current_dir(temp_dir.path())
+            .arg("gc")
+            .arg("--yes")
+            .assert()
+            .success();
+    }
+
+    assert!(ProcessLock::acquire(
+        &temp_dir.path().join(".sikil/sikil.lock"),
+        Duration::ZERO,
+        |_| {}
+    )
+    .is_ok());
+}
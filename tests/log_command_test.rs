@@ -0,0 +1,194 @@
+//! Integration tests for the audit log and the log command
+//!
+//! These tests validate:
+//! - Mutating commands append an entry to `~/.sikil/log.jsonl`
+//! - Failures, dry runs and read-only commands are handled correctly
+//! - `sikil log` filters by skill and date and prints JSON
+
+mod common;
+
+use common::setup_home;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn read_log(home: &Path) -> Vec<serde_json::Value> {
+    fs::read_to_string(home.join(".sikil/log.jsonl"))
+        .unwrap_or_default()
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Invalid log line"))
+        .collect()
+}
+
+#[test]
+fn test_mutating_commands_are_logged() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "audited", "");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .env("USER", "alice")
+        .arg("install")
+        .arg(&source)
+        .arg("--to")
+        .arg("claude-code")
+        .assert()
+        .success();
+
+    // Neither read-only commands nor dry runs are logged
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("list")
+        .assert()
+        .success();
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("remove")
+        .arg("audited")
+        .arg("--all")
+        .arg("--dry-run")
+        .assert()
+        .success();
+
+    // Installing again fails, and the failure is logged
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .env("USER", "bob")
+        .arg("install")
+        .arg(&source)
+        .arg("--to")
+        .arg("claude-code")
+        .assert()
+        .failure();
+
+    let entries = read_log(temp_dir.path());
+    assert_eq!(entries.len(), 2);
+
+    assert_eq!(entries[0]["user"], "alice");
+    assert_eq!(entries[0]["command"], "install");
+    assert_eq!(entries[0]["skill"], "audited");
+    assert_eq!(entries[0]["source"], source.display().to_string());
+    assert_eq!(entries[0]["agents"][0], "claude-code");
+    assert_eq!(entries[0]["outcome"], "success");
+    assert_eq!(entries[0]["content_hash"].as_str().map(str::len), Some(64));
+    assert!(entries[0]["timestamp"].as_str().unwrap().ends_with('Z'));
+
+    assert_eq!(entries[1]["user"], "bob");
+    assert_eq!(entries[1]["outcome"], "failure");
+    assert!(entries[1]["error"].is_string());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_user_is_looked_up_without_environment() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "audited", "");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .env_remove("USER")
+        .env_remove("USERNAME")
+        .env_remove("LOGNAME")
+        .arg("install")
+        .arg(&source)
+        .arg("--to")
+        .arg("claude-code")
+        .assert()
+        .success();
+
+    let entries = read_log(temp_dir.path());
+    let user = entries[0]["user"]
+        .as_str()
+        .expect("user should be a string");
+    assert!(!user.is_empty());
+    assert_ne!(user, "unknown");
+}
+
+#[test]
+fn test_log_command_filters_entries() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "audited", "");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("install")
+        .arg(&source)
+        .arg("--to")
+        .arg("claude-code")
+        .assert()
+        .success();
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("gc")
+        .arg("--yes")
+        .assert()
+        .success();
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("log")
+        .assert()
+        .success()
+        .stdout(contains("install audited from"))
+        .stdout(contains("gc"));
+
+    let mut cmd = sikil_cmd!();
+    let output = cmd
+        .env("HOME", temp_dir.path())
+        .arg("log")
+        .arg("--skill")
+        .arg("audited")
+        .arg("--since")
+        .arg("2000-01-01")
+        .arg("--json")
+        .output()
+        .expect("Failed to run log");
+    assert!(output.status.success());
+    let entries: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(entries.as_array().map(Vec::len), Some(1));
+    assert_eq!(entries[0]["command"], "install");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("log")
+        .arg("--since")
+        .arg("2999-01-01")
+        .assert()
+        .success()
+        .stdout(contains("No logged changes"))
+        .stdout(contains("install").not());
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("log")
+        .arg("--since")
+        .arg("yesterday")
+        .assert()
+        .failure()
+        .stderr(contains("invalid date"));
+}
+
+#[test]
+fn test_failed_validate_fix_is_logged() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path(), "audited", "");
+    let skill = temp_dir.path().join("broken");
+    fs::create_dir_all(&skill).expect("Failed to create skill");
+    fs::write(skill.join("SKILL.md"), "---\nname: broken\n---\n").expect("Failed to write");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("validate")
+        .arg(&skill)
+        .arg("--fix")
+        .arg("--yes")
+        .assert()
+        .code(2);
+
+    let entries = read_log(temp_dir.path());
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["command"], "validate --fix");
+    assert_eq!(entries[0]["outcome"], "failure");
+}
@@ -0,0 +1,239 @@
+//! Integration tests for Meta Command
+//!
+//! These tests validate:
+//! - `meta set` edits a managed skill in the repository, keeping comments and the body
+//! - `meta get` prints one field or all fields, in text and JSON
+//! - `meta unset` removes a field
+//! - Edits that would make SKILL.md invalid, or rename the skill, are rejected and
+//!   leave the file untouched
+//! - Cached scan results reflect an edit
+
+mod common;
+
+use predicates::str::contains;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+const SKILL_MD: &str = r#"---
+# Maintained by the docs team
+name: meta-skill
+description: "A skill for meta testing"
+version: "0.1.0" # bump on release
+---
+
+# Meta Skill
+
+---
+
+Body text.
+"#;
+
+/// Sets up a home with a managed skill linked into the claude-code directory
+fn setup_home(home: &Path) -> PathBuf {
+    let agent_dir = home.join("agent");
+    let skill_dir = home.join(".sikil/repo/meta-skill");
+    fs::create_dir_all(&agent_dir).expect("Failed to create agent dir");
+    fs::create_dir_all(&skill_dir).expect("Failed to create skill dir");
+    fs::write(skill_dir.join("SKILL.md"), SKILL_MD).expect("Failed to write SKILL.md");
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(&skill_dir, agent_dir.join("meta-skill"))
+        .expect("Failed to create symlink");
+
+    fs::write(
+        home.join(".sikil/config.toml"),
+        format!(
+            "[agents.claude-code]\nenabled = true\nglobal_path = \"{}\"\nworkspace_path = \".claude/skills\"\n",
+            agent_dir.display()
+        ),
+    )
+    .expect("Failed to write config");
+    skill_dir
+}
+
+#[test]
+#[cfg(unix)]
+fn test_meta_set_edits_managed_skill_in_place() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let skill_dir = setup_home(temp_dir.path());
+
+    // Populate the cache before editing
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .arg("list")
+        .assert()
+        .success();
+
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["meta", "set", "meta-skill", "version", "0.2.0"])
+        .assert()
+        .success()
+        .stdout(contains("Set 'version' in"));
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args([
+            "meta",
+            "set",
+            "meta-skill",
+            "tags",
+            "[docs, meta]",
+            "--yaml",
+        ])
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(skill_dir.join("SKILL.md")).unwrap();
+    assert_eq!(
+        content,
+        SKILL_MD.replace(
+            "version: \"0.1.0\" # bump on release\n",
+            "version: \"0.2.0\" # bump on release\ntags: [\"docs\",\"meta\"]\n"
+        )
+    );
+
+    let output = sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["show", "meta-skill", "--json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let parsed: serde_json::Value = serde_json::from_slice(&output).expect("valid JSON");
+    assert_eq!(parsed["version"], "0.2.0");
+    assert_eq!(parsed["tags"][1], "meta");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_meta_get() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path());
+
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["meta", "get", "meta-skill", "description"])
+        .assert()
+        .success()
+        .stdout("A skill for meta testing\n");
+
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["meta", "get", "meta-skill"])
+        .assert()
+        .success()
+        .stdout(contains("name: meta-skill"))
+        .stdout(contains("version: 0.1.0"));
+
+    let output = sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["--json", "meta", "get", "meta-skill", "version"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let parsed: serde_json::Value = serde_json::from_slice(&output).expect("valid JSON");
+    assert_eq!(parsed["key"], "version");
+    assert_eq!(parsed["value"], "0.1.0");
+
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["meta", "get", "meta-skill", "homepage"])
+        .assert()
+        .code(1)
+        .stderr(contains("'homepage' is not set"));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_meta_unset() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let skill_dir = setup_home(temp_dir.path());
+
+    let output = sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["--json", "meta", "unset", "meta-skill", "version"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let parsed: serde_json::Value = serde_json::from_slice(&output).expect("valid JSON");
+    assert_eq!(parsed["changed"], true);
+
+    let content = fs::read_to_string(skill_dir.join("SKILL.md")).unwrap();
+    assert_eq!(
+        content,
+        SKILL_MD.replace("version: \"0.1.0\" # bump on release\n", "")
+    );
+
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["meta", "unset", "meta-skill", "version"])
+        .assert()
+        .success()
+        .stdout(contains("'version' is not set"));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_meta_rejects_invalid_edits() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let skill_dir = setup_home(temp_dir.path());
+
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["meta", "unset", "meta-skill", "name"])
+        .assert()
+        .code(2)
+        .stderr(contains("missing required field 'name'"));
+
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["meta", "set", "meta-skill", "version", "[1, 2]", "--yaml"])
+        .assert()
+        .code(2);
+
+    // Renaming would leave the repository directory and symlink behind
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["meta", "set", "meta-skill", "name", "renamed-skill"])
+        .assert()
+        .code(2)
+        .stderr(contains("'name' cannot be changed"));
+
+    let content = fs::read_to_string(skill_dir.join("SKILL.md")).unwrap();
+    assert_eq!(content, SKILL_MD);
+}
+
+#[test]
+fn test_meta_by_path_and_unknown_skill() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let skill_dir = temp_dir.path().join("loose-skill");
+    fs::create_dir(&skill_dir).expect("Failed to create skill dir");
+    fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\r\nname: loose-skill\r\ndescription: d\r\n---\r\nBody\r\n",
+    )
+    .expect("Failed to write SKILL.md");
+
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["meta", "set"])
+        .arg(&skill_dir)
+        .args(["homepage", "https://example.com"])
+        .assert()
+        .success();
+    assert_eq!(
+        fs::read_to_string(skill_dir.join("SKILL.md")).unwrap(),
+        "---\r\nname: loose-skill\r\ndescription: d\r\nhomepage: https://example.com\r\n---\r\nBody\r\n"
+    );
+
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["meta", "get", "no-such-skill", "name"])
+        .assert()
+        .code(3);
+}
@@ -3,6 +3,7 @@
 //! This module tests performance characteristics of key commands.
 //! Target: `sikil list` with 50 skills should complete in <500ms
 //! Target: `sikil show` should complete in <200ms
+//! Target: `sikil list` with 300 skills in two agents should complete in <1s
 
 use std::fs;
 use std::time::{Duration, Instant};
@@ -222,3 +223,62 @@ workspace_path = ".claude-code/skills"
     let stdout = String::from_utf8_lossy(&output.get_output().stdout);
     assert!(stdout.starts_with('{') || stdout.starts_with('['));
 }
+
+#[test]
+fn benchmark_list_300_skills_across_agents() {
+    let (home_dir, agent_dir) = setup_large_skill_set(300);
+
+    // A second agent with its own unmanaged copies, so both directories and
+    // every skill are scanned and hashed
+    let windsurf_dir = TempDir::new().expect("Failed to create temp agent dir");
+    for i in 0..300 {
+        let skill_name = format!("test-skill-{:03}", i);
+        let skill_path = create_skill_dir(windsurf_dir.path(), &skill_name);
+        create_complete_skill_md(
+            &skill_path,
+            &skill_name,
+            &format!("Test skill number {}", i),
+        );
+    }
+
+    let config_path = home_dir.path().join(".sikil/config.toml");
+
+    let config_content = format!(
+        r#"[agents.claude-code]
+enabled = true
+global_path = "{}"
+workspace_path = ".claude-code/skills"
+
+[agents.windsurf]
+enabled = true
+global_path = "{}"
+workspace_path = ".windsurf/skills"
+"#,
+        agent_dir.path().display(),
+        windsurf_dir.path().display()
+    );
+    fs::write(&config_path, config_content).expect("Failed to write config");
+
+    // First run with an empty cache: every skill is parsed, sized and hashed
+    let start = Instant::now();
+    let output = sikil_cmd!()
+        .env("HOME", home_dir.path())
+        .env("NO_COLOR", "1")
+        .args(["list", "--json"])
+        .assert()
+        .success();
+    let duration = start.elapsed();
+
+    println!("List 300 skills across 2 agents took: {:?}", duration);
+
+    assert!(
+        duration < Duration::from_millis(1000),
+        "List command took {:?}, expected <1000ms",
+        duration
+    );
+
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output.get_output().stdout).expect("Output should be valid JSON");
+    let skills = parsed.as_array().expect("list --json should be an array");
+    assert_eq!(skills.len(), 300);
+}
@@ -0,0 +1,115 @@
+//! Integration tests for Permissions Command
+//!
+//! These tests validate:
+//! - Skills are listed with the tools they grant and the agents they are installed for
+//! - Skills that grant shell or network access are flagged
+//! - `--flagged` hides skills that only grant harmless tools
+//! - Invalid `allowed-tools` values are reported, not silently dropped
+//! - JSON output structure
+
+mod common;
+
+use predicates::str::contains;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Sets up a home with unmanaged skills in the claude-code directory
+fn setup_home(home: &Path) {
+    let agent_dir = home.join("agent");
+    let skills = [
+        ("git-helper", "allowed-tools: Read, Bash(git:*)\n"),
+        ("fetcher", "allowed-tools: [WebFetch, 'Bash(curl:*)']\n"),
+        ("reader", "allowed-tools: Read Grep\n"),
+        ("broken", "allowed-tools: Bash(git:*\n"),
+        ("plain", ""),
+    ];
+    for (name, tools) in skills {
+        let skill_dir = agent_dir.join(name);
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill dir");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            format!("---\nname: {}\ndescription: d\n{}---\n", name, tools),
+        )
+        .expect("Failed to write SKILL.md");
+    }
+
+    fs::create_dir_all(home.join(".sikil")).expect("Failed to create .sikil");
+    fs::write(
+        home.join(".sikil/config.toml"),
+        format!(
+            "[agents.claude-code]\nenabled = true\nglobal_path = \"{}\"\nworkspace_path = \".claude/skills\"\n",
+            agent_dir.display()
+        ),
+    )
+    .expect("Failed to write config");
+}
+
+#[test]
+fn test_permissions_lists_grants_and_flags() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path());
+
+    sikil_cmd!()
+        .args(["permissions", "--no-cache"])
+        .env("HOME", temp_dir.path())
+        .assert()
+        .success()
+        .stdout(contains("git-helper [claude-code]"))
+        .stdout(contains("Bash(git:*)"))
+        .stdout(contains("Bash(curl:*)  shell, network"))
+        .stdout(contains("invalid allowed-tools: unclosed '('"))
+        .stdout(contains(
+            "2 skill(s) grant shell or network access: fetcher, git-helper",
+        ))
+        .stdout(contains("1 skill(s) do not declare allowed-tools"));
+}
+
+#[test]
+fn test_permissions_flagged_only() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path());
+
+    let output = sikil_cmd!()
+        .args(["permissions", "--flagged"])
+        .env("HOME", temp_dir.path())
+        .output()
+        .expect("Failed to run permissions");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("git-helper"));
+    assert!(stdout.contains("broken"));
+    assert!(!stdout.contains("reader"));
+}
+
+#[test]
+fn test_permissions_json_output() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path());
+
+    let output = sikil_cmd!()
+        .args(["permissions", "--json"])
+        .env("HOME", temp_dir.path())
+        .output()
+        .expect("Failed to run permissions");
+    assert!(output.status.success());
+
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(parsed["undeclared"], 1);
+
+    let skills = parsed["skills"]
+        .as_array()
+        .expect("skills should be an array");
+    let names: Vec<&str> = skills.iter().map(|s| s["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["broken", "fetcher", "git-helper", "reader"]);
+
+    let fetcher = &skills[1];
+    assert_eq!(fetcher["grants"][0]["tool"], "WebFetch");
+    assert_eq!(fetcher["grants"][0]["capabilities"][0], "network");
+    assert_eq!(fetcher["flags"], serde_json::json!(["shell", "network"]));
+    assert_eq!(fetcher["agents"][0], "claude-code");
+    assert!(skills[0]["error"].is_string());
+    assert!(skills[3]["error"].is_null());
+}
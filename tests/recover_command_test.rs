@@ -0,0 +1,155 @@
+//! Integration tests for the operation journal and the recover command
+//!
+//! These tests validate:
+//! - Completed mutating commands leave no journal behind
+//! - Interrupted operations are reported before the next mutating command
+//! - `sikil recover` lists, rolls back and rolls forward interrupted operations
+
+mod common;
+
+use common::setup_home;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use sikil::core::journal::Journal;
+use sikil::core::plan::Step;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// Leaves a journal for an install that stopped after copying to the repo
+fn interrupt_install(home: &Path, source: &Path) -> (PathBuf, PathBuf) {
+    let repo = home.join(".sikil/repo/journaled");
+    let link = home.join("agent/journaled");
+    let mut journal = Journal::begin_in(
+        &home.join(".sikil/journal"),
+        "install",
+        "journaled",
+        vec![
+            Step::CopyDir {
+                from: source.to_path_buf(),
+                to: repo.clone(),
+            },
+            Step::CreateSymlink {
+                target: repo.clone(),
+                link: link.clone(),
+            },
+        ],
+    )
+    .expect("Failed to write journal");
+    journal.apply_next().expect("Failed to copy");
+    (repo, link)
+}
+
+fn journal_count(home: &Path) -> usize {
+    fs::read_dir(home.join(".sikil/journal"))
+        .map(|entries| entries.count())
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_install_leaves_no_journal() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "journaled", "");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("install")
+        .arg(&source)
+        .arg("--to")
+        .arg("claude-code")
+        .assert()
+        .success();
+
+    assert!(temp_dir.path().join("agent/journaled").is_symlink());
+    assert_eq!(journal_count(temp_dir.path()), 0);
+}
+
+#[test]
+fn test_mutating_command_reports_interrupted_operation() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "journaled", "");
+    interrupt_install(temp_dir.path(), &source);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("gc")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stdout(contains(
+            "Interrupted `install journaled` (1 of 2 steps done)",
+        ))
+        .stdout(contains("sikil recover --rollback"));
+
+    // Read-only commands do not look at the journal
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(contains("Interrupted").not());
+
+    assert_eq!(journal_count(temp_dir.path()), 1);
+}
+
+#[test]
+fn test_recover_lists_interrupted_operations_as_json() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "journaled", "");
+    let (repo, _) = interrupt_install(temp_dir.path(), &source);
+
+    let mut cmd = sikil_cmd!();
+    let output = cmd
+        .env("HOME", temp_dir.path())
+        .arg("recover")
+        .arg("--json")
+        .output()
+        .expect("Failed to run recover");
+    assert!(output.status.success());
+
+    let operations: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(operations[0]["command"], "install");
+    assert_eq!(operations[0]["completed"], 1);
+    assert_eq!(operations[0]["outcome"], "pending");
+    assert!(repo.exists());
+}
+
+#[test]
+fn test_recover_rollback() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "journaled", "");
+    let (repo, link) = interrupt_install(temp_dir.path(), &source);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("recover")
+        .arg("--rollback")
+        .assert()
+        .success()
+        .stdout(contains("Rolled back"));
+
+    assert!(!repo.exists());
+    assert!(!link.is_symlink());
+    assert!(source.join("SKILL.md").exists());
+    assert_eq!(journal_count(temp_dir.path()), 0);
+}
+
+#[test]
+fn test_recover_forward() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "journaled", "");
+    let (repo, link) = interrupt_install(temp_dir.path(), &source);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("recover")
+        .arg("--forward")
+        .assert()
+        .success()
+        .stdout(contains("Rolled forward"));
+
+    assert!(repo.join("SKILL.md").exists());
+    assert!(link.is_symlink());
+    assert_eq!(journal_count(temp_dir.path()), 0);
+}
@@ -0,0 +1,151 @@
+//! Integration tests for Repair Command
+//!
+//! These tests validate the repair command behavior including:
+//! - Relinking dangling managed symlinks to the repository
+//! - Removing empty directories of managed skills, keeping unknown ones
+//! - --dry-run leaves the filesystem untouched
+//! - JSON output reports one object per action
+
+mod common;
+
+use predicates::str::contains;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Helper to create a valid skill in the repository
+fn create_skill_in_repo(repo_dir: &Path, skill_name: &str) {
+    let skill_dir = repo_dir.join(skill_name);
+    fs::create_dir_all(&skill_dir).expect("Failed to create skill dir in repo");
+    let content = format!(
+        r#"---
+name: "{}"
+description: "A test skill for repair testing"
+---
+
+# Test Skill
+"#,
+        skill_name
+    );
+    fs::write(skill_dir.join("SKILL.md"), content).expect("Failed to write SKILL.md");
+}
+
+/// Helper to write a config with a single agent pointing at `agent_dir`
+fn write_config(home: &Path, agent_dir: &Path) {
+    let config_dir = home.join(".sikil");
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+    let config_content = format!(
+        r#"[agents.claude-code]
+enabled = true
+global_path = "{}"
+workspace_path = ".claude/skills"
+"#,
+        agent_dir.display()
+    );
+    fs::write(config_dir.join("config.toml"), config_content).expect("Failed to write config");
+}
+
+/// Sets up a home with one dangling managed link, one empty managed skill
+/// dir and one empty directory sikil knows nothing about
+#[cfg(unix)]
+fn setup_broken_home(home: &Path) -> (std::path::PathBuf, std::path::PathBuf) {
+    let agent_dir = home.join("agent");
+    let repo_dir = home.join(".sikil").join("repo");
+    fs::create_dir_all(agent_dir.join("leftover")).expect("Failed to create empty dir");
+    fs::create_dir_all(agent_dir.join("drafts")).expect("Failed to create empty dir");
+    create_skill_in_repo(&repo_dir, "leftover");
+    create_skill_in_repo(&repo_dir, "moved-skill");
+    std::os::unix::fs::symlink(
+        home.join("old-home/.sikil/repo/moved-skill"),
+        agent_dir.join("moved-skill"),
+    )
+    .expect("Failed to create symlink");
+    write_config(home, &agent_dir);
+    (agent_dir, repo_dir)
+}
+
+#[test]
+#[cfg(unix)]
+fn test_repair_relinks_and_removes_empty_dirs() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let (agent_dir, repo_dir) = setup_broken_home(temp_dir.path());
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .current_dir(temp_dir.path())
+        .arg("repair")
+        .assert()
+        .success()
+        .stdout(contains("2 repaired"));
+
+    assert_eq!(
+        fs::read_link(agent_dir.join("moved-skill")).unwrap(),
+        repo_dir.join("moved-skill")
+    );
+    assert!(!agent_dir.join("leftover").exists());
+    assert!(agent_dir.join("drafts").exists());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_repair_dry_run_changes_nothing() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let (agent_dir, _) = setup_broken_home(temp_dir.path());
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .current_dir(temp_dir.path())
+        .arg("repair")
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stdout(contains("Would relink"))
+        .stdout(contains("2 repair(s) planned"));
+
+    assert!(!agent_dir.join("moved-skill").exists());
+    assert!(agent_dir.join("leftover").exists());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_repair_json_output() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_broken_home(temp_dir.path());
+
+    let mut cmd = sikil_cmd!();
+    let output = cmd
+        .env("HOME", temp_dir.path())
+        .current_dir(temp_dir.path())
+        .arg("--json")
+        .arg("repair")
+        .output()
+        .expect("Failed to run repair");
+
+    assert!(output.status.success());
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    let actions = json.as_array().expect("Output should be an array");
+    assert_eq!(actions.len(), 2);
+    assert!(actions
+        .iter()
+        .any(|a| a["action"] == "relink" && a["status"] == "done"));
+    assert!(actions
+        .iter()
+        .any(|a| a["action"] == "remove-empty-dir" && a["status"] == "done"));
+}
+
+#[test]
+fn test_repair_nothing_to_do() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let agent_dir = temp_dir.path().join("agent");
+    fs::create_dir_all(&agent_dir).expect("Failed to create agent dir");
+    write_config(temp_dir.path(), &agent_dir);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .current_dir(temp_dir.path())
+        .arg("repair")
+        .assert()
+        .success()
+        .stdout(contains("Nothing to repair"));
+}
@@ -0,0 +1,150 @@
+//! Integration tests for Search Command
+//!
+//! These tests validate:
+//! - Results are ranked, with name matches above body matches
+//! - Fuzzy and prefix matching of query words
+//! - Snippets show the matching SKILL.md line
+//! - JSON output structure
+//! - The search index is written and kept in sync with SKILL.md
+
+mod common;
+
+use predicates::str::contains;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Sets up a home with unmanaged skills in the claude-code directory
+fn setup_home(home: &Path) -> std::path::PathBuf {
+    let agent_dir = home.join("agent");
+    let skills = [
+        (
+            "pdf",
+            "Work with PDF files",
+            "# PDF\n\nFill in forms and merge documents.\n",
+        ),
+        (
+            "doc-tools",
+            "Convert office documents",
+            "# Documents\n\nFor scanned files, extract text from the PDF with pdftotext.\n",
+        ),
+        (
+            "git-review",
+            "Review pull requests",
+            "# Review\n\nRun git diff first.\n",
+        ),
+    ];
+    for (name, description, body) in skills {
+        let skill_dir = agent_dir.join(name);
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill dir");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            format!(
+                "---\nname: {}\ndescription: {}\n---\n{}",
+                name, description, body
+            ),
+        )
+        .expect("Failed to write SKILL.md");
+    }
+
+    fs::create_dir_all(home.join(".sikil")).expect("Failed to create .sikil");
+    fs::write(
+        home.join(".sikil/config.toml"),
+        format!(
+            "[agents.claude-code]\nenabled = true\nglobal_path = \"{}\"\nworkspace_path = \".claude/skills\"\n",
+            agent_dir.display()
+        ),
+    )
+    .expect("Failed to write config");
+    agent_dir
+}
+
+fn search_json(home: &Path, args: &[&str]) -> serde_json::Value {
+    let output = sikil_cmd!()
+        .env("HOME", home)
+        .args(["search", "--json"])
+        .args(args)
+        .output()
+        .expect("Failed to run search");
+    assert!(output.status.success());
+    serde_json::from_slice(&output.stdout).expect("Output should be valid JSON")
+}
+
+#[test]
+fn test_search_ranks_and_shows_snippets() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path());
+
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["search", "pdf"])
+        .assert()
+        .success()
+        .stdout(contains("Found 2 skills matching 'pdf'"))
+        .stdout(contains("pdf (claude-code)"))
+        .stdout(contains(
+            "SKILL.md:7: For scanned files, extract text from the PDF with pdftotext.",
+        ));
+
+    let results = search_json(temp_dir.path(), &["pdf"]);
+    let names: Vec<&str> = results
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["pdf", "doc-tools"]);
+}
+
+#[test]
+fn test_search_fuzzy_words_must_all_match() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_home(temp_dir.path());
+
+    let results = search_json(temp_dir.path(), &["pdf", "extarction"]);
+    assert_eq!(results.as_array().unwrap().len(), 1);
+    let result = &results[0];
+    assert_eq!(result["name"], "doc-tools");
+    assert_eq!(result["matched"], serde_json::json!(["body"]));
+    assert_eq!(result["agents"][0], "claude-code");
+    assert!(result["path"].as_str().unwrap().ends_with("SKILL.md"));
+
+    let snippet = &result["snippet"];
+    assert_eq!(snippet["line"], 7);
+    let text = snippet["text"].as_str().unwrap();
+    let highlighted: Vec<&str> = snippet["highlights"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| &text[r["start"].as_u64().unwrap() as usize..r["end"].as_u64().unwrap() as usize])
+        .collect();
+    assert_eq!(highlighted, vec!["extract", "PDF", "pdftotext"]);
+
+    sikil_cmd!()
+        .env("HOME", temp_dir.path())
+        .args(["search", "kubernetes"])
+        .assert()
+        .success()
+        .stdout(contains("No skills match 'kubernetes'."));
+}
+
+#[test]
+fn test_search_index_follows_skill_changes() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let agent_dir = setup_home(temp_dir.path());
+
+    assert!(search_json(temp_dir.path(), &["rebase"])
+        .as_array()
+        .unwrap()
+        .is_empty());
+    assert!(temp_dir.path().join(".sikil/search-index.json").exists());
+
+    fs::write(
+        agent_dir.join("git-review/SKILL.md"),
+        "---\nname: git-review\ndescription: Review pull requests\n---\n# Review\n\nRebase onto main before reviewing the branch.\n",
+    )
+    .expect("Failed to update SKILL.md");
+
+    let results = search_json(temp_dir.path(), &["rebase"]);
+    assert_eq!(results[0]["name"], "git-review");
+}
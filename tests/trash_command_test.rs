@@ -0,0 +1,240 @@
+//! Integration tests for the trash
+//!
+//! These tests validate:
+//! - `remove` moves the repository copy and unmanaged directories to the trash
+//! - `sikil trash list` shows where each installation lived
+//! - `sikil trash restore` puts the skill back, and refuses occupied paths
+//! - `sikil undo` after a restore leaves the skill in place
+//! - `sikil trash empty` and `[trash] retention_days` delete entries for good,
+//!   and `trash empty` does not count entries `sikil undo` emptied
+
+mod common;
+
+use common::setup_home;
+use predicates::str::contains;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn install_and_remove(home: &Path, source: &Path) {
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", home)
+        .arg("install")
+        .arg(source)
+        .arg("--to")
+        .arg("claude-code")
+        .assert()
+        .success();
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", home)
+        .arg("remove")
+        .arg("trashed")
+        .arg("--all")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stdout(contains("sikil trash restore"));
+}
+
+fn trash_entries(home: &Path) -> Vec<PathBuf> {
+    fs::read_dir(home.join(".sikil/trash"))
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_remove_moves_skill_to_trash_and_restore_brings_it_back() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "trashed", "");
+    install_and_remove(temp_dir.path(), &source);
+
+    let repo = temp_dir.path().join(".sikil/repo/trashed");
+    let link = temp_dir.path().join("agent/trashed");
+    assert!(!repo.exists());
+
+    let entries = trash_entries(temp_dir.path());
+    assert_eq!(entries.len(), 1);
+    assert!(entries[0].join("repo/SKILL.md").is_file());
+    assert!(entries[0]
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .ends_with("Z-trashed"));
+
+    let mut cmd = sikil_cmd!();
+    let output = cmd
+        .env("HOME", temp_dir.path())
+        .arg("trash")
+        .arg("list")
+        .arg("--json")
+        .output()
+        .expect("Failed to run trash list");
+    assert!(output.status.success());
+    let listings: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(listings[0]["name"], "trashed");
+    assert_eq!(listings[0]["repo_path"], repo.display().to_string());
+    assert_eq!(listings[0]["installations"][0]["agent"], "claude-code");
+    assert_eq!(
+        listings[0]["installations"][0]["path"],
+        link.display().to_string()
+    );
+    assert!(listings[0]["expires_at"].is_string());
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("trash")
+        .arg("restore")
+        .arg("trashed")
+        .assert()
+        .success()
+        .stdout(contains("Restored 'trashed'"));
+
+    assert!(repo.join("SKILL.md").is_file());
+    assert!(link.is_symlink());
+    assert!(trash_entries(temp_dir.path()).is_empty());
+}
+
+#[test]
+fn test_undo_after_restore_keeps_skill_in_place() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "trashed", "");
+    install_and_remove(temp_dir.path(), &source);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("trash")
+        .arg("restore")
+        .arg("trashed")
+        .assert()
+        .success();
+
+    // The restore is not undoable, and the remove before it changed since
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("undo")
+        .assert()
+        .failure()
+        .stderr(contains("cannot undo `remove trashed`"));
+
+    assert!(temp_dir
+        .path()
+        .join(".sikil/repo/trashed/SKILL.md")
+        .is_file());
+    assert!(temp_dir.path().join("agent/trashed").is_symlink());
+    assert!(trash_entries(temp_dir.path()).is_empty());
+}
+
+#[test]
+fn test_restore_refuses_occupied_path() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "trashed", "");
+    install_and_remove(temp_dir.path(), &source);
+
+    let link = temp_dir.path().join("agent/trashed");
+    fs::create_dir_all(&link).expect("Failed to create directory");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("trash")
+        .arg("restore")
+        .arg("trashed")
+        .assert()
+        .failure()
+        .stderr(contains(link.display().to_string()));
+
+    assert!(!temp_dir.path().join(".sikil/repo/trashed").exists());
+    assert_eq!(trash_entries(temp_dir.path()).len(), 1);
+}
+
+#[test]
+fn test_trash_empty() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "trashed", "");
+    install_and_remove(temp_dir.path(), &source);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("trash")
+        .arg("empty")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stdout(contains("Deleted 1 skill(s)"));
+
+    assert!(trash_entries(temp_dir.path()).is_empty());
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("trash")
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(contains("Trash is empty"));
+}
+
+#[test]
+fn test_trash_empty_skips_entries_undo_emptied() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "trashed", "");
+    install_and_remove(temp_dir.path(), &source);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("undo")
+        .assert()
+        .success();
+    assert_eq!(trash_entries(temp_dir.path()).len(), 1);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("trash")
+        .arg("empty")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stdout(contains("Trash is empty"));
+
+    assert!(trash_entries(temp_dir.path()).is_empty());
+    assert!(temp_dir
+        .path()
+        .join(".sikil/repo/trashed/SKILL.md")
+        .is_file());
+}
+
+#[test]
+fn test_expired_entries_are_purged() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(
+        temp_dir.path(),
+        "trashed",
+        "\n[trash]\nretention_days = 1\n",
+    );
+    install_and_remove(temp_dir.path(), &source);
+
+    // Backdate the entry past the retention period
+    let entry = trash_entries(temp_dir.path()).remove(0);
+    let metadata_path = entry.join("trash.json");
+    let mut metadata: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&metadata_path).unwrap()).unwrap();
+    metadata["removed_at"] = serde_json::json!(0);
+    fs::write(&metadata_path, metadata.to_string()).unwrap();
+
+    // Listing is read-only and leaves it alone; the next mutating command purges it
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("trash")
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(contains("trashed"));
+    assert_eq!(trash_entries(temp_dir.path()).len(), 1);
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("gc")
+        .arg("--yes")
+        .assert()
+        .success();
+    assert!(trash_entries(temp_dir.path()).is_empty());
+}
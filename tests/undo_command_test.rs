@@ -0,0 +1,144 @@
+//! Integration tests for the undo command
+//!
+//! These tests validate:
+//! - `remove --all --yes` can be undone, restoring the repository entry
+//! - Install and adopt are reversed
+//! - Undo refuses when a touched path changed since
+//! - Undo reports when there is nothing to undo
+
+mod common;
+
+use common::setup_home;
+use predicates::str::contains;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn install(home: &Path, source: &Path) {
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", home)
+        .arg("install")
+        .arg(source)
+        .arg("--to")
+        .arg("claude-code")
+        .assert()
+        .success();
+}
+
+fn remove_all(home: &Path) {
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", home)
+        .arg("remove")
+        .arg("undoable")
+        .arg("--all")
+        .arg("--yes")
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_undo_remove_all_restores_skill() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "undoable", "");
+    install(temp_dir.path(), &source);
+    remove_all(temp_dir.path());
+
+    let repo = temp_dir.path().join(".sikil/repo/undoable");
+    let link = temp_dir.path().join("agent/undoable");
+    assert!(!repo.exists());
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("undo")
+        .assert()
+        .success()
+        .stdout(contains("Undid `remove undoable`"));
+
+    assert!(repo.join("SKILL.md").is_file());
+    assert!(link.is_symlink());
+
+    // The next undo reverses the install
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("undo")
+        .arg("--json")
+        .assert()
+        .success()
+        .stdout(contains("\"command\": \"install\""));
+
+    assert!(!repo.exists());
+    assert!(!link.is_symlink());
+    assert!(source.join("SKILL.md").is_file());
+}
+
+#[test]
+fn test_undo_adopt_moves_skill_back() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "undoable", "");
+    let unmanaged = temp_dir.path().join("agent/undoable");
+    fs::rename(&source, &unmanaged).expect("Failed to move skill");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("adopt")
+        .arg("undoable")
+        .assert()
+        .success();
+    assert!(unmanaged.is_symlink());
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("undo")
+        .assert()
+        .success();
+
+    assert!(!unmanaged.is_symlink());
+    assert!(unmanaged.join("SKILL.md").is_file());
+    assert!(!temp_dir.path().join(".sikil/repo/undoable").exists());
+}
+
+#[test]
+fn test_undo_refuses_after_changes() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "undoable", "");
+    install(temp_dir.path(), &source);
+    remove_all(temp_dir.path());
+
+    // Something else now lives where the symlink was
+    let link = temp_dir.path().join("agent/undoable");
+    fs::create_dir_all(&link).expect("Failed to create directory");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("undo")
+        .assert()
+        .failure()
+        .stderr(contains("cannot undo `remove undoable`"))
+        .stderr(contains(link.display().to_string()));
+
+    assert!(!temp_dir.path().join(".sikil/repo/undoable").exists());
+}
+
+#[test]
+fn test_undo_dry_run_and_empty_history() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = setup_home(temp_dir.path(), "undoable", "");
+
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("undo")
+        .assert()
+        .failure()
+        .stderr(contains("nothing to undo"));
+
+    install(temp_dir.path(), &source);
+    let mut cmd = sikil_cmd!();
+    cmd.env("HOME", temp_dir.path())
+        .arg("undo")
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stdout(contains("remove symlink"));
+
+    assert!(temp_dir.path().join("agent/undoable").is_symlink());
+}